@@ -84,3 +84,7 @@ cargo run --example customize_http_client --features custom_client -- --email $E
 # cargo run --example sign_in_with_google_oauth_credential --features oauth
 # cargo run --example sign_in_with_github_oauth_credential --features oauth
 
+
+# Run examples for OAuth siginig in with redirection on a server.
+
+# cargo run --example sign_in_with_google_oauth_credential_on_server_redirect --features oauth -- --address 0.0.0.0:8080 --origin https://your-app.com
@@ -8,7 +8,6 @@ use clap::Parser;
 use fars::ApiKey;
 use fars::Config;
 use fars::Email;
-use fars::OAuthContinueUri;
 
 #[derive(Parser)]
 struct Arguments {
@@ -31,7 +30,7 @@ async fn main() -> anyhow::Result<()> {
     let providers = config
         .fetch_providers_for_email(
             Email::new(arguments.email.clone()),
-            OAuthContinueUri::new("http://localhost"),
+            None,
         )
         .await?;
 
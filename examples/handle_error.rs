@@ -56,37 +56,41 @@ async fn main() -> anyhow::Result<()> {
                     // Do something with HTTP request error, e.g. retry.
                     Err(error.into())
                 },
+                // Handle wrong email or password.
+                | fars::Error::InvalidCredentials {
+                    response,
+                } => {
+                    eprintln!("Invalid email and/or password.");
+                    // Do something with invalid login credentials, e.g. display error message for user.
+                    Err(fars::Error::InvalidCredentials {
+                        response,
+                    }
+                    .into())
+                },
+                // Handle disabled user.
+                | fars::Error::AccountDisabled {
+                    response,
+                } => {
+                    eprintln!("This user is disabled.");
+                    // Do something with disabled user, e.g. display error message for user.
+                    Err(fars::Error::AccountDisabled {
+                        response,
+                    }
+                    .into())
+                },
                 // Handle API error.
                 | fars::Error::ApiError {
+                    endpoint,
                     status_code,
                     error_code,
                     response,
                 } => {
                     match error_code {
-                        | CommonErrorCode::InvalidLoginCredentials => {
-                            eprintln!("Invalid email and/or password.");
-                            // Do something with invalid login credentials, e.g. display error message for user.
-                            Err(fars::Error::ApiError {
-                                status_code,
-                                error_code,
-                                response,
-                            }
-                            .into())
-                        },
-                        | CommonErrorCode::UserDisabled => {
-                            eprintln!("This user is disabled.");
-                            // Do something with disabled user, e.g. display error message for user.
-                            Err(fars::Error::ApiError {
-                                status_code,
-                                error_code,
-                                response,
-                            }
-                            .into())
-                        },
                         | CommonErrorCode::TooManyAttemptsTryLater => {
                             eprintln!("Too many attempts, try again later.");
                             // Do something with too many attempts, e.g. display error message for user.
                             Err(fars::Error::ApiError {
+                                endpoint,
                                 status_code,
                                 error_code,
                                 response,
@@ -95,11 +99,12 @@ async fn main() -> anyhow::Result<()> {
                         },
                         | _ => {
                             eprintln!(
-                                "API error: ({:?}) {:?} - {:?}",
-                                status_code, error_code, response
+                                "API error on {}: ({:?}) {:?} - {:?}",
+                                endpoint, status_code, error_code, response
                             );
                             // Do something with other errors.
                             Err(fars::Error::ApiError {
+                                endpoint,
                                 status_code,
                                 error_code,
                                 response,
@@ -39,7 +39,7 @@ async fn main() -> anyhow::Result<()> {
 
     // Send a verification email.
     let session = session
-        .send_email_verification(None)
+        .send_email_verification(None, None)
         .await?;
 
     println!(
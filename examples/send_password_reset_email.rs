@@ -31,6 +31,7 @@ async fn main() -> anyhow::Result<()> {
         .send_reset_password_email(
             Email::new(arguments.email.clone()),
             None,
+            None,
         )
         .await?;
 
@@ -8,7 +8,6 @@
 #![cfg(feature = "oauth")]
 
 use qrcode::render::unicode;
-use std::collections::HashSet;
 
 use fars::oauth::FacebookDeviceCodeClient;
 use fars::oauth::OAuthScope;
@@ -27,12 +26,8 @@ async fn main() -> anyhow::Result<()> {
     let oauth_client = FacebookDeviceCodeClient::new(app_id, client_token)?;
 
     // Request authorization.
-    let session = oauth_client
-        .request_authorization(HashSet::from([
-            OAuthScope::open_id(),
-            OAuthScope::open_id_email(),
-            OAuthScope::open_id_profile(),
-        ]))
+    let mut session = oauth_client
+        .request_authorization(OAuthScope::open_id_standard_set())
         .await?;
 
     let verification_uri = session.verification_uri();
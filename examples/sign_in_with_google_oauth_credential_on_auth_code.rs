@@ -7,7 +7,6 @@
 
 #![cfg(feature = "oauth")]
 
-use std::collections::HashSet;
 use std::sync::Arc;
 
 use axum::extract::{Query, State};
@@ -153,11 +152,8 @@ async fn main() -> anyhow::Result<()> {
     )?;
 
     // Generate an OAuth session with authorization URL.
-    let session = oauth_client.generate_session(HashSet::from([
-        OAuthScope::open_id(),
-        OAuthScope::open_id_email(),
-        OAuthScope::open_id_profile(),
-    ]));
+    let session =
+        oauth_client.generate_session(OAuthScope::open_id_standard_set());
 
     // Open the authorization URL in the default browser.
     webbrowser::open(session.authorize_url.inner())?;
@@ -16,7 +16,6 @@ use fars::Config;
 use fars::OAuthRequestUri;
 use fars::ProviderId;
 use qrcode::render::unicode;
-use std::collections::HashSet;
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -28,12 +27,8 @@ async fn main() -> anyhow::Result<()> {
     let oauth_client = GoogleDeviceCodeClient::new(client_id, client_secret)?;
 
     // Request authorization.
-    let session = oauth_client
-        .request_authorization(HashSet::from([
-            OAuthScope::open_id(),
-            OAuthScope::open_id_email(),
-            OAuthScope::open_id_profile(),
-        ]))
+    let mut session = oauth_client
+        .request_authorization(OAuthScope::open_id_standard_set())
         .await?;
 
     let verification_uri = session
@@ -44,9 +39,7 @@ async fn main() -> anyhow::Result<()> {
     // Encode verification URI to QR code.
     let qr_code = qrcode::QrCode::new(format!(
         "{}?user_code={}",
-        verification_uri
-            .inner()
-            .to_string(),
+        verification_uri.inner(),
         user_code.inner()
     ))?;
 
@@ -0,0 +1,251 @@
+//! An example to sign in with Google OAuth credential by session-based interface
+//! on the Authorization Code grant type of the OAuth 2.0 on a server,
+//! which redirects users to the Google without opening a local browser.
+//!
+//! 1. A user accesses `/auth/google` and is redirected to the authorize URL with a CSRF state.
+//! 2. The Google redirects the user back to `/auth/google-callback` with the authorization code and the state.
+//! 3. The server validates the state, exchanges the code into a token and signs in to the Firebase Auth.
+//!
+//! ```shell
+//! $ cargo run --example sign_in_with_google_oauth_credential_on_server_redirect --features oauth -- --address 0.0.0.0:8080 --origin https://your-app.com
+//! ```
+//!
+//! Please register `{origin}/auth/google-callback` as an authorized redirect URI of the OAuth client.
+
+#![cfg(feature = "oauth")]
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Redirect, Response};
+use axum::{routing::get, Router};
+use clap::Parser;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use fars::oauth::AuthorizationCodeSession;
+use fars::oauth::ClientId;
+use fars::oauth::ClientSecret;
+use fars::oauth::GoogleAuthorizationCodeClient;
+use fars::oauth::OAuthScope;
+use fars::oauth::RedirectUrl;
+use fars::oauth::{AuthorizationCode, CsrfState};
+use fars::ApiKey;
+use fars::Config;
+use fars::OAuthRequestUri;
+use fars::ProviderId;
+
+#[derive(Parser)]
+struct Arguments {
+    /// The address to bind the server, e.g. `0.0.0.0:8080`.
+    #[arg(
+        short,
+        long,
+        default_value = "localhost:8080"
+    )]
+    address: String,
+    /// The public origin of the server, e.g. `https://your-app.com`.
+    #[arg(
+        short,
+        long,
+        default_value = "http://localhost:8080"
+    )]
+    origin: String,
+}
+
+/// The lifetime of a pending session, after which the user must start signing in again.
+const PENDING_SESSION_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// The maximum number of pending sessions not to grow the memory without bound.
+const MAX_PENDING_SESSIONS: usize = 10_000;
+
+#[derive(Clone)]
+struct ServerState {
+    config: Arc<Config>,
+    oauth_client: Arc<GoogleAuthorizationCodeClient>,
+    origin: String,
+    // NOTE: Please use a shared store, e.g. Redis, with expiration for multi-instance deployments.
+    // NOTE: Every unauthenticated access to `/auth/google` adds a pending session,
+    // which expires after `PENDING_SESSION_TTL` and is capped by `MAX_PENDING_SESSIONS`.
+    // An attacker can still fill the cap to reject other users until the sessions expire,
+    // so please serve `/auth/google` behind a rate limit, e.g. per client IP address, on production.
+    pending_sessions:
+        Arc<Mutex<HashMap<CsrfState, (Instant, AuthorizationCodeSession)>>>,
+}
+
+#[allow(dead_code)]
+#[derive(Deserialize)]
+struct QueryParameters {
+    code: Option<String>,
+    scope: Option<String>,
+    authuser: Option<usize>,
+    prompt: Option<String>,
+    state: Option<String>,
+    error: Option<String>,
+}
+
+async fn handle_sign_in(State(state): State<ServerState>) -> Response {
+    // Generate an OAuth session with a random CSRF state.
+    let session = state
+        .oauth_client
+        .generate_session_with_state(
+            OAuthScope::open_id_standard_set(),
+            CsrfState::new_random(),
+        );
+
+    let authorize_url = session
+        .authorize_url
+        .inner()
+        .to_string();
+
+    // Store the session by the state to continue on the callback.
+    {
+        let mut pending_sessions = state
+            .pending_sessions
+            .lock()
+            .await;
+
+        // Drop the expired sessions of abandoned flows.
+        pending_sessions.retain(|_, (created_at, _)| {
+            created_at.elapsed() < PENDING_SESSION_TTL
+        });
+
+        if pending_sessions.len() >= MAX_PENDING_SESSIONS {
+            eprintln!("Error: Too many pending sessions.");
+            return (StatusCode::SERVICE_UNAVAILABLE, "Error").into_response();
+        }
+
+        pending_sessions.insert(
+            session.csrf_state().clone(),
+            (Instant::now(), session),
+        );
+    }
+
+    // Redirect the user to the authorize URL.
+    Redirect::to(&authorize_url).into_response()
+}
+
+async fn handle_callback(
+    State(state): State<ServerState>,
+    Query(params): Query<QueryParameters>,
+) -> Response {
+    // Check query parameters.
+    if let Some(error) = params.error {
+        eprintln!("Error: {}", error);
+        return (StatusCode::BAD_REQUEST, "Error").into_response();
+    }
+
+    let (Some(auth_code), Some(auth_state)) = (params.code, params.state)
+    else {
+        eprintln!("Error: No authorization code or state.");
+        return (StatusCode::BAD_REQUEST, "Error").into_response();
+    };
+
+    // Continue to sign in process.
+    match continue_sign_in(state, auth_code, auth_state).await {
+        | Ok(message) => message.into_response(),
+        | Err(e) => {
+            eprintln!("Error: {:?}", e);
+            (StatusCode::UNAUTHORIZED, "Error").into_response()
+        },
+    }
+}
+
+async fn continue_sign_in(
+    state: ServerState,
+    auth_code: String,
+    auth_state: String,
+) -> anyhow::Result<String> {
+    let auth_state = CsrfState::new(auth_state);
+
+    // Take the pending session of the state, which can be used only once.
+    let (created_at, oauth_session) = state
+        .pending_sessions
+        .lock()
+        .await
+        .remove(&auth_state)
+        .ok_or_else(|| anyhow::anyhow!("Unknown or used CSRF state"))?;
+
+    if created_at.elapsed() >= PENDING_SESSION_TTL {
+        return Err(anyhow::anyhow!("Expired CSRF state"));
+    }
+
+    // Validate the state and exchange authorization code into OAuth token.
+    let token = oauth_session
+        .exchange_code_into_token(
+            AuthorizationCode::new(auth_code),
+            auth_state,
+        )
+        .await
+        .map_err(|e| anyhow::anyhow!("{:?}", e))?;
+
+    // Get a session by signing in Google OAuth credential.
+    let session = state
+        .config
+        .sign_in_with_oauth_credential(
+            OAuthRequestUri::new(state.origin.clone()),
+            token.create_idp_post_body(ProviderId::Google)?,
+        )
+        .await?;
+
+    println!(
+        "Succeeded to sign in with Google OAuth credential: {:?}",
+        session
+    );
+
+    Ok(format!(
+        "Succeeded to sign in with Google OAuth credential: {}",
+        session.local_id()
+    ))
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    // Parse the command line arguments.
+    let arguments = Arguments::parse();
+
+    // Get Client ID and Client Secret from the environment variables.
+    let client_id = ClientId::from_env("GOOGLE_CLIENT_ID")?;
+    let client_secret = ClientSecret::from_env("GOOGLE_CLIENT_SECRET")?;
+
+    // Create an OAuth client.
+    let oauth_client = GoogleAuthorizationCodeClient::new(
+        client_id,
+        client_secret,
+        RedirectUrl::new(format!(
+            "{}/auth/google-callback",
+            arguments.origin
+        ))?,
+    )?;
+
+    // Create a server state.
+    let server_state = ServerState {
+        config: Arc::new(Config::new(ApiKey::from_env()?)),
+        oauth_client: Arc::new(oauth_client),
+        origin: arguments.origin.clone(),
+        pending_sessions: Arc::new(Mutex::new(HashMap::new())),
+    };
+
+    // Build application with sign in and redirection handlers.
+    let app = Router::new()
+        .route("/auth/google", get(handle_sign_in))
+        .route(
+            "/auth/google-callback",
+            get(handle_callback),
+        )
+        .with_state(server_state);
+
+    // Run it with hyper on the specified address.
+    let listener = tokio::net::TcpListener::bind(&arguments.address).await?;
+    println!(
+        "Listening on {}, please access {}/auth/google",
+        arguments.address, arguments.origin
+    );
+
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
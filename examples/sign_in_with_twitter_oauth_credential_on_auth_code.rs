@@ -126,7 +126,7 @@ async fn continue_sign_in(
         })?;
 
     println!(
-        "Succeeded to sign in with Facebook OAuth credential: {:?}",
+        "Succeeded to sign in with Twitter OAuth credential: {:?}",
         session
     );
 
@@ -15,6 +15,7 @@
 //! - [ ] (Not tested) [Verify password reset code](https://firebase.google.com/docs/reference/rest/auth#section-verify-password-reset-code)
 //! - [ ] (Not tested) [Confirm password reset](https://firebase.google.com/docs/reference/rest/auth#section-confirm-reset-password)
 //! - [x] [Change email](https://firebase.google.com/docs/reference/rest/auth#section-change-email)
+//! - [ ] (Not tested) [Verify before update email](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode)
 //! - [x] [Change password](https://firebase.google.com/docs/reference/rest/auth#section-change-password)
 //! - [x] [Update profile](https://firebase.google.com/docs/reference/rest/auth#section-update-profile)
 //! - [x] [Get user data](https://firebase.google.com/docs/reference/rest/auth#section-get-account-info)
@@ -23,7 +24,12 @@
 //! - [x] [Unlink provider](https://firebase.google.com/docs/reference/rest/auth#section-unlink-provider)
 //! - [x] [Send email verification](https://firebase.google.com/docs/reference/rest/auth#section-send-email-verification)
 //! - [ ] (Not tested) [Confirm email verification](https://firebase.google.com/docs/reference/rest/auth#section-confirm-email-verification)
+//! - [ ] (Not tested) [Check action code](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/resetPassword)
+//! - [ ] (Not tested) [Apply action code](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/update)
 //! - [x] [Delete account](https://firebase.google.com/docs/reference/rest/auth#section-delete-account)
+//! - [ ] (Not tested) [Fetch password policy](https://cloud.google.com/identity-platform/docs/password-policy)
+//! - [ ] (Not tested) [Fetch project config](https://cloud.google.com/identity-platform/docs/reference/rest/v1/projects/getProjectConfig)
+//! - [ ] (Not tested, requires admin credentials) [Generate action link](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode)
 //!
 //! ## NOTE
 //! Unsupported APIs have already been implemented but not tested.
@@ -90,14 +96,19 @@
 //! ```
 
 // Private modules
+mod apply_action_code;
 mod change_email;
 mod change_password;
+mod check_action_code;
 mod confirm_email_verification;
 mod confirm_password_reset;
 mod delete_account;
 mod exchange_custom_token_for_an_id_and_refresh_token;
 mod exchange_refresh_token;
+mod fetch_password_policy;
+mod fetch_project_config;
 mod fetch_providers_for_email;
+mod generate_action_link;
 mod get_user_data;
 mod link_with_email_password;
 mod link_with_oauth_credential;
@@ -109,15 +120,22 @@ mod sign_in_with_oauth_credential;
 mod sign_up_with_email_password;
 mod unlink_provider;
 mod update_profile;
+mod verify_before_update_email;
 mod verify_password_reset_code;
 
 // Re-exports
+pub use apply_action_code::apply_action_code;
+pub use apply_action_code::ApplyActionCodeRequestBodyPayload;
+pub use apply_action_code::ApplyActionCodeResponsePayload;
 pub use change_email::change_email;
 pub use change_email::ChangeEmailRequestBodyPayload;
 pub use change_email::ChangeEmailResponsePayload;
 pub use change_password::change_password;
 pub use change_password::ChangePasswordRequestBodyPayload;
 pub use change_password::ChangePasswordResponsePayload;
+pub use check_action_code::check_action_code;
+pub use check_action_code::CheckActionCodeRequestBodyPayload;
+pub use check_action_code::CheckActionCodeResponsePayload;
 pub use confirm_email_verification::confirm_email_verification;
 pub use confirm_email_verification::ConfirmEmailVerificationRequestBodyPayload;
 pub use confirm_email_verification::ConfirmEmailVerificationResponsePayload;
@@ -133,9 +151,18 @@ pub use exchange_custom_token_for_an_id_and_refresh_token::ExchangeCustomTokenFo
 pub use exchange_refresh_token::exchange_refresh_token;
 pub use exchange_refresh_token::ExchangeRefreshTokenRequestBodyPayload;
 pub use exchange_refresh_token::ExchangeRefreshTokenResponsePayload;
+pub use fetch_password_policy::fetch_password_policy;
+pub use fetch_password_policy::CustomStrengthOptions;
+pub use fetch_password_policy::FetchPasswordPolicyResponsePayload;
+pub use fetch_project_config::fetch_project_config;
+pub use fetch_project_config::FetchProjectConfigResponsePayload;
+pub use fetch_project_config::IdpConfig;
 pub use fetch_providers_for_email::fetch_providers_for_email;
 pub use fetch_providers_for_email::FetchProvidersForEmailRequestBodyPayload;
 pub use fetch_providers_for_email::FetchProvidersForEmailResponsePayload;
+pub use generate_action_link::generate_action_link;
+pub use generate_action_link::GenerateActionLinkRequestBodyPayload;
+pub use generate_action_link::GenerateActionLinkResponsePayload;
 pub use get_user_data::get_user_data;
 pub use get_user_data::GetUserDataRequestBodyPayload;
 pub use get_user_data::GetUserDataResponsePayload;
@@ -144,6 +171,7 @@ pub use link_with_email_password::LinkWithEmailPasswordRequestBodyPayload;
 pub use link_with_email_password::LinkWithEmailPasswordResponsePayload;
 pub use link_with_oauth_credential::link_with_oauth_credential;
 pub use link_with_oauth_credential::LinkWithOAuthCredentialRequestBodyPayload;
+pub use link_with_oauth_credential::LinkWithOAuthCredentialRequestBodyPayloadBuilder;
 pub use link_with_oauth_credential::LinkWithOAuthCredentialResponsePayload;
 pub use send_email_verification::send_email_verification;
 pub use send_email_verification::SendEmailVerificationRequestBodyPayload;
@@ -168,7 +196,11 @@ pub use unlink_provider::UnlinkProviderRequestBodyPayload;
 pub use unlink_provider::UnlinkProviderResponsePayload;
 pub use update_profile::update_profile;
 pub use update_profile::UpdateProfileRequestBodyPayload;
+pub use update_profile::UpdateProfileRequestBodyPayloadBuilder;
 pub use update_profile::UpdateProfileResponsePayload;
+pub use verify_before_update_email::verify_before_update_email;
+pub use verify_before_update_email::VerifyBeforeUpdateEmailRequestBodyPayload;
+pub use verify_before_update_email::VerifyBeforeUpdateEmailResponsePayload;
 pub use verify_password_reset_code::verify_password_reset_code;
 pub use verify_password_reset_code::VerifyPasswordResetCodeRequestBodyPayload;
 pub use verify_password_reset_code::VerifyPasswordResetCodeResponsePayload;
@@ -0,0 +1,105 @@
+//! Implements the apply action code API of the Firebase Auth.
+//!
+//! You can apply an action code (out-of-band code) of email verification and email change by issuing an HTTP POST request to the Auth setAccountInfo endpoint.
+//!
+//! See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/update).
+
+use serde::{Deserialize, Serialize};
+
+use crate::ApiKey;
+use crate::Client;
+use crate::Endpoint;
+use crate::Result;
+
+/// Request body payload for the apply action code API.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/update).
+#[derive(Serialize)]
+pub struct ApplyActionCodeRequestBodyPayload {
+    /// The action code sent to the user's email.
+    #[serde(rename = "oobCode")]
+    oob_code: String,
+}
+
+impl ApplyActionCodeRequestBodyPayload {
+    /// Creates a new request body payload for the apply action code API.
+    ///
+    /// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/update).
+    ///
+    /// ## Arguments
+    /// - `oob_code` - The action code sent to the user's email.
+    pub fn new(oob_code: String) -> Self {
+        Self {
+            oob_code,
+        }
+    }
+}
+
+/// Response payload for the apply action code API.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/update).
+#[derive(Deserialize, Debug)]
+pub struct ApplyActionCodeResponsePayload {
+    /// The email of the account.
+    #[serde(rename = "email")]
+    pub email: Option<String>,
+    /// Whether or not the account's email has been verified.
+    #[serde(rename = "emailVerified")]
+    pub email_verified: Option<bool>,
+}
+
+/// Applies an action code of email verification and email change sent to the user's email.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/update).
+///
+/// ## Arguments
+/// - `client` - HTTP client.
+/// - `api_key` - Your Firebase project's API key.
+/// - `request_payload` - Request body payload.
+///
+/// ## Errors
+/// - `Error::HttpRequestError` - Failed to send a request.
+/// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+/// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+/// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+/// - `Error::AccountDisabled` - The user account has been disabled.
+/// - `Error::ApiError` - API error on the Firebase Auth.
+///
+/// ## Common error codes
+/// - EXPIRED_OOB_CODE: The action code has expired.
+/// - INVALID_OOB_CODE: The action code is invalid. This can happen if the code is malformed, expired, or has already been used.
+/// - USER_DISABLED: The user account has been disabled by an administrator.
+/// - EMAIL_NOT_FOUND: There is no user record corresponding to this identifier. The user may have been deleted.
+///
+/// ## Example
+/// ```
+/// use fars::api;
+/// use fars::Client;
+/// use fars::ApiKey;
+///
+/// let request_payload = api::ApplyActionCodeRequestBodyPayload::new(
+///     "oob-code".to_string(),
+/// );
+///
+/// let response_payload = api::apply_action_code(
+///     Client::new(),
+///     ApiKey::new("your-firebase-project-api-key"),
+///     request_payload,
+/// ).await?;
+/// ```
+pub async fn apply_action_code(
+    client: &Client,
+    api_key: &ApiKey,
+    request_payload: ApplyActionCodeRequestBodyPayload,
+) -> Result<ApplyActionCodeResponsePayload> {
+    client.send_post::<
+        ApplyActionCodeRequestBodyPayload,
+        ApplyActionCodeResponsePayload,
+    >(
+        Endpoint::Update,
+        api_key,
+        request_payload,
+        None,
+    )
+    .await
+}
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
+use crate::ExpiresIn;
 use crate::LanguageCode;
 use crate::ProviderUserInfo;
 use crate::Result;
@@ -76,7 +77,7 @@ pub struct ChangeEmailResponsePayload {
     pub refresh_token: Option<String>,
     /// The number of seconds in which the ID token expires.
     #[serde(rename = "expiresIn")]
-    pub expires_in: Option<String>,
+    pub expires_in: Option<ExpiresIn>,
 }
 
 /// Changes the email address associated with the user account.
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
+use crate::ExpiresIn;
 use crate::ProviderUserInfo;
 use crate::Result;
 
@@ -75,7 +76,7 @@ pub struct ChangePasswordResponsePayload {
     pub refresh_token: Option<String>,
     /// The number of seconds in which the ID token expires.
     #[serde(rename = "expiresIn")]
-    pub expires_in: Option<String>,
+    pub expires_in: Option<ExpiresIn>,
 }
 
 /// Changes the password associated with the user account.
@@ -0,0 +1,105 @@
+//! Implements the check action code API of the Firebase Auth.
+//!
+//! You can check an action code (out-of-band code) without applying it by issuing an HTTP POST request to the Auth resetPassword endpoint only with the code.
+//!
+//! See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/resetPassword).
+
+use serde::{Deserialize, Serialize};
+
+use crate::ApiKey;
+use crate::Client;
+use crate::Endpoint;
+use crate::Result;
+
+/// Request body payload for the check action code API.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/resetPassword).
+#[derive(Serialize)]
+pub struct CheckActionCodeRequestBodyPayload {
+    /// The action code sent to the user's email.
+    #[serde(rename = "oobCode")]
+    oob_code: String,
+}
+
+impl CheckActionCodeRequestBodyPayload {
+    /// Creates a new request body payload for the check action code API.
+    ///
+    /// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/resetPassword).
+    ///
+    /// ## Arguments
+    /// - `oob_code` - The action code sent to the user's email.
+    pub fn new(oob_code: String) -> Self {
+        Self {
+            oob_code,
+        }
+    }
+}
+
+/// Response payload for the check action code API.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/resetPassword).
+#[derive(Deserialize, Debug)]
+pub struct CheckActionCodeResponsePayload {
+    /// The email of the account that the action code targets.
+    #[serde(rename = "email")]
+    pub email: Option<String>,
+    /// The new email of the account for email change operations.
+    #[serde(rename = "newEmail")]
+    pub new_email: Option<String>,
+    /// Type of the action code, e.g. "PASSWORD_RESET" and "VERIFY_EMAIL".
+    #[serde(rename = "requestType")]
+    pub request_type: String,
+}
+
+/// Checks an action code sent to the user's email without applying it.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/resetPassword).
+///
+/// ## Arguments
+/// - `client` - HTTP client.
+/// - `api_key` - Your Firebase project's API key.
+/// - `request_payload` - Request body payload.
+///
+/// ## Errors
+/// - `Error::HttpRequestError` - Failed to send a request.
+/// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+/// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+/// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+/// - `Error::ApiError` - API error on the Firebase Auth.
+///
+/// ## Common error codes
+/// - EXPIRED_OOB_CODE: The action code has expired.
+/// - INVALID_OOB_CODE: The action code is invalid. This can happen if the code is malformed, expired, or has already been used.
+///
+/// ## Example
+/// ```
+/// use fars::api;
+/// use fars::Client;
+/// use fars::ApiKey;
+///
+/// let request_payload = api::CheckActionCodeRequestBodyPayload::new(
+///     "oob-code".to_string(),
+/// );
+///
+/// let response_payload = api::check_action_code(
+///     Client::new(),
+///     ApiKey::new("your-firebase-project-api-key"),
+///     request_payload,
+/// ).await?;
+/// ```
+pub async fn check_action_code(
+    client: &Client,
+    api_key: &ApiKey,
+    request_payload: CheckActionCodeRequestBodyPayload,
+) -> Result<CheckActionCodeResponsePayload> {
+    client.send_post::<
+        CheckActionCodeRequestBodyPayload,
+        CheckActionCodeResponsePayload,
+    >(
+        Endpoint::ResetPassword,
+        api_key,
+        request_payload,
+        None,
+    )
+    .await
+}
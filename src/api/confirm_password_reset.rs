@@ -70,6 +70,7 @@ pub struct ConfirmPasswordResetResponsePayload {
 /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
 /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
 /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+/// - `Error::AccountDisabled` - The user account has been disabled.
 /// - `Error::ApiError` - API error on the Firebase Auth.
 ///
 /// ## Common error codes
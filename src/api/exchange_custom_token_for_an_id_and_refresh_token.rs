@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
+use crate::ExpiresIn;
 use crate::Result;
 
 /// Request body payload for the exchange custom token for an ID and refresh token API.
@@ -52,7 +53,10 @@ pub struct ExchangeCustomTokenForAnIdAndRefreshTokenResponsePayload {
     pub refresh_token: String,
     /// The number of seconds in which the ID token expires.
     #[serde(rename = "expiresIn")]
-    pub expires_in: String,
+    pub expires_in: ExpiresIn,
+    /// Whether the user has been newly created by the sign in.
+    #[serde(rename = "isNewUser")]
+    pub is_new_user: Option<bool>,
 }
 
 /// Exchanges a custom token for an ID and refresh token.
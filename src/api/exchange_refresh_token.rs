@@ -9,7 +9,9 @@ use serde::{Deserialize, Serialize};
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
+use crate::ExpiresIn;
 use crate::Result;
+use crate::Uid;
 
 /// Request body payload for the exchange refresh token API.
 ///
@@ -46,7 +48,7 @@ impl ExchangeRefreshTokenRequestBodyPayload {
 pub struct ExchangeRefreshTokenResponsePayload {
     /// The number of seconds in which the ID token expires.
     #[serde(rename = "expires_in")]
-    pub expires_in: String,
+    pub expires_in: ExpiresIn,
     /// The type of the refresh token, always "Bearer".
     #[serde(rename = "token_type")]
     pub token_type: String,
@@ -58,7 +60,7 @@ pub struct ExchangeRefreshTokenResponsePayload {
     pub id_token: String,
     /// The uid corresponding to the provided ID token.
     #[serde(rename = "user_id")]
-    pub user_id: String,
+    pub user_id: Uid,
     /// Your Firebase project ID.
     #[serde(rename = "project_id")]
     pub project_id: String,
@@ -78,6 +80,7 @@ pub struct ExchangeRefreshTokenResponsePayload {
 /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
 /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
 /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+/// - `Error::AccountDisabled` - The user account has been disabled.
 /// - `Error::ApiError` - API error on the Firebase Auth.
 ///
 /// ## Common error codes
@@ -0,0 +1,99 @@
+//! Implements the fetch password policy API of the Firebase Auth.
+//!
+//! You can fetch the password policy of the project by issuing an HTTP GET request to the Auth v2 passwordPolicy endpoint.
+//!
+//! This API requires only the Firebase project API key, not any ID token.
+//!
+//! See also [document](https://cloud.google.com/identity-platform/docs/password-policy).
+
+use serde::Deserialize;
+
+use crate::ApiKey;
+use crate::Client;
+use crate::Endpoint;
+use crate::Result;
+
+/// Response payload for the fetch password policy API.
+///
+/// See also [document](https://cloud.google.com/identity-platform/docs/password-policy).
+#[derive(Deserialize, Debug)]
+pub struct FetchPasswordPolicyResponsePayload {
+    /// The custom strength options of the password.
+    #[serde(rename = "customStrengthOptions")]
+    pub custom_strength_options: Option<CustomStrengthOptions>,
+    /// The allowed non-alphanumeric characters.
+    #[serde(rename = "allowedNonAlphanumericCharacters")]
+    pub allowed_non_alphanumeric_characters: Option<Vec<String>>,
+    /// The enforcement state of the policy, "OFF" or "ENFORCE".
+    #[serde(rename = "enforcementState")]
+    pub enforcement_state: Option<String>,
+    /// Whether to force users to upgrade the password on signing in.
+    #[serde(rename = "forceUpgradeOnSignin")]
+    pub force_upgrade_on_signin: Option<bool>,
+    /// The schema version of the policy.
+    #[serde(rename = "schemaVersion")]
+    pub schema_version: Option<u32>,
+}
+
+/// Custom strength options of the password policy.
+///
+/// See also [document](https://cloud.google.com/identity-platform/docs/password-policy).
+#[derive(Deserialize, Debug, Default)]
+pub struct CustomStrengthOptions {
+    /// The minimum length of the password.
+    #[serde(rename = "minPasswordLength")]
+    pub min_password_length: Option<u32>,
+    /// The maximum length of the password.
+    #[serde(rename = "maxPasswordLength")]
+    pub max_password_length: Option<u32>,
+    /// Whether the password requires a lowercase character.
+    #[serde(rename = "containsLowercaseCharacter")]
+    pub contains_lowercase_character: Option<bool>,
+    /// Whether the password requires an uppercase character.
+    #[serde(rename = "containsUppercaseCharacter")]
+    pub contains_uppercase_character: Option<bool>,
+    /// Whether the password requires a numeric character.
+    #[serde(rename = "containsNumericCharacter")]
+    pub contains_numeric_character: Option<bool>,
+    /// Whether the password requires a non-alphanumeric character.
+    #[serde(rename = "containsNonAlphanumericCharacter")]
+    pub contains_non_alphanumeric_character: Option<bool>,
+}
+
+/// Fetches the password policy of the project.
+///
+/// See also [document](https://cloud.google.com/identity-platform/docs/password-policy).
+///
+/// ## Arguments
+/// - `client` - HTTP client.
+/// - `api_key` - Your Firebase project's API key.
+///
+/// ## Errors
+/// - `Error::HttpRequestError` - Failed to send a request.
+/// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+/// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+/// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+/// - `Error::ApiError` - API error on the Firebase Auth.
+///
+/// ## Example
+/// ```
+/// use fars::api;
+/// use fars::Client;
+/// use fars::ApiKey;
+///
+/// let response_payload = api::fetch_password_policy(
+///     Client::new(),
+///     ApiKey::new("your-firebase-project-api-key"),
+/// ).await?;
+/// ```
+pub async fn fetch_password_policy(
+    client: &Client,
+    api_key: &ApiKey,
+) -> Result<FetchPasswordPolicyResponsePayload> {
+    client
+        .send_get::<FetchPasswordPolicyResponsePayload>(
+            Endpoint::PasswordPolicy,
+            api_key,
+        )
+        .await
+}
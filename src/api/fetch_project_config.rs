@@ -0,0 +1,157 @@
+//! Implements the fetch project config API of the Firebase Auth.
+//!
+//! You can fetch the public configuration of the project, e.g. the enabled sign in methods,
+//! by issuing an HTTP GET request to the legacy Identity Toolkit API (v3) `relyingparty/getProjectConfig` endpoint,
+//! because the Auth `v1/projects` endpoint returns only the project ID and the authorized domains.
+//!
+//! This API requires only the Firebase project API key, not any ID token.
+//!
+//! ## NOTE
+//! Some fields may be omitted depending on the project, so all fields are optional.
+//!
+//! See also [API reference](https://developers.google.com/identity/toolkit/web/reference/relyingparty/getProjectConfig).
+
+use serde::Deserialize;
+
+use crate::ApiKey;
+use crate::Client;
+use crate::Endpoint;
+use crate::Result;
+
+/// Response payload for the fetch project config API.
+///
+/// See also [API reference](https://developers.google.com/identity/toolkit/web/reference/relyingparty/getProjectConfig).
+#[derive(Deserialize, Debug)]
+pub struct FetchProjectConfigResponsePayload {
+    /// The project ID of the Firebase project.
+    #[serde(rename = "projectId")]
+    pub project_id: Option<String>,
+    /// The authorized domains for the OAuth redirects.
+    #[serde(rename = "authorizedDomains")]
+    pub authorized_domains: Option<Vec<String>>,
+    /// Whether the email and password sign in is enabled.
+    #[serde(rename = "allowPasswordUser")]
+    pub allow_password_user: Option<bool>,
+    /// Whether the anonymous sign in is enabled.
+    #[serde(rename = "enableAnonymousUser")]
+    pub enable_anonymous_user: Option<bool>,
+    /// The configurations of the ID providers.
+    #[serde(rename = "idpConfig")]
+    pub idp_config: Option<Vec<IdpConfig>>,
+}
+
+/// Configuration of an ID provider in the project config.
+///
+/// See also [API reference](https://developers.google.com/identity/toolkit/web/reference/relyingparty/getProjectConfig).
+#[derive(Deserialize, Debug)]
+pub struct IdpConfig {
+    /// The provider ID, e.g. "google.com", or the legacy provider name, e.g. "GOOGLE".
+    #[serde(rename = "provider")]
+    pub provider: Option<String>,
+    /// Whether the provider is enabled.
+    #[serde(rename = "enabled")]
+    pub enabled: Option<bool>,
+}
+
+/// Fetches the public configuration of the project.
+///
+/// See also [API reference](https://developers.google.com/identity/toolkit/web/reference/relyingparty/getProjectConfig).
+///
+/// ## Arguments
+/// - `client` - HTTP client.
+/// - `api_key` - Your Firebase project's API key.
+///
+/// ## Errors
+/// - `Error::HttpRequestError` - Failed to send a request.
+/// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+/// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+/// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+/// - `Error::ApiError` - API error on the Firebase Auth.
+///
+/// ## Example
+/// ```
+/// use fars::api;
+/// use fars::Client;
+/// use fars::ApiKey;
+///
+/// let response_payload = api::fetch_project_config(
+///     Client::new(),
+///     ApiKey::new("your-firebase-project-api-key"),
+/// ).await?;
+/// ```
+pub async fn fetch_project_config(
+    client: &Client,
+    api_key: &ApiKey,
+) -> Result<FetchProjectConfigResponsePayload> {
+    client
+        .send_get::<FetchProjectConfigResponsePayload>(
+            Endpoint::GetProjectConfig,
+            api_key,
+        )
+        .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserializes_get_project_config_response() {
+        // NOTE: A response of the `relyingparty/getProjectConfig` endpoint with the secrets redacted.
+        let json = r#"{
+            "projectId": "123456789012",
+            "apiKey": "your-firebase-project-api-key",
+            "allowPasswordUser": true,
+            "idpConfig": [
+                {
+                    "provider": "google.com",
+                    "enabled": true,
+                    "experimentPercent": 100,
+                    "clientId": "123456789012-abcdef.apps.googleusercontent.com",
+                    "secret": "redacted",
+                    "whitelistedAudiences": []
+                },
+                {
+                    "provider": "github.com",
+                    "enabled": false,
+                    "experimentPercent": 100
+                }
+            ],
+            "authorizedDomains": [
+                "localhost",
+                "your-project.firebaseapp.com"
+            ],
+            "enableAnonymousUser": false,
+            "useEmailSending": true
+        }"#;
+
+        let payload: FetchProjectConfigResponsePayload =
+            serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            payload.project_id,
+            Some("123456789012".to_string())
+        );
+        assert_eq!(payload.allow_password_user, Some(true));
+        assert_eq!(
+            payload.enable_anonymous_user,
+            Some(false)
+        );
+        assert_eq!(
+            payload.authorized_domains,
+            Some(vec![
+                "localhost".to_string(),
+                "your-project.firebaseapp.com".to_string(),
+            ])
+        );
+
+        let idp_config = payload.idp_config.unwrap();
+        assert_eq!(idp_config.len(), 2);
+        assert_eq!(
+            idp_config[0].provider,
+            Some("google.com".to_string())
+        );
+        assert_eq!(idp_config[0].enabled, Some(true));
+        assert_eq!(idp_config[1].enabled, Some(false));
+    }
+}
@@ -0,0 +1,194 @@
+//! Implements the generate action link API of the Firebase Auth.
+//!
+//! You can generate an action link, e.g. of email verification and password reset, without sending an email
+//! by issuing an HTTP POST request to the Auth sendOobCode endpoint with `returnOobLink`.
+//!
+//! ## NOTE
+//! This is a privileged operation of the Admin SDK that requires the credentials of a service account,
+//! so it fails with `Error::RequiresAdminCredentials` without sending any request when called with a plain API key,
+//! because the endpoint would send an email to the user instead of returning the link.
+//!
+//! See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode)
+
+use serde::{Deserialize, Serialize};
+
+use crate::ActionCodeSettings;
+use crate::ApiKey;
+use crate::Client;
+use crate::Endpoint;
+use crate::Error;
+use crate::Result;
+
+/// Request body payload for the generate action link API.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode).
+#[derive(Serialize)]
+pub struct GenerateActionLinkRequestBodyPayload {
+    /// The kind of OOB code to return, e.g. "PASSWORD_RESET" and "VERIFY_EMAIL".
+    #[serde(rename = "requestType")]
+    request_type: String,
+    /// User's email address.
+    #[serde(rename = "email")]
+    email: String,
+    /// Whether to return the link instead of sending an email.
+    #[serde(rename = "returnOobLink")]
+    return_oob_link: bool,
+    /// The settings of the action code.
+    #[serde(flatten)]
+    action_code_settings: Option<ActionCodeSettings>,
+}
+
+impl GenerateActionLinkRequestBodyPayload {
+    /// Creates a new request body payload for the generate action link API.
+    ///
+    /// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode).
+    ///
+    /// ## Arguments
+    /// - `request_type` - The kind of OOB code to return, e.g. "PASSWORD_RESET" and "VERIFY_EMAIL".
+    /// - `email` - User's email address.
+    /// - `action_code_settings` - The optional settings of the action code.
+    pub fn new(
+        request_type: String,
+        email: String,
+        action_code_settings: Option<ActionCodeSettings>,
+    ) -> Self {
+        Self {
+            request_type,
+            email,
+            return_oob_link: true,
+            action_code_settings,
+        }
+    }
+}
+
+/// Response payload for the generate action link API.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode).
+#[derive(Deserialize, Debug)]
+pub struct GenerateActionLinkResponsePayload {
+    /// User's email address.
+    #[serde(rename = "email")]
+    pub email: Option<String>,
+    /// The generated OOB code.
+    #[serde(rename = "oobCode")]
+    pub oob_code: Option<String>,
+    /// The generated action link.
+    #[serde(rename = "oobLink")]
+    pub oob_link: String,
+}
+
+/// Generates an action link for the given email address without sending an email.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode).
+///
+/// ## Arguments
+/// - `client` - HTTP client.
+/// - `api_key` - Your Firebase project's API key.
+/// - `request_payload` - Request body payload.
+///
+/// ## NOTE
+/// The credentials of a service account must be set as the `Authorization` header by [`crate::Client::with_default_headers`],
+/// e.g. `Bearer {OAuth access token}` (or `Bearer owner` for the Firebase Auth Emulator).
+/// Without it, this fails with `Error::RequiresAdminCredentials` without sending any request.
+///
+/// ## Errors
+/// - `Error::InvalidHeaderValue` - Invalid header value.
+/// - `Error::HttpRequestError` - Failed to send a request.
+/// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+/// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+/// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+/// - `Error::RequiresAdminCredentials` - The operation requires the credentials of a service account, i.e. the `Authorization` header is not set or is rejected.
+/// - `Error::ApiError` - API error on the Firebase Auth.
+///
+/// ## Common error codes
+/// - EMAIL_NOT_FOUND: There is no user record corresponding to this identifier. The user may have been deleted.
+///
+/// ## Example
+/// ```
+/// use fars::api;
+/// use fars::Client;
+/// use fars::ApiKey;
+///
+/// let request_payload = api::GenerateActionLinkRequestBodyPayload::new(
+///     "PASSWORD_RESET".to_string(),
+///     "email".to_string(),
+///     None, // action code settings
+/// );
+///
+/// let response_payload = api::generate_action_link(
+///     Client::new(),
+///     ApiKey::new("your-firebase-project-api-key"),
+///     request_payload,
+/// ).await?;
+/// ```
+pub async fn generate_action_link(
+    client: &Client,
+    api_key: &ApiKey,
+    request_payload: GenerateActionLinkRequestBodyPayload,
+) -> Result<GenerateActionLinkResponsePayload> {
+    // NOTE: Do not send the request with a plain API key, which sends an email to the user instead of returning the link.
+    if !client.has_authorization() {
+        return Err(Error::RequiresAdminCredentials {
+            operation: "generate action link",
+        });
+    }
+
+    client.send_post::<
+        GenerateActionLinkRequestBodyPayload,
+        GenerateActionLinkResponsePayload,
+    >(
+        Endpoint::SendOobCode,
+        api_key,
+        request_payload,
+        None,
+    )
+    .await
+    .map_err(|error| {
+        error.map_requires_admin_credentials("generate action link")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn refuses_plain_api_key_without_sending_request() {
+        // NOTE: The unreachable emulator host fails with `Error::HttpRequestError` if a request is sent.
+        let client = Client::new().with_emulator_host("127.0.0.1:1");
+
+        let result = generate_action_link(
+            &client,
+            &ApiKey::new("api-key"),
+            GenerateActionLinkRequestBodyPayload::new(
+                "PASSWORD_RESET".to_string(),
+                "user@example.com".to_string(),
+                None,
+            ),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::RequiresAdminCredentials {
+                operation: "generate action link",
+            })
+        ));
+    }
+
+    #[test]
+    fn deserializes_response_with_link() {
+        let json = r#"{
+            "email": "user@example.com",
+            "oobLink": "https://example.com/__/auth/action?mode=resetPassword&oobCode=code"
+        }"#;
+
+        let response: GenerateActionLinkResponsePayload =
+            serde_json::from_str(json).unwrap();
+
+        assert_eq!(
+            response.oob_link,
+            "https://example.com/__/auth/action?mode=resetPassword&oobCode=code"
+        );
+    }
+}
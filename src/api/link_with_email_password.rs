@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
+use crate::ExpiresIn;
 use crate::ProviderUserInfo;
 use crate::Result;
 
@@ -88,7 +89,7 @@ pub struct LinkWithEmailPasswordResponsePayload {
     pub refresh_token: String,
     /// The number of seconds in which the ID token expires.
     #[serde(rename = "expiresIn")]
-    pub expires_in: String,
+    pub expires_in: ExpiresIn,
 }
 
 /// Links the user account with the given credentials.
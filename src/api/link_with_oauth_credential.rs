@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
+use crate::ExpiresIn;
 use crate::IdpPostBody;
 use crate::Result;
 
@@ -53,13 +54,148 @@ impl LinkWithOAuthCredentialRequestBodyPayload {
         Self {
             id_token,
             request_uri,
-            post_body: post_body.query,
+            post_body: post_body
+                .as_query()
+                .to_string(),
             return_secure_token: true,
             return_idp_credential,
         }
     }
 }
 
+impl LinkWithOAuthCredentialRequestBodyPayload {
+    /// Creates a new builder of the request body payload for the link with OAuth credential API,
+    /// which requires the ID token, the request URI and the post body before building to prevent misordered arguments.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use fars::api;
+    /// use fars::IdpPostBody;
+    /// use fars::ProviderId;
+    ///
+    /// let request_payload = api::LinkWithOAuthCredentialRequestBodyPayload::builder()
+    ///     .id_token("id-token".to_string())
+    ///     .request_uri("https://your-app.com/redirect/path/auth/handler".to_string())
+    ///     .post_body(IdpPostBody::new(
+    ///         ProviderId::Google,
+    ///         HashMap::from([(
+    ///             "access_token",
+    ///             "google-access-token".to_string(),
+    ///         )]),
+    ///     )?)
+    ///     .build();
+    /// ```
+    pub fn builder(
+    ) -> LinkWithOAuthCredentialRequestBodyPayloadBuilder<(), (), ()> {
+        LinkWithOAuthCredentialRequestBodyPayloadBuilder {
+            id_token: (),
+            request_uri: (),
+            post_body: (),
+            return_idp_credential: false,
+        }
+    }
+}
+
+/// Builder of [`LinkWithOAuthCredentialRequestBodyPayload`] created by [`LinkWithOAuthCredentialRequestBodyPayload::builder`].
+///
+/// The type parameters `I`, `R` and `P` are the types of the ID token, the request URI and the post body after they have been set,
+/// which enables [`LinkWithOAuthCredentialRequestBodyPayloadBuilder::build`].
+pub struct LinkWithOAuthCredentialRequestBodyPayloadBuilder<I, R, P> {
+    id_token: I,
+    request_uri: R,
+    post_body: P,
+    return_idp_credential: bool,
+}
+
+impl<R, P> LinkWithOAuthCredentialRequestBodyPayloadBuilder<(), R, P> {
+    /// Sets the Firebase ID token of the account you are trying to link the credential to.
+    ///
+    /// ## Arguments
+    /// - `id_token` - The Firebase ID token of the account you are trying to link the credential to.
+    pub fn id_token(
+        self,
+        id_token: String,
+    ) -> LinkWithOAuthCredentialRequestBodyPayloadBuilder<String, R, P> {
+        LinkWithOAuthCredentialRequestBodyPayloadBuilder {
+            id_token,
+            request_uri: self.request_uri,
+            post_body: self.post_body,
+            return_idp_credential: self.return_idp_credential,
+        }
+    }
+}
+
+impl<I, P> LinkWithOAuthCredentialRequestBodyPayloadBuilder<I, (), P> {
+    /// Sets the URI to which the IDP redirects the user back.
+    ///
+    /// ## Arguments
+    /// - `request_uri` - The URI to which the IDP redirects the user back.
+    pub fn request_uri(
+        self,
+        request_uri: String,
+    ) -> LinkWithOAuthCredentialRequestBodyPayloadBuilder<I, String, P> {
+        LinkWithOAuthCredentialRequestBodyPayloadBuilder {
+            id_token: self.id_token,
+            request_uri,
+            post_body: self.post_body,
+            return_idp_credential: self.return_idp_credential,
+        }
+    }
+}
+
+impl<I, R> LinkWithOAuthCredentialRequestBodyPayloadBuilder<I, R, ()> {
+    /// Sets the OAuth credential (an ID token or access token) and provider ID which issues the credential.
+    ///
+    /// ## Arguments
+    /// - `post_body` - Contains the OAuth credential (an ID token or access token) and provider ID which issues the credential.
+    pub fn post_body(
+        self,
+        post_body: IdpPostBody,
+    ) -> LinkWithOAuthCredentialRequestBodyPayloadBuilder<I, R, IdpPostBody>
+    {
+        LinkWithOAuthCredentialRequestBodyPayloadBuilder {
+            id_token: self.id_token,
+            request_uri: self.request_uri,
+            post_body,
+            return_idp_credential: self.return_idp_credential,
+        }
+    }
+}
+
+impl<I, R, P> LinkWithOAuthCredentialRequestBodyPayloadBuilder<I, R, P> {
+    /// Sets whether to force the return of the OAuth credential on the following errors: FEDERATED_USER_ID_ALREADY_LINKED and EMAIL_EXISTS,
+    /// `false` by default.
+    ///
+    /// ## Arguments
+    /// - `return_idp_credential` - Whether to force the return of the OAuth credential.
+    pub fn return_idp_credential(
+        mut self,
+        return_idp_credential: bool,
+    ) -> Self {
+        self.return_idp_credential = return_idp_credential;
+        self
+    }
+}
+
+impl
+    LinkWithOAuthCredentialRequestBodyPayloadBuilder<
+        String,
+        String,
+        IdpPostBody,
+    >
+{
+    /// Builds the request body payload for the link with OAuth credential API.
+    pub fn build(self) -> LinkWithOAuthCredentialRequestBodyPayload {
+        LinkWithOAuthCredentialRequestBodyPayload::new(
+            self.id_token,
+            self.request_uri,
+            self.post_body,
+            self.return_idp_credential,
+        )
+    }
+}
+
 /// Response payload for the link with OAuth credential API.
 ///
 /// See also [API reference](https://firebase.google.com/docs/reference/rest/auth#section-link-with-oauth-credential).
@@ -115,7 +251,7 @@ pub struct LinkWithOAuthCredentialResponsePayload {
     pub refresh_token: String,
     /// The number of seconds in which the ID token expires.
     #[serde(rename = "expiresIn")]
-    pub expires_in: String,
+    pub expires_in: ExpiresIn,
 }
 
 /// Links the authenticated user with a federated OAuth credential.
@@ -6,6 +6,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::ActionCodeSettings;
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
@@ -23,6 +24,9 @@ pub struct SendEmailVerificationRequestBodyPayload {
     /// The Firebase ID token of the user to verify.
     #[serde(rename = "idToken")]
     id_token: String,
+    /// The settings of the action code.
+    #[serde(flatten)]
+    action_code_settings: Option<ActionCodeSettings>,
 }
 
 impl SendEmailVerificationRequestBodyPayload {
@@ -32,10 +36,15 @@ impl SendEmailVerificationRequestBodyPayload {
     ///
     /// ## Arguments
     /// - `id_token` - The Firebase ID token of the user to verify.
-    pub fn new(id_token: String) -> Self {
+    /// - `action_code_settings` - The optional settings of the action code.
+    pub fn new(
+        id_token: String,
+        action_code_settings: Option<ActionCodeSettings>,
+    ) -> Self {
         Self {
             request_type: "VERIFY_EMAIL".to_string(),
             id_token,
+            action_code_settings,
         }
     }
 }
@@ -48,6 +57,18 @@ pub struct SendEmailVerificationResponsePayload {
     /// The email of the account.
     #[serde(rename = "email")]
     pub email: String,
+    /// The OOB code sent by the email.
+    ///
+    /// ## NOTE
+    /// This is only returned by the Firebase Local Emulator Suite, not by the production.
+    #[serde(rename = "oobCode")]
+    pub oob_code: Option<String>,
+    /// The action link sent by the email.
+    ///
+    /// ## NOTE
+    /// This is only returned by the Firebase Local Emulator Suite, not by the production.
+    #[serde(rename = "oobLink")]
+    pub oob_link: Option<String>,
 }
 
 /// Sends an email verification to the specified user.
@@ -81,6 +102,7 @@ pub struct SendEmailVerificationResponsePayload {
 ///
 /// let request_payload = api::SendEmailVerificationRequestBodyPayload::new(
 ///     "id-token".to_string(),
+///     None, // action code settings
 /// );
 ///
 /// let response_payload = api::send_email_verification(
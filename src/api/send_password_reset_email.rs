@@ -6,6 +6,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::ActionCodeSettings;
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
@@ -23,6 +24,9 @@ pub struct SendPasswordResetEmailRequestBodyPayload {
     /// User's email address.
     #[serde(rename = "email")]
     email: String,
+    /// The settings of the action code.
+    #[serde(flatten)]
+    action_code_settings: Option<ActionCodeSettings>,
 }
 
 impl SendPasswordResetEmailRequestBodyPayload {
@@ -32,10 +36,15 @@ impl SendPasswordResetEmailRequestBodyPayload {
     ///
     /// ## Arguments
     /// - `email` - User's email address.
-    pub fn new(email: String) -> Self {
+    /// - `action_code_settings` - The optional settings of the action code.
+    pub fn new(
+        email: String,
+        action_code_settings: Option<ActionCodeSettings>,
+    ) -> Self {
         Self {
             request_type: "PASSWORD_RESET".to_string(),
             email,
+            action_code_settings,
         }
     }
 }
@@ -48,6 +57,18 @@ pub struct SendPasswordResetEmailResponsePayload {
     /// User's email address.
     #[serde(rename = "email")]
     pub email: String,
+    /// The OOB code sent by the email.
+    ///
+    /// ## NOTE
+    /// This is only returned by the Firebase Local Emulator Suite, not by the production.
+    #[serde(rename = "oobCode")]
+    pub oob_code: Option<String>,
+    /// The action link sent by the email.
+    ///
+    /// ## NOTE
+    /// This is only returned by the Firebase Local Emulator Suite, not by the production.
+    #[serde(rename = "oobLink")]
+    pub oob_link: Option<String>,
 }
 
 /// Sends a password reset email to the given email address.
@@ -71,6 +92,9 @@ pub struct SendPasswordResetEmailResponsePayload {
 /// ## Common error codes
 /// - EMAIL_NOT_FOUND: There is no user record corresponding to this identifier. The user may have been deleted.
 ///
+/// ## NOTE
+/// EMAIL_NOT_FOUND is not returned when the email enumeration protection is enabled on the project.
+///
 /// ## Example
 /// ```
 /// use fars::api;
@@ -79,6 +103,7 @@ pub struct SendPasswordResetEmailResponsePayload {
 ///
 /// let request_payload = api::SendPasswordResetEmailRequestBodyPayload::new(
 ///     "email".to_string(),
+///     None, // action code settings
 /// );
 ///
 /// let response_payload = api::send_password_reset_email(
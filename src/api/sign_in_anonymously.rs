@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
+use crate::ExpiresIn;
 use crate::Result;
 
 /// Request body payload for the sign in anonymously API.
@@ -51,7 +52,7 @@ pub struct SignInAnonymouslyResponsePayload {
     pub refresh_token: String,
     /// The number of seconds in which the ID token expires.
     #[serde(rename = "expiresIn")]
-    pub expires_in: String,
+    pub expires_in: ExpiresIn,
     /// The uid of the newly created user.
     #[serde(rename = "localId")]
     pub local_id: String,
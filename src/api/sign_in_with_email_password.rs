@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
+use crate::Error;
+use crate::ExpiresIn;
 use crate::Result;
 
 /// Request body payload for the sign in with email password API.
@@ -63,7 +65,7 @@ pub struct SignInWithEmailPasswordResponsePayload {
     pub refresh_token: String,
     /// The number of seconds in which the ID token expires.
     #[serde(rename = "expiresIn")]
-    pub expires_in: String,
+    pub expires_in: ExpiresIn,
     /// The uid of the authenticated user.
     #[serde(rename = "localId")]
     pub local_id: String,
@@ -86,11 +88,14 @@ pub struct SignInWithEmailPasswordResponsePayload {
 /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
 /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
 /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+/// - `Error::AccountDisabled` - The user account has been disabled.
 /// - `Error::ApiError` - API error on the Firebase Auth.
+/// - `Error::MfaRequired` - Multi-factor authentication is required to complete signing in.
 ///
 /// ## Common error codes
 /// - EMAIL_NOT_FOUND: There is no user record corresponding to this identifier. The user may have been deleted.
 /// - INVALID_PASSWORD: The password is invalid or the user does not have a password.
+/// - INVALID_LOGIN_CREDENTIALS: The email or the password is invalid, returned instead of the above with the email enumeration protection.
 /// - USER_DISABLED: The user account has been disabled by an administrator.
 ///
 /// ## Example
@@ -125,4 +130,5 @@ pub async fn sign_in_with_email_password(
         None,
     )
     .await
+    .map_err(Error::map_mfa_required)
 }
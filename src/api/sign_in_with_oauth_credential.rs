@@ -9,6 +9,8 @@ use serde::{Deserialize, Serialize};
 use crate::ApiKey;
 use crate::Client;
 use crate::Endpoint;
+use crate::Error;
+use crate::ExpiresIn;
 use crate::IdpPostBody;
 use crate::Result;
 
@@ -29,6 +31,18 @@ pub struct SignInWithOAuthCredentialRequestBodyPayload {
     /// Whether to force the return of the OAuth credential on the following errors: FEDERATED_USER_ID_ALREADY_LINKED and EMAIL_EXISTS.
     #[serde(rename = "returnIdpCredential")]
     return_ipd_credential: bool,
+    /// The session ID returned by the createAuthUri to validate the nonce of the OAuth credential.
+    #[serde(
+        rename = "sessionId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    session_id: Option<String>,
+    /// The pending token returned by a prior request to complete the sign in, e.g. of account linking.
+    #[serde(
+        rename = "pendingToken",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pending_token: Option<String>,
 }
 
 impl SignInWithOAuthCredentialRequestBodyPayload {
@@ -47,11 +61,39 @@ impl SignInWithOAuthCredentialRequestBodyPayload {
     ) -> Self {
         Self {
             request_uri,
-            post_body: post_body.query,
+            post_body: post_body
+                .as_query()
+                .to_string(),
             return_secure_token: true,
             return_ipd_credential,
+            session_id: None,
+            pending_token: None,
         }
     }
+
+    /// Sets the session ID returned by the createAuthUri to validate the nonce of the OAuth credential.
+    ///
+    /// ## Arguments
+    /// - `session_id` - The session ID returned by the createAuthUri.
+    pub fn with_session_id(
+        mut self,
+        session_id: String,
+    ) -> Self {
+        self.session_id = Some(session_id);
+        self
+    }
+
+    /// Sets the pending token returned by a prior request to complete the sign in, e.g. of account linking.
+    ///
+    /// ## Arguments
+    /// - `pending_token` - The pending token returned by a prior request.
+    pub fn with_pending_token(
+        mut self,
+        pending_token: String,
+    ) -> Self {
+        self.pending_token = Some(pending_token);
+        self
+    }
 }
 
 /// Response payload for the sign in with OAuth credential API.
@@ -109,11 +151,17 @@ pub struct SignInWithOAuthCredentialResponsePayload {
     pub refresh_token: String,
     /// The number of seconds in which the ID token expires.
     #[serde(rename = "expiresIn")]
-    pub expires_in: String,
+    pub expires_in: ExpiresIn,
     /// Whether another account with the same credential already exists.
     /// The user will need to sign in to the original account and then link the current credential to it.
     #[serde(rename = "needConfirmation")]
     pub need_confirmation: Option<bool>,
+    /// Whether the user has been newly created by the sign in.
+    #[serde(rename = "isNewUser")]
+    pub is_new_user: Option<bool>,
+    /// The pending token to complete the sign in by a subsequent request if available.
+    #[serde(rename = "pendingToken")]
+    pub pending_token: Option<String>,
     /// Kind.
     #[serde(rename = "kind")]
     pub kind: Option<String>,
@@ -134,6 +182,8 @@ pub struct SignInWithOAuthCredentialResponsePayload {
 /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
 /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
 /// - `Error::ApiError` - API error on the Firebase Auth.
+/// - `Error::MfaRequired` - Multi-factor authentication is required to complete signing in.
+/// - `Error::AccountLinkingRequired` - Another account with the same email already exists, so the credential must be linked to it.
 ///
 /// ## Common error codes
 /// - OPERATION_NOT_ALLOWED: The corresponding provider is disabled for this project.
@@ -181,4 +231,6 @@ pub async fn sign_in_with_oauth_credential(
         None,
     )
     .await
+    .map_err(Error::map_mfa_required)
+    .map_err(Error::map_account_linking_required)
 }
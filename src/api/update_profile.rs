@@ -12,6 +12,7 @@ use crate::ApiKey;
 use crate::Client;
 use crate::DeleteAttribute;
 use crate::Endpoint;
+use crate::ExpiresIn;
 use crate::ProviderUserInfo;
 use crate::Result;
 
@@ -75,6 +76,124 @@ impl UpdateProfileRequestBodyPayload {
     }
 }
 
+impl UpdateProfileRequestBodyPayload {
+    /// Creates a new builder of the request body payload for the update profile API,
+    /// which requires the ID token before building to prevent misordered arguments.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::api;
+    ///
+    /// let request_payload = api::UpdateProfileRequestBodyPayload::builder()
+    ///     .id_token("id-token".to_string())
+    ///     .display_name("new-display-name".to_string())
+    ///     .return_secure_token(true)
+    ///     .build();
+    /// ```
+    pub fn builder() -> UpdateProfileRequestBodyPayloadBuilder<()> {
+        UpdateProfileRequestBodyPayloadBuilder {
+            id_token: (),
+            display_name: None,
+            photo_url: None,
+            delete_attribute: None,
+            return_secure_token: false,
+        }
+    }
+}
+
+/// Builder of [`UpdateProfileRequestBodyPayload`] created by [`UpdateProfileRequestBodyPayload::builder`].
+///
+/// The type parameter `I` is `String` after the ID token has been set, which enables [`UpdateProfileRequestBodyPayloadBuilder::build`].
+pub struct UpdateProfileRequestBodyPayloadBuilder<I> {
+    id_token: I,
+    display_name: Option<String>,
+    photo_url: Option<String>,
+    delete_attribute: Option<HashSet<DeleteAttribute>>,
+    return_secure_token: bool,
+}
+
+impl UpdateProfileRequestBodyPayloadBuilder<()> {
+    /// Sets a Firebase Auth ID token for the user.
+    ///
+    /// ## Arguments
+    /// - `id_token` - A Firebase Auth ID token for the user.
+    pub fn id_token(
+        self,
+        id_token: String,
+    ) -> UpdateProfileRequestBodyPayloadBuilder<String> {
+        UpdateProfileRequestBodyPayloadBuilder {
+            id_token,
+            display_name: self.display_name,
+            photo_url: self.photo_url,
+            delete_attribute: self.delete_attribute,
+            return_secure_token: self.return_secure_token,
+        }
+    }
+}
+
+impl<I> UpdateProfileRequestBodyPayloadBuilder<I> {
+    /// Sets the user's new display name.
+    ///
+    /// ## Arguments
+    /// - `display_name` - The user's new display name.
+    pub fn display_name(
+        mut self,
+        display_name: String,
+    ) -> Self {
+        self.display_name = Some(display_name);
+        self
+    }
+
+    /// Sets the user's new photo url.
+    ///
+    /// ## Arguments
+    /// - `photo_url` - The user's new photo url.
+    pub fn photo_url(
+        mut self,
+        photo_url: String,
+    ) -> Self {
+        self.photo_url = Some(photo_url);
+        self
+    }
+
+    /// Sets the attributes to delete.
+    ///
+    /// ## Arguments
+    /// - `delete_attribute` - List of attributes to delete.
+    pub fn delete_attribute(
+        mut self,
+        delete_attribute: HashSet<DeleteAttribute>,
+    ) -> Self {
+        self.delete_attribute = Some(delete_attribute);
+        self
+    }
+
+    /// Sets whether or not to return an ID and refresh token, `false` by default.
+    ///
+    /// ## Arguments
+    /// - `return_secure_token` - Whether or not to return an ID and refresh token.
+    pub fn return_secure_token(
+        mut self,
+        return_secure_token: bool,
+    ) -> Self {
+        self.return_secure_token = return_secure_token;
+        self
+    }
+}
+
+impl UpdateProfileRequestBodyPayloadBuilder<String> {
+    /// Builds the request body payload for the update profile API.
+    pub fn build(self) -> UpdateProfileRequestBodyPayload {
+        UpdateProfileRequestBodyPayload::new(
+            self.id_token,
+            self.display_name,
+            self.photo_url,
+            self.delete_attribute,
+            self.return_secure_token,
+        )
+    }
+}
+
 /// Response payload for the update profile API.
 ///
 /// See also [API reference](https://firebase.google.com/docs/reference/rest/auth#section-update-profile).
@@ -106,7 +225,7 @@ pub struct UpdateProfileResponsePayload {
     pub refresh_token: Option<String>,
     /// The number of seconds in which the ID token expires.
     #[serde(rename = "expiresIn")]
-    pub expires_in: Option<String>,
+    pub expires_in: Option<ExpiresIn>,
 }
 
 /// Updates a user's profile information.
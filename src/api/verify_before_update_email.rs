@@ -0,0 +1,128 @@
+//! Implements the verify before update email API of the Firebase Auth.
+//!
+//! You can send a verification email to a new email of the current user by issuing an HTTP POST request to the Auth getOobConfirmationCode endpoint.
+//! The email of the user is updated after the user clicks the link in the email.
+//!
+//! See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode).
+
+use serde::{Deserialize, Serialize};
+
+use crate::ActionCodeSettings;
+use crate::ApiKey;
+use crate::Client;
+use crate::Endpoint;
+use crate::LanguageCode;
+use crate::Result;
+
+/// Request body payload for the verify before update email API.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode).
+#[derive(Serialize)]
+pub struct VerifyBeforeUpdateEmailRequestBodyPayload {
+    /// The type of confirmation code to send. Should always be "VERIFY_AND_CHANGE_EMAIL".
+    #[serde(rename = "requestType")]
+    request_type: String,
+    /// The Firebase ID token of the user to update the email.
+    #[serde(rename = "idToken")]
+    id_token: String,
+    /// The new email of the user.
+    #[serde(rename = "newEmail")]
+    new_email: String,
+    /// The settings of the action code.
+    #[serde(flatten)]
+    action_code_settings: Option<ActionCodeSettings>,
+}
+
+impl VerifyBeforeUpdateEmailRequestBodyPayload {
+    /// Creates a new request body payload for the verify before update email API.
+    ///
+    /// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode).
+    ///
+    /// ## Arguments
+    /// - `id_token` - The Firebase ID token of the user to update the email.
+    /// - `new_email` - The new email of the user.
+    /// - `action_code_settings` - The optional settings of the action code.
+    pub fn new(
+        id_token: String,
+        new_email: String,
+        action_code_settings: Option<ActionCodeSettings>,
+    ) -> Self {
+        Self {
+            request_type: "VERIFY_AND_CHANGE_EMAIL".to_string(),
+            id_token,
+            new_email,
+            action_code_settings,
+        }
+    }
+}
+
+/// Response payload for the verify before update email API.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode).
+#[derive(Deserialize, Debug)]
+pub struct VerifyBeforeUpdateEmailResponsePayload {
+    /// The current email of the account.
+    #[serde(rename = "email")]
+    pub email: Option<String>,
+}
+
+/// Sends a verification email to the new email of the specified user before updating the email.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/sendOobCode).
+///
+/// ## Arguments
+/// - `client` - HTTP client.
+/// - `api_key` - Your Firebase project's API key.
+/// - `request_payload` - Request body payload.
+/// - `locale` - The BCP 47 language code, eg: en-US.
+///
+/// ## Errors
+/// - `Error::InvalidHeaderValue` - Invalid header value.
+/// - `Error::HttpRequestError` - Failed to send a request.
+/// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+/// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+/// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+/// - `Error::InvalidIdToken` - Invalid ID token.
+/// - `Error::ApiError` - API error on the Firebase Auth.
+///
+/// ## Common error codes
+/// - INVALID_ID_TOKEN: The user's credential is no longer valid. The user must sign in again.
+/// - EMAIL_EXISTS: The email address is already in use by another account.
+/// - CREDENTIAL_TOO_OLD_LOGIN_AGAIN: The user's credential is too old. The user must sign in again.
+///
+/// ## Example
+/// ```
+/// use fars::api;
+/// use fars::Client;
+/// use fars::ApiKey;
+///
+/// let request_payload = api::VerifyBeforeUpdateEmailRequestBodyPayload::new(
+///     "id-token".to_string(),
+///     "new-email".to_string(),
+///     None, // action code settings
+/// );
+///
+/// let response_payload = api::verify_before_update_email(
+///     Client::new(),
+///     ApiKey::new("your-firebase-project-api-key"),
+///     request_payload,
+///     None, // locale
+/// ).await?;
+/// ```
+pub async fn verify_before_update_email(
+    client: &Client,
+    api_key: &ApiKey,
+    request_payload: VerifyBeforeUpdateEmailRequestBodyPayload,
+    locale: Option<LanguageCode>,
+) -> Result<VerifyBeforeUpdateEmailResponsePayload> {
+    client.send_post::<
+        VerifyBeforeUpdateEmailRequestBodyPayload,
+        VerifyBeforeUpdateEmailResponsePayload,
+    >(
+        Endpoint::SendOobCode,
+        api_key,
+        request_payload,
+        locale,
+    )
+    .await
+}
@@ -0,0 +1,481 @@
+//! A session wrapper that caches the user data.
+//!
+//! See [`crate::CachedSession`].
+
+use std::collections::HashSet;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::ActionCodeSettings;
+use crate::DeleteAttribute;
+use crate::DisplayName;
+use crate::Email;
+use crate::IdpPostBody;
+use crate::LanguageCode;
+use crate::OAuthRequestUri;
+use crate::Password;
+use crate::PhotoUrl;
+use crate::ProviderId;
+use crate::Result;
+use crate::Session;
+use crate::UserData;
+
+/// A wrapper of [`Session`] that caches the last user data for a TTL,
+/// e.g. to avoid redundant lookups in UIs that re-render.
+///
+/// The cache is invalidated automatically by the methods that mutate the user on this wrapper,
+/// e.g. [`CachedSession::update_profile`],
+/// [`CachedSession::change_email`], [`CachedSession::link_with_oauth_credential`] and [`CachedSession::unlink_provider`].
+/// Use [`CachedSession::invalidate_user_data`] to invalidate it manually,
+/// e.g. after the user data has been changed by other sessions or the Admin SDK.
+///
+/// ## NOTE
+/// This is not available on `wasm32` targets because `std::time::Instant` is not available.
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use fars::CachedSession;
+/// use fars::Config;
+/// use fars::ApiKey;
+/// use fars::Email;
+/// use fars::Password;
+///
+/// let config = Config::new(
+///     ApiKey::new("your-firebase-project-api-key"),
+/// );
+/// let session = config.sign_in_with_email_password(
+///     Email::new("user@example"),
+///     Password::new("password"),
+/// ).await?;
+///
+/// let cached = CachedSession::new(session, Duration::from_secs(60));
+///
+/// // Fetches the user data from the Firebase Auth.
+/// let (cached, user_data) = cached.get_user_data().await?;
+///
+/// // Returns the cached user data within the TTL.
+/// let (cached, user_data) = cached.get_user_data().await?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct CachedSession {
+    /// The wrapped session.
+    session: Session,
+    /// The time to live of the cached user data.
+    ttl: Duration,
+    /// The cached user data and the time when it has been fetched.
+    cache: Option<(UserData, Instant)>,
+}
+
+impl CachedSession {
+    /// Creates a new wrapper of the session with the TTL of the cached user data.
+    ///
+    /// ## Arguments
+    /// - `session` - The session to wrap.
+    /// - `ttl` - The time to live of the cached user data.
+    pub fn new(
+        session: Session,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            session,
+            ttl,
+            cache: None,
+        }
+    }
+
+    /// Returns the wrapped session.
+    pub fn session(&self) -> &Session {
+        &self.session
+    }
+
+    /// Unwraps the session to call other methods of the session.
+    pub fn into_session(self) -> Session {
+        self.session
+    }
+
+    /// Invalidates the cached user data to fetch it on the next [`CachedSession::get_user_data`].
+    pub fn invalidate_user_data(&mut self) {
+        self.cache = None;
+    }
+
+    /// Gets the user data, returning the cached one within the TTL.
+    ///
+    /// See also [`Session::get_user_data`].
+    ///
+    /// ## Returns
+    /// New wrapper to replace the consumed one and the user data.
+    pub async fn get_user_data(self) -> Result<(Self, UserData)> {
+        // Return the cached user data within the TTL.
+        if let Some((user_data, fetched_at)) = &self.cache {
+            if fetched_at.elapsed() < self.ttl {
+                let user_data = user_data.clone();
+                return Ok((self, user_data));
+            }
+        }
+
+        let (session, user_data) = self
+            .session
+            .get_user_data()
+            .await?;
+
+        Ok((
+            Self {
+                session,
+                ttl: self.ttl,
+                cache: Some((user_data.clone(), Instant::now())),
+            },
+            user_data,
+        ))
+    }
+
+    /// Refreshes the tokens, keeping the cached user data.
+    ///
+    /// See also [`Session::refresh_token`].
+    pub async fn refresh_token(self) -> Result<Self> {
+        let session = self
+            .session
+            .refresh_token()
+            .await?;
+
+        Ok(Self {
+            session,
+            ttl: self.ttl,
+            cache: self.cache,
+        })
+    }
+
+    /// Changes the email and invalidates the cached user data.
+    ///
+    /// See also [`Session::change_email`].
+    pub async fn change_email(
+        self,
+        new_email: Email,
+        locale: Option<LanguageCode>,
+    ) -> Result<Self> {
+        let session = self
+            .session
+            .change_email(new_email, locale)
+            .await?;
+
+        Ok(Self::new(session, self.ttl))
+    }
+
+    /// Sends an email to verify the new email before updating it and invalidates the cached user data.
+    ///
+    /// See also [`Session::verify_before_update_email`].
+    pub async fn verify_before_update_email(
+        self,
+        new_email: Email,
+        action_code_settings: Option<ActionCodeSettings>,
+        locale: Option<LanguageCode>,
+    ) -> Result<Self> {
+        let session = self
+            .session
+            .verify_before_update_email(new_email, action_code_settings, locale)
+            .await?;
+
+        Ok(Self::new(session, self.ttl))
+    }
+
+    /// Sends an email verification to the user only if the email has not been verified yet
+    /// by the cached user data within the TTL.
+    ///
+    /// See also [`Session::send_email_verification_if_needed`].
+    ///
+    /// ## Returns
+    /// New wrapper to replace the consumed one and whether the email verification has been sent.
+    pub async fn send_email_verification_if_needed(
+        self,
+        action_code_settings: Option<ActionCodeSettings>,
+        locale: Option<LanguageCode>,
+    ) -> Result<(Self, bool)> {
+        let (cached, user_data) = self.get_user_data().await?;
+
+        // Skip sending if the email has already been verified.
+        if user_data
+            .email_verified
+            .unwrap_or(false)
+        {
+            return Ok((cached, false));
+        }
+
+        let session = cached
+            .session
+            .send_email_verification(action_code_settings, locale)
+            .await?;
+
+        Ok((
+            Self {
+                session,
+                ttl: cached.ttl,
+                cache: cached.cache,
+            },
+            true,
+        ))
+    }
+
+    /// Changes the password and invalidates the cached user data.
+    ///
+    /// See also [`Session::change_password`].
+    pub async fn change_password(
+        self,
+        new_password: Password,
+    ) -> Result<Self> {
+        let session = self
+            .session
+            .change_password(new_password)
+            .await?;
+
+        Ok(Self::new(session, self.ttl))
+    }
+
+    /// Updates the user profile and invalidates the cached user data.
+    ///
+    /// See also [`Session::update_profile`].
+    pub async fn update_profile(
+        self,
+        display_name: Option<DisplayName>,
+        photo_url: Option<PhotoUrl>,
+    ) -> Result<Self> {
+        let session = self
+            .session
+            .update_profile(display_name, photo_url)
+            .await?;
+
+        Ok(Self::new(session, self.ttl))
+    }
+
+    /// Deletes the user profile and invalidates the cached user data.
+    ///
+    /// See also [`Session::delete_profile`].
+    pub async fn delete_profile(
+        self,
+        delete_attribute: HashSet<DeleteAttribute>,
+    ) -> Result<Self> {
+        let session = self
+            .session
+            .delete_profile(delete_attribute)
+            .await?;
+
+        Ok(Self::new(session, self.ttl))
+    }
+
+    /// Links the user with the email and password and invalidates the cached user data.
+    ///
+    /// See also [`Session::link_with_email_password`].
+    pub async fn link_with_email_password(
+        self,
+        email: Email,
+        password: Password,
+    ) -> Result<Self> {
+        let session = self
+            .session
+            .link_with_email_password(email, password)
+            .await?;
+
+        Ok(Self::new(session, self.ttl))
+    }
+
+    /// Links the user with the OAuth credential and invalidates the cached user data.
+    ///
+    /// See also [`Session::link_with_oauth_credential`].
+    pub async fn link_with_oauth_credential(
+        self,
+        request_uri: OAuthRequestUri,
+        post_body: IdpPostBody,
+    ) -> Result<Self> {
+        let session = self
+            .session
+            .link_with_oauth_credential(request_uri, post_body)
+            .await?;
+
+        Ok(Self::new(session, self.ttl))
+    }
+
+    /// Unlinks the user from the providers and invalidates the cached user data.
+    ///
+    /// See also [`Session::unlink_provider`].
+    pub async fn unlink_provider(
+        self,
+        delete_provider: HashSet<ProviderId>,
+    ) -> Result<Self> {
+        let session = self
+            .session
+            .unlink_provider(delete_provider)
+            .await?;
+
+        Ok(Self::new(session, self.ttl))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+
+    use super::*;
+    use crate::ApiKey;
+    use crate::Client;
+    use crate::ExpiresIn;
+    use crate::IdToken;
+    use crate::RefreshToken;
+    use crate::Uid;
+
+    /// The host where nothing listens, to fail if any request is sent.
+    const UNREACHABLE_HOST: &str = "127.0.0.1:1";
+
+    /// Creates a session that sends requests to the host.
+    fn session(host: &str) -> Session {
+        Session {
+            client: Client::new().with_emulator_host(host),
+            api_key: ApiKey::new("api-key"),
+            id_token: IdToken::new("id-token"),
+            expires_in: ExpiresIn::parse("3600".to_string()).unwrap(),
+            refresh_token: RefreshToken::new("refresh-token"),
+            local_id: Uid::new("uid"),
+            first_sign_in: false,
+            anonymous: false,
+            display_name: None,
+            photo_url: None,
+            expected_project_id: None,
+        }
+    }
+
+    /// Creates a wrapper of the session with the cached user data.
+    fn cached(
+        host: &str,
+        ttl: Duration,
+    ) -> CachedSession {
+        let user_data: UserData = serde_json::from_value(serde_json::json!({
+            "localId": "uid",
+            "email": "user@example.com",
+            "lastLoginAt": "1700000000000",
+            "createdAt": "1700000000000",
+        }))
+        .unwrap();
+
+        CachedSession {
+            cache: Some((user_data, Instant::now())),
+            ..CachedSession::new(session(host), ttl)
+        }
+    }
+
+    /// Serves a successful response of the body to a request on a local port.
+    ///
+    /// ## Returns
+    /// The host of the server.
+    fn serve_once(body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let host = listener
+            .local_addr()
+            .unwrap()
+            .to_string();
+
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            // Read the request headers and body.
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader
+                    .read_line(&mut line)
+                    .unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((key, value)) = line.split_once(':') {
+                    if key.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut request_body = vec![0; content_length];
+            reader
+                .read_exact(&mut request_body)
+                .unwrap();
+
+            // Write the response.
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            reader
+                .get_mut()
+                .write_all(response.as_bytes())
+                .unwrap();
+        });
+
+        host
+    }
+
+    #[tokio::test]
+    async fn get_user_data_from_cache_within_ttl() {
+        let cached = cached(
+            UNREACHABLE_HOST,
+            Duration::from_secs(60),
+        );
+
+        let (cached, user_data) = cached
+            .get_user_data()
+            .await
+            .unwrap();
+
+        assert_eq!(user_data.local_id.as_str(), "uid");
+        assert!(cached.cache.is_some());
+    }
+
+    #[tokio::test]
+    async fn refetch_user_data_after_ttl() {
+        let cached = cached(UNREACHABLE_HOST, Duration::ZERO);
+
+        // NOTE: Fails to send a request to the unreachable host on the refetch.
+        assert!(matches!(
+            cached.get_user_data().await,
+            Err(crate::Error::HttpRequestError(_))
+        ));
+    }
+
+    #[test]
+    fn invalidate_user_data_clears_cache() {
+        let mut cached = cached(
+            UNREACHABLE_HOST,
+            Duration::from_secs(60),
+        );
+        assert!(cached.cache.is_some());
+
+        cached.invalidate_user_data();
+
+        assert!(cached.cache.is_none());
+    }
+
+    #[tokio::test]
+    async fn update_profile_resets_cache() {
+        let host = serve_once(
+            r#"{
+                "localId": "uid",
+                "email": "user@example.com",
+                "displayName": "new-name",
+                "passwordHash": "hash",
+                "providerUserInfo": []
+            }"#,
+        );
+        let cached = cached(&host, Duration::from_secs(60));
+
+        let cached = cached
+            .update_profile(Some(DisplayName::new("new-name")), None)
+            .await
+            .unwrap();
+
+        assert!(cached.cache.is_none());
+        assert_eq!(
+            cached
+                .session()
+                .display_name(),
+            Some("new-name")
+        );
+    }
+}
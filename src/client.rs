@@ -10,6 +10,19 @@
 //! let client = Client::new();
 //! ```
 //!
+//! ## Client builder
+//! You can tune the connection, e.g. timeouts and the connection pool, by [`crate::ClientBuilder`].
+//!
+//! ```rust
+//! use fars::Client;
+//! use std::time::Duration;
+//!
+//! let client = Client::builder()
+//!     .timeout(Duration::from_secs(60))
+//!     .pool_max_idle_per_host(8)
+//!     .build()?;
+//! ```
+//!
 //! ## Custom HTTP client
 //! You can use a custom HTTP client by enabling the `custom_client` feature with the re-exported `reqwest` crate.
 //!
@@ -26,20 +39,209 @@
 //! // Customize HTTP client.
 //! let client = Client::custom(client);
 //! ```
+//!
+//! ## WebAssembly
+//! On `wasm32` targets, `reqwest` sends requests via the `fetch` API of the browser and does not support `timeout` and `connect_timeout` of `reqwest::ClientBuilder`.
+//! Please set any timeout on the JavaScript side instead.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::Duration;
 
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::error::{ApiErrorResponse, CommonErrorCode};
+use crate::metrics::Metrics;
+use crate::metrics::NoopMetrics;
 use crate::ApiKey;
 use crate::Endpoint;
 use crate::Error;
 use crate::LanguageCode;
 use crate::Result;
+use crate::RetryPolicy;
+
+// Re-exports of the HTTP header types for `Client::with_default_headers` without the feature "custom_client".
+pub use reqwest::header::HeaderMap;
+pub use reqwest::header::HeaderName;
+pub use reqwest::header::HeaderValue;
+
+/// The header key of the locale.
+const LOCALE_HEADER_KEY: &str = "X-Firebase-Locale";
+
+/// The header key of the client version.
+const CLIENT_VERSION_HEADER_KEY: &str = "X-Client-Version";
+
+/// The default client version of this crate, e.g. `fars/0.1.0`.
+const DEFAULT_CLIENT_VERSION: &str =
+    concat!("fars/", env!("CARGO_PKG_VERSION"));
 
 /// HTTP client.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct Client {
     inner: reqwest::Client,
+    metrics: Arc<dyn Metrics>,
+    default_headers: reqwest::header::HeaderMap,
+    client_version: reqwest::header::HeaderValue,
+    max_response_size: usize,
+    retry_policy: Option<Arc<RetryPolicy>>,
+    emulator_host: Option<String>,
+}
+
+impl Debug for Client {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("inner", &self.inner)
+            .field("default_headers", &self.default_headers)
+            .field("client_version", &self.client_version)
+            .field(
+                "max_response_size",
+                &self.max_response_size,
+            )
+            .field("retry_policy", &self.retry_policy)
+            .field("emulator_host", &self.emulator_host)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Builder of the HTTP client to tune the connection without the `custom_client` feature.
+///
+/// This exposes a curated subset of the options of `reqwest::ClientBuilder`.
+/// For the full control, please use [`Client::custom`] with the `custom_client` feature.
+///
+/// ## NOTE
+/// On `wasm32` targets, only the user agent is available because the `fetch` API of the browser manages the connection.
+///
+/// ## Example
+/// ```
+/// use fars::Client;
+/// use std::time::Duration;
+///
+/// let client = Client::builder()
+///     .timeout(Duration::from_secs(60))
+///     .connect_timeout(Duration::from_secs(10))
+///     .pool_idle_timeout(Duration::from_secs(90))
+///     .pool_max_idle_per_host(8)
+///     .user_agent("my-app/1.0")
+///     .build()?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ClientBuilder {
+    #[cfg(not(target_arch = "wasm32"))]
+    timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    connect_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pool_idle_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    pool_max_idle_per_host: Option<usize>,
+    user_agent: Option<String>,
+}
+
+impl ClientBuilder {
+    /// Sets the timeout of a whole request, from connecting to reading the response body.
+    ///
+    /// ## Arguments
+    /// - `timeout` - The timeout of a request.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout of connecting to the server.
+    ///
+    /// ## Arguments
+    /// - `connect_timeout` - The timeout of connecting.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn connect_timeout(
+        mut self,
+        connect_timeout: Duration,
+    ) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the timeout to keep an idle connection in the pool.
+    ///
+    /// ## Arguments
+    /// - `pool_idle_timeout` - The timeout of an idle connection.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_idle_timeout(
+        mut self,
+        pool_idle_timeout: Duration,
+    ) -> Self {
+        self.pool_idle_timeout = Some(pool_idle_timeout);
+        self
+    }
+
+    /// Sets the maximum number of idle connections per host in the pool.
+    ///
+    /// ## Arguments
+    /// - `pool_max_idle_per_host` - The maximum number of idle connections per host.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn pool_max_idle_per_host(
+        mut self,
+        pool_max_idle_per_host: usize,
+    ) -> Self {
+        self.pool_max_idle_per_host = Some(pool_max_idle_per_host);
+        self
+    }
+
+    /// Sets the `User-Agent` header of requests.
+    ///
+    /// ## Arguments
+    /// - `user_agent` - The user agent, e.g. `my-app/1.0`.
+    pub fn user_agent<S>(
+        mut self,
+        user_agent: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Builds the HTTP client.
+    ///
+    /// ## Errors
+    /// - `Error::BuildClientFailed` - Failed to build the HTTP client, e.g. an invalid user agent or TLS backend initialization.
+    pub fn build(self) -> Result<Client> {
+        let mut builder = reqwest::Client::builder();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if let Some(timeout) = self.timeout {
+                builder = builder.timeout(timeout);
+            }
+            if let Some(connect_timeout) = self.connect_timeout {
+                builder = builder.connect_timeout(connect_timeout);
+            }
+            if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+                builder = builder.pool_idle_timeout(pool_idle_timeout);
+            }
+            if let Some(pool_max_idle_per_host) = self.pool_max_idle_per_host {
+                builder =
+                    builder.pool_max_idle_per_host(pool_max_idle_per_host);
+            }
+        }
+
+        if let Some(user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+
+        let inner = builder
+            .build()
+            .map_err(Error::BuildClientFailed)?;
+
+        Ok(Client::from_inner(inner))
+    }
 }
 
 impl Default for Client {
@@ -49,10 +251,44 @@ impl Default for Client {
 }
 
 impl Client {
+    /// The default maximum size of a response body in bytes: 10 MiB.
+    pub const DEFAULT_MAX_RESPONSE_SIZE: usize = 10 * 1024 * 1024;
+
     /// Creates a new HTTP client.
     pub fn new() -> Self {
+        Self::from_inner(reqwest::Client::new())
+    }
+
+    /// Creates a builder of the HTTP client to tune the connection, e.g. timeouts and the connection pool.
+    ///
+    /// See also [`ClientBuilder`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Client;
+    /// use std::time::Duration;
+    ///
+    /// let client = Client::builder()
+    ///     .timeout(Duration::from_secs(60))
+    ///     .connect_timeout(Duration::from_secs(10))
+    ///     .build()?;
+    /// ```
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// Creates a new HTTP client with the inner HTTP client.
+    fn from_inner(inner: reqwest::Client) -> Self {
         Self {
-            inner: reqwest::Client::new(),
+            inner,
+            metrics: Arc::new(NoopMetrics),
+            default_headers: reqwest::header::HeaderMap::new(),
+            client_version: reqwest::header::HeaderValue::from_static(
+                DEFAULT_CLIENT_VERSION,
+            ),
+            max_response_size: Self::DEFAULT_MAX_RESPONSE_SIZE,
+            retry_policy: None,
+            emulator_host: None,
         }
     }
 
@@ -80,9 +316,187 @@ impl Client {
     /// ```
     #[cfg(feature = "custom_client")]
     pub fn custom(client: crate::reqwest::Client) -> Self {
-        Self {
-            inner: client,
-        }
+        Self::from_inner(client)
+    }
+
+    /// Sets a metrics hook to record API calls.
+    ///
+    /// The default hook is [`crate::metrics::NoopMetrics`] that records nothing.
+    ///
+    /// ## Arguments
+    /// - `metrics` - A metrics hook.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::sync::Arc;
+    /// use fars::Client;
+    /// use fars::metrics::NoopMetrics;
+    ///
+    /// let client = Client::new()
+    ///     .with_metrics(Arc::new(NoopMetrics));
+    /// ```
+    pub fn with_metrics(
+        mut self,
+        metrics: Arc<dyn Metrics>,
+    ) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Sets custom headers attached to all requests to the Firebase Auth API,
+    /// e.g. `X-Request-Id` and an authorization for an egress proxy or an API gateway.
+    ///
+    /// ## NOTE
+    /// `Content-Type`, `X-Firebase-Locale` and `X-Client-Version` in the headers are ignored
+    /// not to clobber the content type of the payload, the locale specified by each API
+    /// and the client version set by [`Client::with_client_info`].
+    ///
+    /// ## Arguments
+    /// - `headers` - Custom headers to attach to all requests, built by the re-exported [`HeaderMap`], [`HeaderName`] and [`HeaderValue`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Client;
+    /// use fars::client::HeaderMap;
+    /// use fars::client::HeaderValue;
+    ///
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("X-Request-Id", HeaderValue::from_static("request-id"));
+    ///
+    /// let client = Client::new()
+    ///     .with_default_headers(headers);
+    /// ```
+    pub fn with_default_headers(
+        mut self,
+        mut headers: HeaderMap,
+    ) -> Self {
+        headers.remove(reqwest::header::CONTENT_TYPE);
+        headers.remove(LOCALE_HEADER_KEY);
+        headers.remove(CLIENT_VERSION_HEADER_KEY);
+
+        self.default_headers = headers;
+        self
+    }
+
+    /// Sets the client version sent as the `X-Client-Version` header of all requests to the Firebase Auth API,
+    /// which identifies the client on the Firebase side.
+    ///
+    /// The default is `fars/{version of this crate}`.
+    ///
+    /// ## Arguments
+    /// - `client_info` - The client version, e.g. `my-app/1.0.0/fars`.
+    ///
+    /// ## Errors
+    /// - `Error::InvalidHeaderValue` - The client version is not a valid header value.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Client;
+    ///
+    /// let client = Client::new()
+    ///     .with_client_info("my-app/1.0.0/fars")?;
+    /// ```
+    pub fn with_client_info<S>(
+        mut self,
+        client_info: S,
+    ) -> Result<Self>
+    where
+        S: Into<String>,
+    {
+        self.client_version =
+            reqwest::header::HeaderValue::from_str(&client_info.into())
+                .map_err(|error| Error::InvalidHeaderValue {
+                    key: CLIENT_VERSION_HEADER_KEY,
+                    error,
+                })?;
+
+        Ok(self)
+    }
+
+    /// Sets the maximum size of a response body in bytes.
+    ///
+    /// The default is [`Client::DEFAULT_MAX_RESPONSE_SIZE`].
+    /// A response body larger than this is aborted with `Error::ResponseTooLarge`
+    /// while reading instead of being buffered entirely into memory.
+    ///
+    /// ## Arguments
+    /// - `max_response_size` - The maximum size of a response body in bytes.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Client;
+    ///
+    /// let client = Client::new()
+    ///     .with_max_response_size(1024 * 1024);
+    /// ```
+    pub fn with_max_response_size(
+        mut self,
+        max_response_size: usize,
+    ) -> Self {
+        self.max_response_size = max_response_size;
+        self
+    }
+
+    /// Sets the policy to retry the requests to the Firebase Auth API.
+    ///
+    /// The requests are not retried by default.
+    ///
+    /// ## Arguments
+    /// - `retry_policy` - The policy to retry the requests.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Client;
+    /// use fars::RetryPolicy;
+    ///
+    /// let client = Client::new()
+    ///     .with_retry_policy(RetryPolicy::new(tokio::time::sleep));
+    /// ```
+    pub fn with_retry_policy(
+        mut self,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        self.retry_policy = Some(Arc::new(retry_policy));
+        self
+    }
+
+    /// Returns the policy to retry the requests if any.
+    #[cfg(feature = "verify")]
+    pub(crate) fn retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_deref()
+    }
+
+    /// Sends the requests to the Firebase Auth Emulator of the [Firebase Local Emulator Suite](https://firebase.google.com/docs/emulator-suite)
+    /// instead of the production.
+    ///
+    /// See also [the official document](https://firebase.google.com/docs/emulator-suite/connect_auth#rest-api).
+    ///
+    /// ## Arguments
+    /// - `host` - The host and port of the emulator, e.g. `localhost:9099` as the `FIREBASE_AUTH_EMULATOR_HOST` environment variable.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Client;
+    ///
+    /// let client = Client::new()
+    ///     .with_emulator_host("localhost:9099");
+    /// ```
+    pub fn with_emulator_host<S>(
+        mut self,
+        host: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        self.emulator_host = Some(host.into());
+        self
+    }
+
+    /// Returns whether the `Authorization` header is set by [`Client::with_default_headers`],
+    /// e.g. the OAuth access token of a service account for privileged operations.
+    pub(crate) fn has_authorization(&self) -> bool {
+        self.default_headers
+            .contains_key(reqwest::header::AUTHORIZATION)
     }
 
     /// Returns a reference to the inner HTTP client.
@@ -107,9 +521,12 @@ impl Client {
     /// ## Errors
     /// - `Error::HttpRequestError` - Failed to send a request.
     /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::ResponseTooLarge` - The response body exceeds the maximum size.
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::EmptyResponseBody` - The response body is empty on a successful response that expects a payload.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::AccountDisabled` - The user account has been disabled.
     /// - `Error::ApiError` - API error on the Firebase Auth.
     pub(crate) async fn send_post<T, U>(
         &self,
@@ -123,16 +540,17 @@ impl Client {
         U: DeserializeOwned,
     {
         // Build a request URL.
-        let url = format!(
-            "https://identitytoolkit.googleapis.com/v1/{}?key={}",
-            endpoint.format(),
-            api_key.inner()
+        let url = build_url(
+            endpoint,
+            api_key,
+            self.emulator_host.as_deref(),
         );
 
-        // Create request builder and set method and payload.
+        // Create request builder and set method, default headers and payload.
         let mut builder = self
             .inner
             .post(url)
+            .headers(self.request_headers())
             .json(&request_payload);
 
         // Set optional headers if some are provided.
@@ -140,63 +558,395 @@ impl Client {
             builder = builder.headers(optional_locale_header(locale)?);
         }
 
-        // Send a request.
-        let response = builder
-            .send()
+        self.send(endpoint, builder)
             .await
-            .map_err(Error::HttpRequestError)?;
+    }
 
-        // Check the response status code.
-        let status_code = response.status();
+    /// Sends a GET request to the Firebase Auth API.
+    ///
+    /// See also [API reference](https://firebase.google.com/docs/reference/rest/auth).
+    ///
+    /// ## Arguments
+    /// - `endpoint` - The endpoint to send the request to.
+    /// - `api_key` - The Firebase project's API key.
+    ///
+    /// ## Returns
+    /// The result with the response payload of the API.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::ResponseTooLarge` - The response body exceeds the maximum size.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::EmptyResponseBody` - The response body is empty on a successful response that expects a payload.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    pub(crate) async fn send_get<U>(
+        &self,
+        endpoint: Endpoint,
+        api_key: &ApiKey,
+    ) -> Result<U>
+    where
+        U: DeserializeOwned,
+    {
+        // Build a request URL.
+        let url = build_url(
+            endpoint,
+            api_key,
+            self.emulator_host.as_deref(),
+        );
 
-        // Read the response body as text.
-        let response_text = response
-            .text()
+        // Create request builder and set method and default headers.
+        let builder = self
+            .inner
+            .get(url)
+            .headers(self.request_headers());
+
+        self.send(endpoint, builder)
             .await
-            .map_err(|error| Error::ReadResponseTextFailed {
-                error,
-            })?;
+    }
+
+    /// Creates the headers attached to all requests, i.e. the default headers and the client version.
+    fn request_headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = self.default_headers.clone();
+
+        headers.insert(
+            CLIENT_VERSION_HEADER_KEY,
+            self.client_version.clone(),
+        );
+
+        headers
+    }
+
+    /// Sends a request with the retry policy if any.
+    ///
+    /// ## Arguments
+    /// - `endpoint` - The endpoint to send the request to.
+    /// - `builder` - The request builder to send.
+    ///
+    /// ## Returns
+    /// The result with the response payload of the API.
+    async fn send<U>(
+        &self,
+        endpoint: Endpoint,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<U>
+    where
+        U: DeserializeOwned,
+    {
+        let Some(retry_policy) = &self.retry_policy else {
+            return self
+                .send_once(endpoint, builder)
+                .await;
+        };
+
+        let mut retry = 0;
+        loop {
+            // NOTE: The builder can not be cloned with a streaming body, then it is sent without any retry.
+            let Some(retry_builder) = builder.try_clone() else {
+                return self
+                    .send_once(endpoint, builder)
+                    .await;
+            };
+
+            match self
+                .send_once(endpoint, retry_builder)
+                .await
+            {
+                | Err(error)
+                    if retry_policy
+                        .wait(retry, &error)
+                        .await =>
+                {
+                    retry += 1;
+                },
+                | result => return result,
+            }
+        }
+    }
+
+    /// Sends a request once and records the metrics of the API call.
+    ///
+    /// ## Arguments
+    /// - `endpoint` - The endpoint to send the request to.
+    /// - `builder` - The request builder to send.
+    ///
+    /// ## Returns
+    /// The result with the response payload of the API.
+    async fn send_once<U>(
+        &self,
+        endpoint: Endpoint,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<U>
+    where
+        U: DeserializeOwned,
+    {
+        let timer = Timer::start();
+
+        // Send a request.
+        let response = match builder.send().await {
+            | Ok(response) => response,
+            | Err(error) => {
+                // Record the metrics of the failed call without any response.
+                self.metrics
+                    .record_transport_error(
+                        endpoint.format(),
+                        timer.elapsed(),
+                        &error,
+                    );
+
+                return Err(Error::HttpRequestError(error));
+            },
+        };
+
+        let status_code = response.status();
+        let result = handle_response(
+            endpoint,
+            response,
+            self.max_response_size,
+        )
+        .await;
+
+        // Record the metrics.
+        let error_code = match &result {
+            | Err(Error::ApiError {
+                error_code,
+                ..
+            }) => Some(error_code),
+            | Err(Error::InvalidIdToken {
+                ..
+            }) => Some(&CommonErrorCode::InvalidIdToken),
+            | Err(Error::AccountDisabled {
+                ..
+            }) => Some(&CommonErrorCode::UserDisabled),
+            | _ => None,
+        };
+        self.metrics.record(
+            endpoint.format(),
+            status_code,
+            timer.elapsed(),
+            error_code,
+        );
+
+        result
+    }
+}
+
+/// A timer to measure the latency of an API call.
+///
+/// ## NOTE
+/// `std::time::Instant` is not available on `wasm32` targets, so the latency is always zero.
+struct Timer {
+    #[cfg(not(target_arch = "wasm32"))]
+    start: std::time::Instant,
+}
+
+impl Timer {
+    fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            start: std::time::Instant::now(),
+        }
+    }
+
+    fn elapsed(&self) -> Duration {
+        #[cfg(not(target_arch = "wasm32"))]
+        return self.start.elapsed();
+
+        #[cfg(target_arch = "wasm32")]
+        return Duration::ZERO;
+    }
+}
+
+/// Builds a request URL of the Firebase Auth API.
+///
+/// ## Arguments
+/// - `endpoint` - The endpoint to send the request to.
+/// - `api_key` - The Firebase project's API key.
+/// - `emulator_host` - The optional host of the Firebase Auth Emulator.
+fn build_url(
+    endpoint: Endpoint,
+    api_key: &ApiKey,
+    emulator_host: Option<&str>,
+) -> String {
+    match emulator_host {
+        | Some(emulator_host) => format!(
+            "http://{}/{}/{}/{}?key={}",
+            emulator_host,
+            endpoint.emulator_host(),
+            endpoint.version(),
+            endpoint.format(),
+            api_key.inner()
+        ),
+        | None => format!(
+            "https://{}/{}/{}?key={}",
+            endpoint.host(),
+            endpoint.version(),
+            endpoint.format(),
+            api_key.inner()
+        ),
+    }
+}
+
+/// Handles a response of the Firebase Auth API.
+///
+/// ## Arguments
+/// - `endpoint` - The endpoint that the request has been sent to.
+/// - `response` - The response of the API.
+/// - `max_response_size` - The maximum size of the response body in bytes.
+///
+/// ## Returns
+/// The result with the response payload of the API.
+///
+/// ## Errors
+/// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+/// - `Error::ResponseTooLarge` - The response body exceeds the maximum size.
+/// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+/// - `Error::EmptyResponseBody` - The response body is empty on a successful response that expects a payload.
+/// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+/// - `Error::InvalidIdToken` - Invalid ID token.
+/// - `Error::AccountDisabled` - The user account has been disabled.
+/// - `Error::ApiError` - API error on the Firebase Auth.
+async fn handle_response<U>(
+    endpoint: Endpoint,
+    response: reqwest::Response,
+    max_response_size: usize,
+) -> Result<U>
+where
+    U: DeserializeOwned,
+{
+    // Check the response status code.
+    let status_code = response.status();
 
-        // Successful response.
-        if status_code.is_success() {
-            // Deserialize the response text to a payload.
-            serde_json::from_str::<U>(&response_text).map_err(|error| {
-                Error::DeserializeResponseJsonFailed {
+    // Read the response body as text.
+    let response_text = read_response_text(response, max_response_size).await?;
+
+    // Successful response.
+    if status_code.is_success() {
+        deserialize_payload(status_code, response_text)
+    }
+    // Error response.
+    else {
+        // Deserialize the response text to the error payload.
+        let error_response =
+            serde_json::from_str::<ApiErrorResponse>(&response_text).map_err(
+                |error| Error::DeserializeErrorResponseJsonFailed {
                     error,
                     json: response_text,
-                }
-            })
-        }
-        // Error response.
-        else {
-            // Deserialize the response text to the error payload.
-            let error_response =
-                serde_json::from_str::<ApiErrorResponse>(&response_text)
-                    .map_err(|error| {
-                        Error::DeserializeErrorResponseJsonFailed {
-                            error,
-                            json: response_text,
-                        }
-                    })?;
-
-            // Check error message and create error code.
-            let error_code: CommonErrorCode = error_response
-                .error
-                .message
-                .clone()
-                .into();
-
-            match error_code {
-                // Take invalid ID token error as special case.
-                | CommonErrorCode::InvalidIdToken => Err(Error::InvalidIdToken),
-                | _ => Err(Error::ApiError {
-                    status_code,
-                    error_code,
-                    response: error_response,
-                }),
+                },
+            )?;
+
+        Err(Error::from_api_error_response(
+            endpoint.format(),
+            status_code,
+            error_response,
+        ))
+    }
+}
+
+/// Deserializes the body of a successful response to a payload.
+///
+/// ## Arguments
+/// - `status_code` - The status code of the response.
+/// - `response_text` - The response body as text.
+///
+/// ## Returns
+/// The response payload of the API.
+///
+/// ## Errors
+/// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+/// - `Error::EmptyResponseBody` - The response body is empty on a successful response that expects a payload.
+fn deserialize_payload<U>(
+    status_code: reqwest::StatusCode,
+    response_text: String,
+) -> Result<U>
+where
+    U: DeserializeOwned,
+{
+    // NOTE: Some endpoints (and the emulator) return an empty body,
+    // so treat it as an empty JSON object for payloads without required fields.
+    if response_text
+        .trim()
+        .is_empty()
+    {
+        return serde_json::from_str::<U>("{}").map_err(|_| {
+            Error::EmptyResponseBody {
+                status_code,
             }
+        });
+    }
+
+    // Deserialize the response text to a payload.
+    serde_json::from_str::<U>(&response_text).map_err(|error| {
+        Error::DeserializeResponseJsonFailed {
+            error,
+            json: response_text,
+        }
+    })
+}
+
+/// Reads a response body as text up to the maximum size.
+///
+/// ## Arguments
+/// - `response` - The response of the API.
+/// - `max_response_size` - The maximum size of the response body in bytes.
+///
+/// ## Returns
+/// The response body as text.
+///
+/// ## Errors
+/// - `Error::ReadResponseTextFailed` - Failed to read the response body.
+/// - `Error::ResponseTooLarge` - The response body exceeds the maximum size.
+async fn read_response_text(
+    #[allow(unused_mut)] mut response: reqwest::Response,
+    max_response_size: usize,
+) -> Result<String> {
+    let too_large = Error::ResponseTooLarge {
+        max_size: max_response_size,
+    };
+
+    // Check the content length in advance if it is provided.
+    if let Some(content_length) = response.content_length() {
+        if content_length > max_response_size as u64 {
+            return Err(too_large);
         }
     }
+
+    // Read the body chunk by chunk not to buffer an unbounded body.
+    #[cfg(not(target_arch = "wasm32"))]
+    let body = {
+        let mut body = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|error| Error::ReadResponseTextFailed {
+                error,
+            })?
+        {
+            if body.len() + chunk.len() > max_response_size {
+                return Err(too_large);
+            }
+            body.extend_from_slice(&chunk);
+        }
+        body
+    };
+
+    // NOTE: The WASM backend does not support reading chunks.
+    #[cfg(target_arch = "wasm32")]
+    let body = {
+        let body = response
+            .bytes()
+            .await
+            .map_err(|error| Error::ReadResponseTextFailed {
+                error,
+            })?;
+        if body.len() > max_response_size {
+            return Err(too_large);
+        }
+        body.to_vec()
+    };
+
+    Ok(String::from_utf8_lossy(&body).into_owned())
 }
 
 /// Creates optional headers for the locale.
@@ -215,10 +965,10 @@ fn optional_locale_header(
     let mut headers = reqwest::header::HeaderMap::new();
 
     headers.insert(
-        "X-Firebase-Locale",
+        LOCALE_HEADER_KEY,
         reqwest::header::HeaderValue::from_str(locale.format()).map_err(
             |error| Error::InvalidHeaderValue {
-                key: "X-Firebase-Locale",
+                key: LOCALE_HEADER_KEY,
                 error,
             },
         )?,
@@ -226,3 +976,126 @@ fn optional_locale_header(
 
     Ok(headers)
 }
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, Deserialize)]
+    struct OptionalPayload {
+        #[serde(rename = "email")]
+        email: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct RequiredPayload {
+        #[serde(rename = "idToken")]
+        _id_token: String,
+    }
+
+    #[test]
+    fn deserialize_payload_accepts_empty_body_without_required_fields() {
+        for body in ["", "  \n"] {
+            let payload: OptionalPayload = deserialize_payload(
+                reqwest::StatusCode::OK,
+                body.to_string(),
+            )
+            .unwrap();
+            assert_eq!(payload.email, None);
+        }
+    }
+
+    #[test]
+    fn deserialize_payload_rejects_empty_body_with_required_fields() {
+        let result: Result<RequiredPayload> =
+            deserialize_payload(reqwest::StatusCode::OK, String::new());
+
+        assert!(matches!(
+            result,
+            Err(Error::EmptyResponseBody {
+                status_code: reqwest::StatusCode::OK,
+            })
+        ));
+    }
+
+    #[test]
+    fn deserialize_payload_rejects_malformed_body() {
+        let result: Result<OptionalPayload> = deserialize_payload(
+            reqwest::StatusCode::OK,
+            "not json".to_string(),
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::DeserializeResponseJsonFailed { .. })
+        ));
+    }
+
+    /// A metrics hook that counts the recorded API calls.
+    #[derive(Default)]
+    struct CountingMetrics {
+        responses: std::sync::atomic::AtomicUsize,
+        transport_errors: std::sync::atomic::AtomicUsize,
+    }
+
+    impl Metrics for CountingMetrics {
+        fn record(
+            &self,
+            _endpoint: &str,
+            _status: reqwest::StatusCode,
+            _latency: Duration,
+            _error_code: Option<&CommonErrorCode>,
+        ) {
+            self.responses
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+
+        fn record_transport_error(
+            &self,
+            endpoint: &str,
+            _latency: Duration,
+            _error: &reqwest::Error,
+        ) {
+            assert_eq!(endpoint, Endpoint::SendOobCode.format());
+            self.transport_errors
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    #[tokio::test]
+    async fn record_transport_error_on_connection_failure() {
+        let metrics = Arc::new(CountingMetrics::default());
+        // NOTE: Nothing listens on the port 1 of the loopback address.
+        let client = Client::new()
+            .with_metrics(metrics.clone())
+            .with_emulator_host("127.0.0.1:1");
+
+        let result = client
+            .send_post::<_, OptionalPayload>(
+                Endpoint::SendOobCode,
+                &ApiKey::new("api-key"),
+                serde_json::json!({}),
+                None,
+            )
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::HttpRequestError(_))
+        ));
+        assert_eq!(
+            metrics
+                .transport_errors
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+        assert_eq!(
+            metrics
+                .responses
+                .load(std::sync::atomic::Ordering::SeqCst),
+            0
+        );
+    }
+}
@@ -10,16 +10,30 @@
 //! Supported sigining in methods are as follows:
 //!
 //! - [Sign up with email and password](`crate::Config::sign_up_with_email_password`)
+//! - [Sign up with email and password checking for a registered email](`crate::Config::sign_up_with_email_password_checked`)
 //! - [Sign in with email and password](`crate::Config::sign_in_with_email_password`)
 //! - [Sign in with OAuth credential](`crate::Config::sign_in_with_oauth_credential`)
+//! - [Sign in with OAuth credential continuing from a prior request](`crate::Config::sign_in_with_oauth_credential_continued`)
+//! - [Sign in with OAuth credential and link to an existing account](`crate::Config::sign_in_with_oauth_credential_auto_link`)
 //! - [Sign in anounymously](`crate::Config::sign_in_anonymously`)
+//! - [Sign in anounymously or restore the stored anonymous user](`crate::Config::sign_in_anonymously_or_restore`)
 //! - [Exchange a refresh token to an ID token](`crate::Config::exchange_refresh_token`)
+//! - [Sign in with custom token](`crate::Config::sign_in_with_custom_token`)
+//! - [Sign in with custom tokens concurrently](`crate::Config::sign_in_with_custom_tokens`)
+//! - [Create a session from externally obtained tokens](`crate::Config::session_from_tokens`)
 //!
 //! ## 2. Supported APIs that do not require an ID token
 //! Supported APIs that do not require an ID token are as follows:
 //!
 //! - [Fetch providers for email](`crate::Config::fetch_providers_for_email`)
 //! - [Send password reset email](`crate::Config::send_reset_password_email`)
+//! - [Send password reset email and return the OOB code](`crate::Config::send_reset_password_email_returning_oob_code`) (emulator only)
+//! - [Check action code](`crate::Config::check_action_code`)
+//! - [Apply action code](`crate::Config::apply_action_code`)
+//! - [Fetch password policy](`crate::Config::fetch_password_policy`)
+//! - [Fetch enabled sign in methods](`crate::Config::fetch_enabled_sign_in_methods`)
+//! - [Generate password reset link](`crate::Config::generate_password_reset_link`) (requires admin credentials)
+//! - [Generate email verification link](`crate::Config::generate_email_verification_link`) (requires admin credentials)
 //!
 //! ## Supported OAuth ID providers
 //! Supported OAuth ID provides are as follows:
@@ -132,6 +146,8 @@
 //!     // Send reset password email to specified email.
 //!     config.send_reset_password_email(
 //!         Email::new("user@example"),
+//!         None, // action code settings
+//!         None, // locale
 //!     ).await?;
 //!
 //!     // Do something with the resutl.
@@ -141,21 +157,36 @@
 //! }
 //! ```
 
+use futures_util::StreamExt;
+
 use crate::api;
+use crate::error::CommonErrorCode;
+use crate::ActionCodeInfo;
+use crate::ActionCodeOperation;
+use crate::ActionCodeSettings;
 use crate::ApiKey;
+use crate::AutoLink;
 use crate::Client;
 use crate::Email;
+use crate::EnabledSignInMethods;
+use crate::Error;
 use crate::ExpiresIn;
 use crate::IdToken;
 use crate::IdpPostBody;
 use crate::LanguageCode;
 use crate::OAuthContinueUri;
 use crate::OAuthRequestUri;
+use crate::OobCode;
 use crate::Password;
+use crate::PasswordPolicy;
+use crate::ProjectId;
 use crate::ProviderId;
 use crate::RefreshToken;
 use crate::Result;
+use crate::RetryPolicy;
+use crate::SentOobCode;
 use crate::Session;
+use crate::Uid;
 
 /// Configuration for the Firebase Auth.
 ///
@@ -174,6 +205,8 @@ pub struct Config {
     api_key: ApiKey,
     /// A HTTP client.
     client: Client,
+    /// The expected project ID to verify the exchanged refresh tokens.
+    expected_project_id: Option<ProjectId>,
 }
 
 impl Config {
@@ -195,6 +228,7 @@ impl Config {
         Self {
             api_key,
             client: Client::new(),
+            expected_project_id: None,
         }
     }
 
@@ -233,11 +267,135 @@ impl Config {
         Self {
             api_key,
             client,
+            expected_project_id: None,
         }
     }
 
+    /// Replaces the HTTP client of the config.
+    ///
+    /// This is available without the `custom_client` feature, e.g. to inject a client for tests.
+    ///
+    /// ## Arguments
+    /// - `client` - A HTTP client to replace.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Client;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// )
+    /// .with_client(Client::new());
+    /// ```
+    pub fn with_client(
+        mut self,
+        client: Client,
+    ) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Sets the expected project ID to verify the project of the tokens exchanged by [`Config::exchange_refresh_token`].
+    ///
+    /// A mismatched project indicates a misrouted refresh token of another project,
+    /// so the exchange fails with `Error::ProjectIdMismatch` instead of creating a session.
+    /// The project is not verified by default.
+    ///
+    /// This does not apply to [`Config::sign_in_with_custom_token`],
+    /// whose project is verified by the Firebase Auth as `Error::CustomTokenProjectMismatch`.
+    ///
+    /// ## NOTE
+    /// The API returns the project number, e.g. "123456789012", as the project ID,
+    /// so please set the project number of your Firebase project.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The expected project ID.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::ProjectId;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// )
+    /// .with_expected_project_id(ProjectId::new("123456789012"));
+    /// ```
+    pub fn with_expected_project_id(
+        mut self,
+        project_id: ProjectId,
+    ) -> Self {
+        self.expected_project_id = Some(project_id);
+        self
+    }
+
+    /// Sets the policy to retry the requests to the Firebase Auth API, e.g. rejected by the rate limiting (`TOO_MANY_ATTEMPTS_TRY_LATER`).
+    ///
+    /// This sets the policy on the HTTP client of the config by [`Client::with_retry_policy`],
+    /// so it applies to all APIs of the config, e.g. signing up, signing in and exchanging a refresh token,
+    /// and of the sessions created by the config. The requests are not retried by default.
+    ///
+    /// ## Arguments
+    /// - `retry_policy` - The policy to retry the requests.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::RetryPolicy;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// )
+    /// .with_retry_policy(RetryPolicy::new(tokio::time::sleep));
+    /// ```
+    pub fn with_retry_policy(
+        mut self,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        self.client = self
+            .client
+            .with_retry_policy(retry_policy);
+        self
+    }
+
+    /// Replaces the HTTP client of the config in place.
+    ///
+    /// This is available without the `custom_client` feature, e.g. to inject a client for tests.
+    ///
+    /// ## Arguments
+    /// - `client` - A HTTP client to replace.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Client;
+    ///
+    /// let mut config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// config.set_client(Client::new());
+    /// ```
+    pub fn set_client(
+        &mut self,
+        client: Client,
+    ) {
+        self.client = client;
+    }
+
     /// Signs up a new user with the given email and password.
     ///
+    /// ## NOTE
+    /// The email of the signed up user is unverified, and whether to require the email verification is up to your app
+    /// because the Firebase Auth does not enforce it and the public project config ([`crate::Config::fetch_enabled_sign_in_methods`]) does not expose such policy.
+    /// Please send a verification email by [`crate::Session::send_email_verification`] after signing up if your app requires it,
+    /// and check `email_verified` of [`crate::Session::get_user_data`] or the ID token claims to route the user into a "verify your email" state.
+    ///
     /// ## Arguments
     /// - `email` - The email of the user to sign up.
     /// - `password` - The password of the user to sign up.
@@ -251,7 +409,6 @@ impl Config {
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::ApiError` - API error on the Firebase Auth.
-    /// - `Error::ParseExpriesInFailed` - Failed to parse the expires in value.
     ///
     /// ## Example
     /// ```
@@ -294,11 +451,96 @@ impl Config {
             client: self.client.clone(),
             api_key: self.api_key.clone(),
             id_token: IdToken::new(response_payload.id_token),
-            expires_in: ExpiresIn::parse(response_payload.expires_in)?,
+            expires_in: response_payload.expires_in,
             refresh_token: RefreshToken::new(response_payload.refresh_token),
+            local_id: Uid::new(response_payload.local_id),
+            first_sign_in: true,
+            anonymous: false,
+            display_name: None,
+            photo_url: None,
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
         })
     }
 
+    /// Signs up a new user with the given email and password after checking that the email is not registered yet.
+    ///
+    /// This fetches the providers for the email before signing up
+    /// to return a distinct error with the linked providers, e.g. to show "email already registered" instantly.
+    ///
+    /// ## NOTE
+    /// This costs an extra round-trip to the Firebase Auth for the check.
+    ///
+    /// When the email enumeration protection is enabled, the check is inconclusive because the providers are not returned,
+    /// so this falls back to the `EMAIL_EXISTS` error of the sign up API.
+    ///
+    /// ## Arguments
+    /// - `email` - The email of the user to sign up.
+    /// - `password` - The password of the user to sign up.
+    /// - `continue_uri` - The optional URI to which the IDP redirects the user back, used to fetch the providers.
+    ///
+    /// ## Returns
+    /// The session for the signed up user.
+    ///
+    /// ## Errors
+    /// - `Error::EmailAlreadyRegistered` - The email is already registered with the providers.
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Error;
+    /// use fars::Password;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// match config.sign_up_with_email_password_checked(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    ///     None,
+    /// ).await {
+    ///     | Ok(session) => {
+    ///         // Signed up.
+    ///     },
+    ///     | Err(Error::EmailAlreadyRegistered { providers }) => {
+    ///         // Suggest signing in with the providers.
+    ///     },
+    ///     | Err(error) => {
+    ///         // Handle other errors.
+    ///     },
+    /// }
+    /// ```
+    pub async fn sign_up_with_email_password_checked(
+        &self,
+        email: Email,
+        password: Password,
+        continue_uri: Option<OAuthContinueUri>,
+    ) -> Result<Session> {
+        // Check the providers for the email.
+        if let Some(providers) = self
+            .fetch_providers_for_email(email.clone(), continue_uri)
+            .await?
+        {
+            if !providers.is_empty() {
+                return Err(Error::EmailAlreadyRegistered {
+                    providers,
+                });
+            }
+        }
+
+        self.sign_up_with_email_password(email, password)
+            .await
+    }
+
     /// Signs in a user with the given email and password.
     ///
     /// ## Arguments
@@ -313,8 +555,17 @@ impl Config {
     /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::AccountDisabled` - The user account has been disabled.
+    /// - `Error::InvalidCredentials` - The email or the password is wrong.
     /// - `Error::ApiError` - API error on the Firebase Auth.
-    /// - `Error::ParseExpriesInFailed` - Failed to parse the expires in value.
+    /// - `Error::MfaRequired` - Multi-factor authentication is required to complete signing in.
+    ///
+    /// ## NOTE
+    /// `INVALID_LOGIN_CREDENTIALS`, `EMAIL_NOT_FOUND` and `INVALID_PASSWORD` are mapped to `Error::InvalidCredentials`,
+    /// because the Firebase Auth returns only `INVALID_LOGIN_CREDENTIALS` with the email enumeration protection.
+    ///
+    /// The email is sent as is without any normalization.
+    /// Please use [`Email::normalized`] to trim and lowercase the email that the user typed if needed.
     ///
     /// ## Example
     /// ```
@@ -350,15 +601,24 @@ impl Config {
             &self.api_key,
             request_payload,
         )
-        .await?;
+        .await
+        .map_err(Error::map_invalid_credentials)?;
 
         // Create session.
         Ok(Session {
             client: self.client.clone(),
             api_key: self.api_key.clone(),
             id_token: IdToken::new(response_payload.id_token),
-            expires_in: ExpiresIn::parse(response_payload.expires_in)?,
+            expires_in: response_payload.expires_in,
             refresh_token: RefreshToken::new(response_payload.refresh_token),
+            local_id: Uid::new(response_payload.local_id),
+            first_sign_in: !response_payload.registered,
+            anonymous: false,
+            display_name: None,
+            photo_url: None,
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
         })
     }
 
@@ -366,6 +626,7 @@ impl Config {
     ///
     /// ## Returns
     /// The session for the signed in user.
+    /// The UID of the anonymous user is available by [`crate::Session::local_id`].
     ///
     /// ## Errors
     /// - `Error::HttpRequestError` - Failed to send a request.
@@ -373,7 +634,6 @@ impl Config {
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::ApiError` - API error on the Firebase Auth.
-    /// - `Error::ParseExpriesInFailed` - Failed to parse the expires in value.
     ///
     /// ## Example
     /// ```
@@ -403,11 +663,76 @@ impl Config {
             client: self.client.clone(),
             api_key: self.api_key.clone(),
             id_token: IdToken::new(response_payload.id_token),
-            expires_in: ExpiresIn::parse(response_payload.expires_in)?,
+            expires_in: response_payload.expires_in,
             refresh_token: RefreshToken::new(response_payload.refresh_token),
+            local_id: Uid::new(response_payload.local_id),
+            first_sign_in: true,
+            anonymous: true,
+            display_name: None,
+            photo_url: None,
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
         })
     }
 
+    /// Restores the anonymous user by the stored refresh token, or signs in as a new anonymous user if none is stored.
+    ///
+    /// This prevents creating a new anonymous user on every launch of apps that persist the refresh token.
+    ///
+    /// ## Arguments
+    /// - `refresh_token` - The stored refresh token of the anonymous user, or `None` to sign in as a new anonymous user.
+    ///
+    /// ## Returns
+    /// The session for the restored or signed in anonymous user.
+    ///
+    /// ## NOTE
+    /// The restored session is anonymous only if the refreshed ID token has been issued for an anonymous user
+    /// by the `firebase.sign_in_provider` claim, i.e. the user has not been linked to any credential,
+    /// as [`Config::exchange_refresh_token`].
+    /// This does not fall back to signing in as a new anonymous user when exchanging the stored refresh token fails,
+    /// e.g. because the user has been deleted, so that the stored user is not orphaned silently.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::AccountDisabled` - The user account has been disabled.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    /// - `Error::ProjectIdMismatch` - The project of the tokens does not match the expected one.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::RefreshToken;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// // Load the stored refresh token of the anonymous user if any.
+    /// let stored = Some(RefreshToken::new("stored-refresh-token"));
+    ///
+    /// let session = config.sign_in_anonymously_or_restore(stored).await?;
+    /// ```
+    pub async fn sign_in_anonymously_or_restore(
+        &self,
+        refresh_token: Option<RefreshToken>,
+    ) -> Result<Session> {
+        match refresh_token {
+            | Some(refresh_token) => {
+                self.exchange_refresh_token(refresh_token)
+                    .await
+            },
+            | None => {
+                self.sign_in_anonymously()
+                    .await
+            },
+        }
+    }
+
     /// Signs in a user with the given OAuth credential.
     ///
     /// ## Arguments
@@ -423,7 +748,13 @@ impl Config {
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::ApiError` - API error on the Firebase Auth.
-    /// - `Error::ParseExpriesInFailed` - Failed to parse the expires in value.
+    /// - `Error::MfaRequired` - Multi-factor authentication is required to complete signing in.
+    /// - `Error::AccountLinkingRequired` - Another account with the same email already exists, so the credential must be linked to it.
+    ///
+    /// ## NOTE
+    /// When another account with the same email already exists (`needConfirmation`),
+    /// this fails with `Error::AccountLinkingRequired` containing the [`crate::PendingOAuthLink`]
+    /// to link the credential after the user signs in to the existing account.
     ///
     /// ## Example
     /// ```
@@ -454,38 +785,25 @@ impl Config {
         request_uri: OAuthRequestUri,
         post_body: IdpPostBody,
     ) -> Result<Session> {
-        // Create request payload.
-        let request_payload =
-            api::SignInWithOAuthCredentialRequestBodyPayload::new(
-                request_uri
-                    .inner()
-                    .to_string(),
-                post_body,
-                false,
-            );
-
-        // Send request.
-        let response_payload = api::sign_in_with_oauth_credential(
-            &self.client,
-            &self.api_key,
-            request_payload,
+        self.sign_in_with_oauth_credential_continued(
+            request_uri,
+            post_body,
+            None,
+            None,
         )
-        .await?;
-
-        // Create session.
-        Ok(Session {
-            client: self.client.clone(),
-            api_key: self.api_key.clone(),
-            id_token: IdToken::new(response_payload.id_token),
-            expires_in: ExpiresIn::parse(response_payload.expires_in)?,
-            refresh_token: RefreshToken::new(response_payload.refresh_token),
-        })
+        .await
     }
 
-    /// Exchanges a refresh token for an ID token and new refresh token.
+    /// Signs in a user with the given OAuth credential,
+    /// continuing from a prior request by the session ID or the pending token.
+    ///
+    /// See also [`crate::Config::sign_in_with_oauth_credential`].
     ///
     /// ## Arguments
-    /// - `refresh_token` - A Firebase Auth refresh token.
+    /// - `request_uri` - The URI to which the IDP redirects the user back.
+    /// - `post_body` - The POST body passed to the IDP containing the OAuth credential and provider ID.
+    /// - `session_id` - The optional session ID returned by the createAuthUri to validate the nonce of the OAuth credential.
+    /// - `pending_token` - The optional pending token returned by a prior request to complete the sign in, e.g. [`crate::PendingOAuthLink::pending_token`] of account linking.
     ///
     /// ## Returns
     /// The session for the signed in user.
@@ -496,35 +814,60 @@ impl Config {
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::ApiError` - API error on the Firebase Auth.
-    /// - `Error::ParseExpriesInFailed` - Failed to parse the expires in value.
+    /// - `Error::MfaRequired` - Multi-factor authentication is required to complete signing in.
+    /// - `Error::AccountLinkingRequired` - Another account with the same email already exists, so the credential must be linked to it.
     ///
     /// ## Example
     /// ```
+    /// use std::collections::HashMap;
     /// use fars::Config;
     /// use fars::ApiKey;
-    /// use fars::RefreshToken;
+    /// use fars::OAuthRequestUri;
+    /// use fars::IdpPostBody;
+    /// use fars::ProviderId;
     ///
     /// let config = Config::new(
     ///     ApiKey::new("your-firebase-project-api-key"),
     /// );
     ///
-    /// let session = config.exchange_refresh_token(
-    ///     RefreshToken::new("user-firebase-refresh-token"),
+    /// let session = config.sign_in_with_oauth_credential_continued(
+    ///     OAuthRequestUri::new("https://your-app.com/redirect/path/auth/handler"),
+    ///     IdpPostBody::new(
+    ///         ProviderId::Google,
+    ///         HashMap::from([(
+    ///             "id_token",
+    ///             "google-id-token".to_string(),
+    ///         )]),
+    ///     )?,
+    ///     Some("session-id-of-create-auth-uri".to_string()),
+    ///     None, // pending token
     /// ).await?;
     /// ```
-    pub async fn exchange_refresh_token(
+    pub async fn sign_in_with_oauth_credential_continued(
         &self,
-        refresh_token: RefreshToken,
+        request_uri: OAuthRequestUri,
+        post_body: IdpPostBody,
+        session_id: Option<String>,
+        pending_token: Option<String>,
     ) -> Result<Session> {
         // Create request payload.
-        let request_payload = api::ExchangeRefreshTokenRequestBodyPayload::new(
-            refresh_token
-                .inner()
-                .to_string(),
-        );
+        let mut request_payload =
+            api::SignInWithOAuthCredentialRequestBodyPayload::new(
+                request_uri
+                    .inner()
+                    .to_string(),
+                post_body,
+                false,
+            );
+        if let Some(session_id) = session_id {
+            request_payload = request_payload.with_session_id(session_id);
+        }
+        if let Some(pending_token) = pending_token {
+            request_payload = request_payload.with_pending_token(pending_token);
+        }
 
         // Send request.
-        let response_payload = api::exchange_refresh_token(
+        let response_payload = api::sign_in_with_oauth_credential(
             &self.client,
             &self.api_key,
             request_payload,
@@ -536,20 +879,34 @@ impl Config {
             client: self.client.clone(),
             api_key: self.api_key.clone(),
             id_token: IdToken::new(response_payload.id_token),
-            expires_in: ExpiresIn::parse(response_payload.expires_in)?,
+            expires_in: response_payload.expires_in,
             refresh_token: RefreshToken::new(response_payload.refresh_token),
+            local_id: Uid::new(response_payload.local_id),
+            first_sign_in: response_payload
+                .is_new_user
+                .unwrap_or(false),
+            anonymous: false,
+            display_name: None,
+            photo_url: None,
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
         })
     }
 
-    /// Fetches the list of all IDPs for the specified email.
+    /// Signs in a user with the given OAuth credential,
+    /// and links the OAuth credential to the existing account according to the `auto_link` policy
+    /// when an account with the same email already exists.
+    ///
+    /// This encapsulates the account linking flow: sign in to the existing account and then link the new OAuth credential to it.
     ///
     /// ## Arguments
-    /// - `email` - The email of the user to fetch providers.
-    /// - `continue_uri` - The URI to which the IDP redirects the user back.
+    /// - `request_uri` - The URI to which the IDP redirects the user back.
+    /// - `post_body` - The POST body passed to the IDP containing the OAuth credential and provider ID.
+    /// - `auto_link` - The policy to link the OAuth credential to the existing account.
     ///
     /// ## Returns
-    /// - None - The email address is not registered or protected. See also the [issue](https://github.com/firebase/firebase-ios-sdk/issues/11810).
-    /// - Some - The list of all IDPs for the specified email if the email is registered and not protected.
+    /// The session for the signed in user, or for the existing account that the OAuth credential has been linked to.
     ///
     /// ## Errors
     /// - `Error::HttpRequestError` - Failed to send a request.
@@ -557,39 +914,439 @@ impl Config {
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::ApiError` - API error on the Firebase Auth.
+    /// - `Error::MfaRequired` - Multi-factor authentication is required to complete signing in.
+    ///
+    /// ## WARNING
+    /// Linking grants the OAuth identity full access to the existing account.
+    /// Only use [`AutoLink::WithEmailPassword`] or [`AutoLink::WithSession`]
+    /// after the user has proven the ownership of the existing account,
+    /// and never link automatically based on an email address reported by an ID provider,
+    /// because the email may be unverified and an attacker could take over the account.
+    ///
+    /// ## NOTE
+    /// The linking is performed when the sign in fails with `EMAIL_EXISTS`
+    /// or the response requires a confirmation (`needConfirmation`).
+    /// Other errors are returned as is regardless of the policy.
     ///
     /// ## Example
     /// ```
+    /// use std::collections::HashMap;
+    /// use fars::AutoLink;
     /// use fars::Config;
     /// use fars::ApiKey;
     /// use fars::Email;
-    /// use fars::OAuthContinueUri;
+    /// use fars::OAuthRequestUri;
+    /// use fars::IdpPostBody;
+    /// use fars::Password;
+    /// use fars::ProviderId;
     ///
     /// let config = Config::new(
     ///     ApiKey::new("your-firebase-project-api-key"),
     /// );
     ///
-    /// let providers = config.fetch_providers_for_email(
-    ///     Email::new("user@example"),
-    ///     OAuthContinueUri::new("https://your-app.com/current/path"),
+    /// let session = config.sign_in_with_oauth_credential_auto_link(
+    ///     OAuthRequestUri::new("https://your-app.com/redirect/path/auth/handler"),
+    ///     IdpPostBody::new(
+    ///         ProviderId::Google,
+    ///         HashMap::from([(
+    ///             "access_token",
+    ///             "google-access-token".to_string(),
+    ///         )]),
+    ///     )?,
+    ///     AutoLink::WithEmailPassword {
+    ///         email: Email::new("user@example.com"),
+    ///         password: Password::new("password"),
+    ///     },
     /// ).await?;
     /// ```
-    pub async fn fetch_providers_for_email(
+    pub async fn sign_in_with_oauth_credential_auto_link(
         &self,
-        email: Email,
-        continue_uri: OAuthContinueUri,
-    ) -> Result<Option<Vec<ProviderId>>> {
-        // Create request payload.
-        let request_payload =
-            api::FetchProvidersForEmailRequestBodyPayload::new(
-                email.inner().to_string(),
-                continue_uri
-                    .inner()
-                    .to_string(),
-            );
+        request_uri: OAuthRequestUri,
+        post_body: IdpPostBody,
+        auto_link: AutoLink,
+    ) -> Result<Session> {
+        // Try to sign in with the OAuth credential.
+        let error = match self
+            .sign_in_with_oauth_credential(
+                request_uri.clone(),
+                post_body.clone(),
+            )
+            .await
+        {
+            | Ok(session) => return Ok(session),
+            | Err(error) => error,
+        };
 
-        // Send request.
-        let response_payload = api::fetch_providers_for_email(
+        if !requires_account_linking(&error) {
+            return Err(error);
+        }
+
+        // Get a session of the existing account.
+        let session = match auto_link {
+            | AutoLink::Disabled => return Err(error),
+            | AutoLink::WithEmailPassword {
+                email,
+                password,
+            } => {
+                self.sign_in_with_email_password(email, password)
+                    .await?
+            },
+            | AutoLink::WithSession(session) => session,
+        };
+
+        // Link the OAuth credential to the existing account.
+        session
+            .link_with_oauth_credential(request_uri, post_body)
+            .await
+    }
+
+    /// Exchanges a refresh token for an ID token and new refresh token.
+    ///
+    /// ## Arguments
+    /// - `refresh_token` - A Firebase Auth refresh token.
+    ///
+    /// ## Returns
+    /// The session for the signed in user.
+    ///
+    /// ## NOTE
+    /// The session is anonymous if the ID token has been issued for an anonymous user
+    /// by the `firebase.sign_in_provider` claim, which is read without any verification.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::AccountDisabled` - The user account has been disabled.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    /// - `Error::ProjectIdMismatch` - The project of the tokens does not match the expected one.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::RefreshToken;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let session = config.exchange_refresh_token(
+    ///     RefreshToken::new("user-firebase-refresh-token"),
+    /// ).await?;
+    /// ```
+    pub async fn exchange_refresh_token(
+        &self,
+        refresh_token: RefreshToken,
+    ) -> Result<Session> {
+        // Create request payload.
+        let request_payload = api::ExchangeRefreshTokenRequestBodyPayload::new(
+            refresh_token
+                .expose_secret()
+                .to_string(),
+        );
+
+        // Send request.
+        let response_payload = api::exchange_refresh_token(
+            &self.client,
+            &self.api_key,
+            request_payload,
+        )
+        .await?;
+
+        // Verify the project of the tokens.
+        if let Some(expected) = &self.expected_project_id {
+            if expected.inner() != response_payload.project_id {
+                return Err(Error::ProjectIdMismatch {
+                    expected: expected.clone(),
+                    actual: response_payload.project_id,
+                });
+            }
+        }
+
+        // Create session.
+        let mut session = Session {
+            client: self.client.clone(),
+            api_key: self.api_key.clone(),
+            id_token: IdToken::new(response_payload.id_token),
+            expires_in: response_payload.expires_in,
+            refresh_token: RefreshToken::new(response_payload.refresh_token),
+            local_id: response_payload.user_id,
+            first_sign_in: false,
+            anonymous: false,
+            display_name: None,
+            photo_url: None,
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
+        };
+        session.anonymous = session.signed_in_anonymously_hint();
+
+        Ok(session)
+    }
+
+    /// Signs in a user with the given custom token minted by the Admin SDK.
+    ///
+    /// ## NOTE
+    /// The response of the custom token does not contain the UID of the user,
+    /// so the UID is read from the `sub` claim of the returned ID token without any verification.
+    ///
+    /// The project of a custom token is determined by the service account that signed it,
+    /// not by the `aud` claim, so a mismatch is detected only by the Firebase Auth as `Error::CustomTokenProjectMismatch`.
+    ///
+    /// ## Arguments
+    /// - `token` - A Firebase Auth custom token.
+    ///
+    /// ## Returns
+    /// The session for the signed in user.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    /// - `Error::CustomTokenProjectMismatch` - The custom token has been minted for a different Firebase project.
+    /// - `Error::NotFoundAnyUserData` - The returned ID token does not contain the UID of the signed in user.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let session = config.sign_in_with_custom_token(
+    ///     "custom-token".to_string(),
+    /// ).await?;
+    /// ```
+    pub async fn sign_in_with_custom_token(
+        &self,
+        token: String,
+    ) -> Result<Session> {
+        // Create request payload.
+        let request_payload =
+            api::ExchangeCustomTokenForAnIdAndRefreshTokenRequestBodyPayload::new(
+                token,
+            );
+
+        // Send request.
+        let response_payload =
+            api::exchange_custom_token_for_an_id_and_refresh_token(
+                &self.client,
+                &self.api_key,
+                request_payload,
+            )
+            .await
+            .map_err(Error::map_custom_token_project_mismatch)?;
+
+        /// The subject claim of an ID token, i.e. the UID of the user.
+        #[derive(serde::Deserialize)]
+        struct Subject {
+            sub: Uid,
+        }
+
+        // Get the UID of the user from the ID token.
+        let id_token = IdToken::new(response_payload.id_token);
+        let local_id = id_token
+            .decode_unverified_claims::<Subject>()
+            .ok_or(Error::NotFoundAnyUserData)?
+            .sub;
+
+        // Create session.
+        Ok(Session {
+            client: self.client.clone(),
+            api_key: self.api_key.clone(),
+            id_token,
+            expires_in: response_payload.expires_in,
+            refresh_token: RefreshToken::new(response_payload.refresh_token),
+            local_id,
+            first_sign_in: response_payload
+                .is_new_user
+                .unwrap_or(false),
+            anonymous: false,
+            display_name: None,
+            photo_url: None,
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
+        })
+    }
+
+    /// Signs in users with the given custom tokens concurrently, e.g. to migrate many users on a server.
+    ///
+    /// See also [`crate::Config::sign_in_with_custom_token`].
+    ///
+    /// ## NOTE
+    /// Too high concurrency may be rejected by the rate limiting (`TOO_MANY_ATTEMPTS_TRY_LATER`),
+    /// so please set a retry policy by [`crate::Config::with_retry_policy`] for bulk operations.
+    ///
+    /// ## Arguments
+    /// - `tokens` - Firebase Auth custom tokens.
+    /// - `concurrency` - The maximum number of sign ins in flight, at least 1.
+    ///
+    /// ## Returns
+    /// The results of the sign ins in the same order as the tokens.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let results = config.sign_in_with_custom_tokens(
+    ///     vec![
+    ///         "custom-token-1".to_string(),
+    ///         "custom-token-2".to_string(),
+    ///     ],
+    ///     8,
+    /// ).await;
+    ///
+    /// for result in results {
+    ///     match result {
+    ///         | Ok(session) => println!("Signed in: {}", session.local_id()),
+    ///         | Err(error) => eprintln!("Failed to sign in: {:?}", error),
+    ///     }
+    /// }
+    /// ```
+    pub async fn sign_in_with_custom_tokens(
+        &self,
+        tokens: Vec<String>,
+        concurrency: usize,
+    ) -> Vec<Result<Session>> {
+        futures_util::stream::iter(tokens)
+            .map(|token| self.sign_in_with_custom_token(token))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Creates a session from the tokens obtained externally, e.g. by a platform SDK of the Firebase Auth.
+    ///
+    /// This does not send any request, and the session uses the HTTP client and the API key of this config.
+    /// The ID token is refreshed automatically by the refresh token when it has been expired as other sessions.
+    ///
+    /// ## NOTE
+    /// The ID token is not verified. Please verify it by [`crate::verification`] (optional feature: `verify`) if it comes from an untrusted client.
+    /// The session is anonymous if the ID token has been issued for an anonymous user
+    /// by the `firebase.sign_in_provider` claim.
+    ///
+    /// ## Arguments
+    /// - `id_token` - The Firebase Auth ID token.
+    /// - `refresh_token` - The Firebase Auth refresh token.
+    /// - `expires_in` - The number of seconds in which the ID token expires, e.g. "3600".
+    /// - `local_id` - The user ID of the tokens.
+    ///
+    /// ## Returns
+    /// The session of the tokens.
+    ///
+    /// ## Errors
+    /// - `Error::ParseExpriesInFailed` - Failed to parse the expires in value.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::IdToken;
+    /// use fars::RefreshToken;
+    /// use fars::Uid;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let session = config.session_from_tokens(
+    ///     IdToken::new("id-token"),
+    ///     RefreshToken::new("refresh-token"),
+    ///     "3600".to_string(),
+    ///     Uid::new("user-id"),
+    /// )?;
+    /// ```
+    pub fn session_from_tokens(
+        &self,
+        id_token: IdToken,
+        refresh_token: RefreshToken,
+        expires_in: String,
+        local_id: Uid,
+    ) -> Result<Session> {
+        let mut session = Session {
+            client: self.client.clone(),
+            api_key: self.api_key.clone(),
+            id_token,
+            expires_in: ExpiresIn::parse(expires_in)?,
+            refresh_token,
+            local_id,
+            first_sign_in: false,
+            anonymous: false,
+            display_name: None,
+            photo_url: None,
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
+        };
+        session.anonymous = session.signed_in_anonymously_hint();
+
+        Ok(session)
+    }
+
+    /// Fetches the list of all IDPs for the specified email.
+    ///
+    /// ## Arguments
+    /// - `email` - The email of the user to fetch providers.
+    /// - `continue_uri` - The optional URI to which the IDP redirects the user back, defaults to `"http://localhost"`.
+    ///
+    /// ## NOTE
+    /// The endpoint requires a continue URI, so the default one is sent when it is `None` as the official JavaScript SDK does.
+    /// The continue URI does not affect the providers to fetch.
+    ///
+    /// ## Returns
+    /// - None - The email address is not registered or protected. See also the [issue](https://github.com/firebase/firebase-ios-sdk/issues/11810).
+    /// - Some - The list of all IDPs for the specified email if the email is registered and not protected.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let providers = config.fetch_providers_for_email(
+    ///     Email::new("user@example"),
+    ///     None,
+    /// ).await?;
+    /// ```
+    pub async fn fetch_providers_for_email(
+        &self,
+        email: Email,
+        continue_uri: Option<OAuthContinueUri>,
+    ) -> Result<Option<Vec<ProviderId>>> {
+        // Create request payload.
+        let request_payload =
+            api::FetchProvidersForEmailRequestBodyPayload::new(
+                email.inner().to_string(),
+                continue_uri
+                    .as_ref()
+                    .map_or("http://localhost", |uri| uri.inner())
+                    .to_string(),
+            );
+
+        // Send request.
+        let response_payload = api::fetch_providers_for_email(
             &self.client,
             &self.api_key,
             request_payload,
@@ -614,8 +1371,20 @@ impl Config {
     ///
     /// ## Arguments
     /// - `email` - The email of the user to send password reset email.
+    /// - `action_code_settings` - The optional settings of the action code, e.g. the continue URL.
     /// - `locale` - The optional language code corresponding to the user's locale.
     ///
+    /// ## Returns
+    /// `Ok(())` when the request has been accepted.
+    ///
+    /// ## NOTE
+    /// When the [email enumeration protection](https://cloud.google.com/identity-platform/docs/admin/email-enumeration-protection) is enabled on the project,
+    /// the Firebase Auth returns success regardless of whether the email is registered or not,
+    /// so `Ok(())` does not mean that the email has been sent to an existing user.
+    /// Please show the same message to the user in both cases not to reveal registered emails.
+    ///
+    /// When the protection is disabled, an unregistered email fails with `Error::ApiError` with `CommonErrorCode::EmailNotFound`.
+    ///
     /// ## Errors
     /// - `Error::InvalidHeaderValue` - Invalid header value.
     /// - `Error::HttpRequestError` - Failed to send a request.
@@ -635,23 +1404,85 @@ impl Config {
     /// );
     ///
     /// config.send_reset_password_email(
-    ///     Email::new("user@example".),
+    ///     Email::new("user@example"),
+    ///     None, // action code settings
     ///     None, // locale
     /// ).await?;
     /// ```
     pub async fn send_reset_password_email(
         &self,
         email: Email,
+        action_code_settings: Option<ActionCodeSettings>,
         locale: Option<LanguageCode>,
     ) -> Result<()> {
+        self.send_reset_password_email_returning_oob_code(
+            email,
+            action_code_settings,
+            locale,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Sends a password reset email to the given email address and returns the sent OOB code and link.
+    ///
+    /// ## Arguments
+    /// - `email` - The email of the user to send password reset email.
+    /// - `action_code_settings` - The optional settings of the action code, e.g. the continue URL.
+    /// - `locale` - The optional language code corresponding to the user's locale.
+    ///
+    /// ## Returns
+    /// The OOB code and link sent by the email.
+    ///
+    /// ## NOTE
+    /// The OOB code and link are only returned by the [Firebase Local Emulator Suite](https://firebase.google.com/docs/emulator-suite),
+    /// so this is realistically useful only for integration tests against the emulator.
+    /// Production responses do not include them and both fields of [`SentOobCode`] are `None`.
+    ///
+    /// ## Errors
+    /// - `Error::InvalidHeaderValue` - Invalid header value.
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let sent = config.send_reset_password_email_returning_oob_code(
+    ///     Email::new("user@example.com"),
+    ///     None, // action code settings
+    ///     None, // locale
+    /// ).await?;
+    ///
+    /// if let Some(oob_code) = sent.oob_code {
+    ///     let info = config.check_action_code(oob_code).await?;
+    /// }
+    /// ```
+    pub async fn send_reset_password_email_returning_oob_code(
+        &self,
+        email: Email,
+        action_code_settings: Option<ActionCodeSettings>,
+        locale: Option<LanguageCode>,
+    ) -> Result<SentOobCode> {
         // Create request payload.
         let request_payload =
             api::SendPasswordResetEmailRequestBodyPayload::new(
                 email.inner().to_string(),
+                action_code_settings,
             );
 
         // Send request.
-        api::send_password_reset_email(
+        let response_payload = api::send_password_reset_email(
             &self.client,
             &self.api_key,
             request_payload,
@@ -659,6 +1490,376 @@ impl Config {
         )
         .await?;
 
-        Ok(())
+        Ok(SentOobCode {
+            oob_code: response_payload
+                .oob_code
+                .map(OobCode::new),
+            oob_link: response_payload.oob_link,
+        })
+    }
+
+    /// Generates a password reset link for the given email address without sending an email,
+    /// e.g. to send the link by your own email service.
+    ///
+    /// ## Arguments
+    /// - `email` - The email of the user to reset the password.
+    /// - `action_code_settings` - The optional settings of the action code, e.g. the continue URL.
+    ///
+    /// ## Returns
+    /// The generated password reset link.
+    ///
+    /// ## NOTE
+    /// This is a privileged operation of the Admin SDK that requires the credentials of a service account,
+    /// e.g. an OAuth access token set as the `Authorization` header by [`crate::Client::with_default_headers`].
+    /// It is NOT available with a plain API key and fails with `Error::RequiresAdminCredentials` without sending any request;
+    /// please use [`Config::send_reset_password_email`] to let the Firebase Auth send the email instead.
+    ///
+    /// ## Errors
+    /// - `Error::InvalidHeaderValue` - Invalid header value.
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::RequiresAdminCredentials` - The operation requires the credentials of a service account.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Error;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// match config.generate_password_reset_link(
+    ///     Email::new("user@example.com"),
+    ///     None, // action code settings
+    /// ).await {
+    ///     | Ok(link) => println!("Generated link: {}", link),
+    ///     | Err(Error::RequiresAdminCredentials { .. }) => {
+    ///         // Fallback to send the email by the Firebase Auth.
+    ///     },
+    ///     | Err(error) => return Err(error.into()),
+    /// }
+    /// ```
+    pub async fn generate_password_reset_link(
+        &self,
+        email: Email,
+        action_code_settings: Option<ActionCodeSettings>,
+    ) -> Result<String> {
+        self.generate_action_link(
+            "PASSWORD_RESET",
+            email,
+            action_code_settings,
+        )
+        .await
+    }
+
+    /// Generates an email verification link for the given email address without sending an email,
+    /// e.g. to send the link by your own email service.
+    ///
+    /// ## Arguments
+    /// - `email` - The email of the user to verify.
+    /// - `action_code_settings` - The optional settings of the action code, e.g. the continue URL.
+    ///
+    /// ## Returns
+    /// The generated email verification link.
+    ///
+    /// ## NOTE
+    /// This is a privileged operation of the Admin SDK that requires the credentials of a service account,
+    /// e.g. an OAuth access token set as the `Authorization` header by [`crate::Client::with_default_headers`].
+    /// It is NOT available with a plain API key and fails with `Error::RequiresAdminCredentials` without sending any request;
+    /// please use [`crate::Session::send_email_verification`] to let the Firebase Auth send the email instead.
+    ///
+    /// ## Errors
+    /// - `Error::InvalidHeaderValue` - Invalid header value.
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::RequiresAdminCredentials` - The operation requires the credentials of a service account.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let link = config.generate_email_verification_link(
+    ///     Email::new("user@example.com"),
+    ///     None, // action code settings
+    /// ).await?;
+    /// ```
+    pub async fn generate_email_verification_link(
+        &self,
+        email: Email,
+        action_code_settings: Option<ActionCodeSettings>,
+    ) -> Result<String> {
+        self.generate_action_link(
+            "VERIFY_EMAIL",
+            email,
+            action_code_settings,
+        )
+        .await
+    }
+
+    /// Checks an action code (out-of-band code) sent to the user's email without applying it.
+    ///
+    /// This is the equivalent of `checkActionCode` of the Firebase JavaScript SDK.
+    ///
+    /// ## Arguments
+    /// - `oob_code` - The action code, e.g. extracted from an action link by [`crate::OobCode::from_link`].
+    ///
+    /// ## Returns
+    /// The information of the action code describing the operation and the target email.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::OobCode;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let info = config.check_action_code(
+    ///     OobCode::new("oob-code"),
+    /// ).await?;
+    /// ```
+    pub async fn check_action_code(
+        &self,
+        oob_code: OobCode,
+    ) -> Result<ActionCodeInfo> {
+        // Create request payload.
+        let request_payload = api::CheckActionCodeRequestBodyPayload::new(
+            oob_code.inner().to_string(),
+        );
+
+        // Send request.
+        let response_payload = api::check_action_code(
+            &self.client,
+            &self.api_key,
+            request_payload,
+        )
+        .await?;
+
+        Ok(ActionCodeInfo {
+            operation: ActionCodeOperation::parse(
+                response_payload.request_type,
+            ),
+            email: response_payload.email,
+            new_email: response_payload.new_email,
+        })
+    }
+
+    /// Checks and applies an action code (out-of-band code) sent to the user's email.
+    ///
+    /// This is a single entry point to handle action links that users clicked,
+    /// the equivalent of `checkActionCode` and `applyActionCode` of the Firebase JavaScript SDK.
+    ///
+    /// ## NOTE
+    /// Only email verification, email recovery and email change (verify and change email) are applied by this method.
+    /// Other operations, e.g. password reset that requires a new password and email link sign in, are only checked and not applied,
+    /// so please continue them by the returned [`crate::ActionCodeInfo::operation`].
+    ///
+    /// ## Arguments
+    /// - `oob_code` - The action code, e.g. extracted from an action link by [`crate::OobCode::from_link`].
+    ///
+    /// ## Returns
+    /// The information of the action code describing the operation and the target email.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::OobCode;
+    /// use fars::ActionCodeOperation;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let oob_code = OobCode::from_link(
+    ///     "https://your-app.com/auth/action?mode=verifyEmail&oobCode=code",
+    /// ).unwrap();
+    ///
+    /// let info = config.apply_action_code(oob_code).await?;
+    ///
+    /// match info.operation {
+    ///     | ActionCodeOperation::PasswordReset => {
+    ///         // Ask the user a new password and confirm the password reset.
+    ///     },
+    ///     | _ => {
+    ///         // Applied.
+    ///     },
+    /// }
+    /// ```
+    pub async fn apply_action_code(
+        &self,
+        oob_code: OobCode,
+    ) -> Result<ActionCodeInfo> {
+        // Check the operation of the action code.
+        let info = self
+            .check_action_code(oob_code.clone())
+            .await?;
+
+        match info.operation {
+            | ActionCodeOperation::VerifyEmail
+            | ActionCodeOperation::RecoverEmail
+            | ActionCodeOperation::VerifyAndChangeEmail => {
+                // Create request payload.
+                let request_payload =
+                    api::ApplyActionCodeRequestBodyPayload::new(
+                        oob_code.inner().to_string(),
+                    );
+
+                // Send request.
+                api::apply_action_code(
+                    &self.client,
+                    &self.api_key,
+                    request_payload,
+                )
+                .await?;
+
+                Ok(info)
+            },
+            | _ => Ok(info),
+        }
+    }
+
+    /// Fetches the password policy of the project.
+    ///
+    /// The policy is fetched from the `v2/passwordPolicy` endpoint that requires only the API key, not any ID token.
+    ///
+    /// ## Returns
+    /// The password policy configured on the project.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let policy = config.fetch_password_policy().await?;
+    /// ```
+    pub async fn fetch_password_policy(&self) -> Result<PasswordPolicy> {
+        // Send request.
+        let response_payload =
+            api::fetch_password_policy(&self.client, &self.api_key).await?;
+
+        Ok(response_payload.into())
+    }
+
+    /// Fetches the sign in methods enabled on the project, e.g. to hide disabled options on a sign in UI
+    /// instead of failing with `OPERATION_NOT_ALLOWED` at runtime.
+    ///
+    /// The methods are fetched from the public project config that requires only the API key, not any ID token.
+    ///
+    /// ## Returns
+    /// The sign in methods enabled on the project.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::ProviderId;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let methods = config.fetch_enabled_sign_in_methods().await?;
+    ///
+    /// if methods.is_enabled(&ProviderId::Google) == Some(true) {
+    ///     // Show the Google sign in button.
+    /// }
+    /// ```
+    pub async fn fetch_enabled_sign_in_methods(
+        &self
+    ) -> Result<EnabledSignInMethods> {
+        // Send request.
+        let response_payload =
+            api::fetch_project_config(&self.client, &self.api_key).await?;
+
+        Ok(response_payload.into())
+    }
+
+    /// Generates an action link of the given request type without sending an email.
+    async fn generate_action_link(
+        &self,
+        request_type: &'static str,
+        email: Email,
+        action_code_settings: Option<ActionCodeSettings>,
+    ) -> Result<String> {
+        // Create request payload.
+        let request_payload = api::GenerateActionLinkRequestBodyPayload::new(
+            request_type.to_string(),
+            email.inner().to_string(),
+            action_code_settings,
+        );
+
+        // Send request.
+        let response_payload = api::generate_action_link(
+            &self.client,
+            &self.api_key,
+            request_payload,
+        )
+        .await?;
+
+        Ok(response_payload.oob_link)
+    }
+}
+
+/// Whether the error of signing in with an OAuth credential requires linking to an existing account.
+fn requires_account_linking(error: &Error) -> bool {
+    match error {
+        | Error::ApiError {
+            error_code: CommonErrorCode::EmailExists,
+            ..
+        } => true,
+        | Error::AccountLinkingRequired {
+            ..
+        } => true,
+        | _ => false,
     }
 }
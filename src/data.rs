@@ -1,20 +1,35 @@
 //! Shared data structures for the Firebase Auth API.
 
 // Internal modules
+pub(super) mod account_info;
+pub(super) mod action_code_info;
+pub(super) mod action_code_operation;
+pub(super) mod action_code_settings;
 pub(super) mod api_key;
+pub(super) mod auto_link;
 pub(super) mod delete_attribute;
 pub(super) mod display_name;
 pub(super) mod email;
+pub(super) mod enabled_sign_in_methods;
 pub(super) mod expires_in;
 pub(super) mod id_token;
 pub(super) mod idp_post_body;
 pub(super) mod language_code;
+pub(super) mod mfa_enrollment;
 pub(super) mod oauth_continue_uri;
 pub(super) mod oauth_request_uri;
+pub(super) mod oob_code;
 pub(super) mod password;
+pub(super) mod password_policy;
+pub(super) mod pending_oauth_link;
 pub(super) mod photo_url;
 pub(super) mod project_id;
 pub(super) mod provider_id;
 pub(super) mod provider_user_info;
 pub(super) mod refresh_token;
+pub(super) mod retry_policy;
+pub(super) mod secret;
+pub(super) mod sent_oob_code;
+pub(super) mod tokens;
+pub(super) mod uid;
 pub(super) mod user_data;
@@ -0,0 +1,89 @@
+use std::time::Duration;
+use std::time::SystemTime;
+
+use crate::ProviderId;
+use crate::UserData;
+
+/// Account information of the Firebase Auth that bundles the user data and the derived values,
+/// e.g. for a settings screen.
+///
+/// See also [`crate::Session::account_info`].
+///
+/// ## Example
+/// ```
+/// use fars::Config;
+/// use fars::ApiKey;
+/// use fars::Email;
+/// use fars::Password;
+///
+/// let config = Config::new(
+///     ApiKey::new("your-firebase-project-api-key"),
+/// );
+///
+/// let session = config.sign_in_with_email_password(
+///     Email::new("user@example"),
+///     Password::new("password"),
+/// ).await?;
+///
+/// let (new_session, account_info) = session.account_info().await?;
+///
+/// if account_info.is_anonymous {
+///     // Show an "upgrade your account" UI.
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccountInfo {
+    /// The raw user data.
+    pub user_data: UserData,
+    /// Whether the account is an anonymous user, see also [`UserData::is_anonymous`].
+    pub is_anonymous: bool,
+    /// The linked provider IDs.
+    pub linked_providers: Vec<ProviderId>,
+    /// The time when the account was created at.
+    pub created_at: Option<SystemTime>,
+    /// The time when the account last logged in at.
+    pub last_login_at: Option<SystemTime>,
+    /// The time when the account password was last changed.
+    pub password_updated_at: Option<SystemTime>,
+    /// The time before which the Firebase ID tokens are considered revoked.
+    pub valid_since: Option<SystemTime>,
+}
+
+impl From<UserData> for AccountInfo {
+    fn from(user_data: UserData) -> Self {
+        let linked_providers = user_data
+            .provider_user_info
+            .iter()
+            .flatten()
+            .map(|info| info.typed_provider_id())
+            .collect();
+
+        Self {
+            is_anonymous: user_data.is_anonymous(),
+            linked_providers,
+            created_at: parse_millis(&user_data.created_at),
+            last_login_at: parse_millis(&user_data.last_login_at),
+            password_updated_at: user_data
+                .password_updated_at
+                .filter(|millis| millis.is_finite() && *millis >= 0.0)
+                .map(|millis| {
+                    SystemTime::UNIX_EPOCH
+                        + Duration::from_secs_f64(millis / 1000.0)
+                }),
+            valid_since: user_data
+                .valid_since
+                .as_deref()
+                .and_then(|secs| secs.parse::<u64>().ok())
+                .map(|secs| SystemTime::UNIX_EPOCH + Duration::from_secs(secs)),
+            user_data,
+        }
+    }
+}
+
+/// Parses a timestamp in milliseconds of the Firebase Auth.
+fn parse_millis(millis: &str) -> Option<SystemTime> {
+    millis
+        .parse::<u64>()
+        .ok()
+        .map(|millis| SystemTime::UNIX_EPOCH + Duration::from_millis(millis))
+}
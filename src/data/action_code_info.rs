@@ -0,0 +1,14 @@
+use crate::ActionCodeOperation;
+
+/// Information of an action code (out-of-band code) of the Firebase Auth.
+///
+/// See also [`crate::Config::check_action_code`] and [`crate::Config::apply_action_code`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ActionCodeInfo {
+    /// The operation of the action code.
+    pub operation: ActionCodeOperation,
+    /// The email of the account that the action code targets.
+    pub email: Option<String>,
+    /// The new email of the account for email change operations.
+    pub new_email: Option<String>,
+}
@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+/// Operation of an action code (out-of-band code) of the Firebase Auth.
+///
+/// ## NOTE
+/// This enum is marked as `#[non_exhaustive]` to add new operations without breaking changes,
+/// so please include a wildcard arm (`_ => ...`) when matching on it.
+#[derive(Clone, Debug, PartialEq, Hash, Eq)]
+#[non_exhaustive]
+pub enum ActionCodeOperation {
+    /// Password reset.
+    PasswordReset,
+    /// Email verification.
+    VerifyEmail,
+    /// Email recovery, i.e. revert of an email change.
+    RecoverEmail,
+    /// Email change after verifying the new email.
+    VerifyAndChangeEmail,
+    /// Email link sign in.
+    EmailSignIn,
+    /// Revert of a second factor addition.
+    RevertSecondFactorAddition,
+    /// Operation that is not listed here.
+    Other(String),
+}
+
+impl Display for ActionCodeOperation {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.format())
+    }
+}
+
+impl ActionCodeOperation {
+    /// Formats the operation to a string representation of the Firebase Auth.
+    ///
+    /// ## Returns
+    /// String representation of the operation of the Firebase Auth.
+    pub fn format(&self) -> &str {
+        match self {
+            | ActionCodeOperation::PasswordReset => "PASSWORD_RESET",
+            | ActionCodeOperation::VerifyEmail => "VERIFY_EMAIL",
+            | ActionCodeOperation::RecoverEmail => "RECOVER_EMAIL",
+            | ActionCodeOperation::VerifyAndChangeEmail => {
+                "VERIFY_AND_CHANGE_EMAIL"
+            },
+            | ActionCodeOperation::EmailSignIn => "EMAIL_SIGNIN",
+            | ActionCodeOperation::RevertSecondFactorAddition => {
+                "REVERT_SECOND_FACTOR_ADDITION"
+            },
+            | ActionCodeOperation::Other(string) => string,
+        }
+    }
+
+    /// Parses a string to an operation of an action code.
+    ///
+    /// ## Arguments
+    /// - `string` - String to parse.
+    ///
+    /// ## Returns
+    /// Operation of an action code, [`ActionCodeOperation::Other`] if the string is not listed.
+    pub fn parse(string: String) -> Self {
+        match string.as_str() {
+            | "PASSWORD_RESET" => ActionCodeOperation::PasswordReset,
+            | "VERIFY_EMAIL" => ActionCodeOperation::VerifyEmail,
+            | "RECOVER_EMAIL" => ActionCodeOperation::RecoverEmail,
+            | "VERIFY_AND_CHANGE_EMAIL" => {
+                ActionCodeOperation::VerifyAndChangeEmail
+            },
+            | "EMAIL_SIGNIN" => ActionCodeOperation::EmailSignIn,
+            | "REVERT_SECOND_FACTOR_ADDITION" => {
+                ActionCodeOperation::RevertSecondFactorAddition
+            },
+            | _ => ActionCodeOperation::Other(string),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use serde::Serialize;
+
+/// Settings of an action code (out-of-band code) sent by emails, e.g. email verification and password reset.
+///
+/// All fields are optional and only specified fields are sent to the Firebase Auth.
+///
+/// See also [document](https://firebase.google.com/docs/auth/web/passing-state-in-email-actions#passing_statecontinue_url_in_email_actions).
+///
+/// ## Example
+/// ```
+/// use fars::ActionCodeSettings;
+///
+/// let settings = ActionCodeSettings {
+///     continue_url: Some("https://your-app.com/finish".to_string()),
+///     handle_code_in_app: Some(true),
+///     ios_bundle_id: Some("com.example.ios".to_string()),
+///     android_package_name: Some("com.example.android".to_string()),
+///     android_install_app: Some(true),
+///     android_minimum_version: Some("12".to_string()),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ActionCodeSettings {
+    /// The URL to continue to after the user clicks the link sent in the email.
+    #[serde(
+        rename = "continueUrl",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub continue_url: Option<String>,
+    /// Whether the action code link will be opened in a mobile app or a web page first.
+    #[serde(
+        rename = "canHandleCodeInApp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub handle_code_in_app: Option<bool>,
+    /// The bundle ID of the iOS app to open the link.
+    #[serde(
+        rename = "iOSBundleId",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ios_bundle_id: Option<String>,
+    /// The package name of the Android app to open the link.
+    #[serde(
+        rename = "androidPackageName",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub android_package_name: Option<String>,
+    /// Whether to install the Android app if the device supports it and the app is not already installed.
+    #[serde(
+        rename = "androidInstallApp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub android_install_app: Option<bool>,
+    /// The minimum version of the Android app to open the link.
+    #[serde(
+        rename = "androidMinimumVersion",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub android_minimum_version: Option<String>,
+    /// The dynamic link domain to use for the link when it is opened in a mobile app.
+    #[serde(
+        rename = "dynamicLinkDomain",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dynamic_link_domain: Option<String>,
+}
@@ -1,4 +1,6 @@
+use std::convert::Infallible;
 use std::env::VarError;
+use std::str::FromStr;
 
 /// The Firebase project API key.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
@@ -7,6 +9,9 @@ pub struct ApiKey {
 }
 
 impl ApiKey {
+    /// The default environment variable name of the API key.
+    pub const DEFAULT_ENV_KEY: &'static str = "FIREBASE_API_KEY";
+
     /// Creates a new API key.
     pub fn new<S>(inner: S) -> Self
     where
@@ -17,14 +22,77 @@ impl ApiKey {
         }
     }
 
-    /// Loads API key from environment variable: `"FIREBASE_API_KEY"`.
+    /// Loads API key from the default environment variable: `"FIREBASE_API_KEY"`.
     pub fn from_env() -> std::result::Result<Self, VarError> {
-        let key = std::env::var("FIREBASE_API_KEY")?;
+        Self::from_env_key(Self::DEFAULT_ENV_KEY)
+    }
+
+    /// Loads API key from specified environment variable.
+    pub fn from_env_key(key: &str) -> std::result::Result<Self, VarError> {
+        let api_key = std::env::var(key)?;
 
-        Ok(Self::new(key))
+        Ok(Self::new(api_key))
     }
 
     pub(crate) fn inner(&self) -> &str {
         &self.inner
     }
 }
+
+impl FromStr for ApiKey {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for ApiKey {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for ApiKey {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct Arguments {
+        #[arg(long)]
+        api_key: ApiKey,
+    }
+
+    #[test]
+    fn parses_from_command_line_argument() {
+        let arguments = Arguments::try_parse_from([
+            "app",
+            "--api-key",
+            "your-firebase-project-api-key",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            arguments.api_key,
+            ApiKey::new("your-firebase-project-api-key")
+        );
+    }
+
+    #[test]
+    fn parses_from_str() {
+        assert_eq!(
+            "your-firebase-project-api-key".parse::<ApiKey>(),
+            Ok(ApiKey::new(
+                "your-firebase-project-api-key"
+            ))
+        );
+    }
+}
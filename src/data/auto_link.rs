@@ -0,0 +1,42 @@
+use crate::Email;
+use crate::Password;
+use crate::Session;
+
+/// Policy to automatically link an OAuth credential to an existing account
+/// when signing in with the OAuth credential is rejected because an account with the same email already exists.
+///
+/// See also [`crate::Config::sign_in_with_oauth_credential_auto_link`].
+///
+/// ## WARNING
+/// Linking a credential to an existing account grants the OAuth identity full access to that account.
+/// Only provide the credentials or the session of the existing account after the user has proven the ownership of it,
+/// e.g. by entering the password of the existing account by themselves or by signing in to it in the current device.
+/// Never build this policy from information that the user has not proven, such as the email address returned by an ID provider.
+///
+/// ## Example
+/// ```
+/// use fars::AutoLink;
+/// use fars::Email;
+/// use fars::Password;
+///
+/// let auto_link = AutoLink::WithEmailPassword {
+///     email: Email::new("user@example.com"),
+///     password: Password::new("password"),
+/// };
+/// ```
+#[derive(Clone, Debug, Default)]
+#[allow(clippy::large_enum_variant)]
+pub enum AutoLink {
+    /// Does not link automatically and returns the error as is.
+    #[default]
+    Disabled,
+    /// Signs in to the existing account with email and password, then links the OAuth credential to it.
+    WithEmailPassword {
+        /// The email of the existing account.
+        email: Email,
+        /// The password of the existing account.
+        password: Password,
+    },
+    /// Links the OAuth credential to the existing account of the given session.
+    WithSession(Session),
+}
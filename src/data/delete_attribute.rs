@@ -1,18 +1,108 @@
 /// Attributes to delete profile information.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/accounts/update#userattributename).
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum DeleteAttribute {
     /// Delete the display name.
     DisplayName,
     /// Delete the photo url.
     PhotoUrl,
+    /// Delete the email.
+    Email,
+    /// Delete the password.
+    Password,
+    /// Delete the raw user information of the ID providers.
+    RawUserInfo,
+    /// Delete the linked ID providers.
+    Provider,
+    /// Other attribute that is not supported by this crate yet, e.g. added by the Firebase Auth in the future.
+    Custom(String),
 }
 
 impl DeleteAttribute {
     /// Formats the delete attribute to a string representation of the Firebase Auth.
-    pub fn format(self) -> &'static str {
+    pub fn format(&self) -> &str {
         match self {
             | DeleteAttribute::DisplayName => "DISPLAY_NAME",
             | DeleteAttribute::PhotoUrl => "PHOTO_URL",
+            | DeleteAttribute::Email => "EMAIL",
+            | DeleteAttribute::Password => "PASSWORD",
+            | DeleteAttribute::RawUserInfo => "RAW_USER_INFO",
+            | DeleteAttribute::Provider => "PROVIDER",
+            | DeleteAttribute::Custom(attribute) => attribute,
         }
     }
+
+    /// Parses a string representation of the Firebase Auth to a delete attribute.
+    ///
+    /// ## Arguments
+    /// - `string` - String to parse.
+    ///
+    /// ## Returns
+    /// Delete attribute, [`DeleteAttribute::Custom`] if the string is unknown.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::DeleteAttribute;
+    ///
+    /// assert_eq!(DeleteAttribute::parse("PHOTO_URL".to_string()), DeleteAttribute::PhotoUrl);
+    /// assert_eq!(DeleteAttribute::PhotoUrl.format(), "PHOTO_URL");
+    /// ```
+    pub fn parse(string: String) -> Self {
+        match string.as_str() {
+            | "DISPLAY_NAME" => DeleteAttribute::DisplayName,
+            | "PHOTO_URL" => DeleteAttribute::PhotoUrl,
+            | "EMAIL" => DeleteAttribute::Email,
+            | "PASSWORD" => DeleteAttribute::Password,
+            | "RAW_USER_INFO" => DeleteAttribute::RawUserInfo,
+            | "PROVIDER" => DeleteAttribute::Provider,
+            | _ => DeleteAttribute::Custom(string),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_and_parse_round_trip() {
+        let attributes = [
+            DeleteAttribute::DisplayName,
+            DeleteAttribute::PhotoUrl,
+            DeleteAttribute::Email,
+            DeleteAttribute::Password,
+            DeleteAttribute::RawUserInfo,
+            DeleteAttribute::Provider,
+            DeleteAttribute::Custom("UNKNOWN_ATTRIBUTE".to_string()),
+        ];
+
+        for attribute in attributes {
+            assert_eq!(
+                DeleteAttribute::parse(attribute.format().to_string()),
+                attribute
+            );
+        }
+    }
+
+    #[test]
+    fn format_known_attributes() {
+        assert_eq!(DeleteAttribute::Email.format(), "EMAIL");
+        assert_eq!(
+            DeleteAttribute::Provider.format(),
+            "PROVIDER"
+        );
+    }
+
+    #[test]
+    fn parse_unknown_attribute() {
+        assert_eq!(
+            DeleteAttribute::parse("UNKNOWN_ATTRIBUTE".to_string()),
+            DeleteAttribute::Custom("UNKNOWN_ATTRIBUTE".to_string())
+        );
+        assert_eq!(
+            DeleteAttribute::parse("email".to_string()),
+            DeleteAttribute::Custom("email".to_string())
+        );
+    }
 }
@@ -1,3 +1,6 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
 /// A display name of a user.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct DisplayName {
@@ -19,3 +22,23 @@ impl DisplayName {
         &self.inner
     }
 }
+
+impl FromStr for DisplayName {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for DisplayName {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for DisplayName {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
@@ -1,3 +1,6 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
 /// Email of an user.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct Email {
@@ -15,7 +18,79 @@ impl Email {
         }
     }
 
+    /// Returns a normalized email that is trimmed and lowercased,
+    /// e.g. to avoid "account not found" on an email with trailing spaces or mixed cases that users typed.
+    ///
+    /// ## NOTE
+    /// Emails are not normalized automatically on any API, so please call this explicitly if needed.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Email;
+    ///
+    /// let email = Email::new(" User@Example.com ").normalized();
+    ///
+    /// assert_eq!(email, Email::new("user@example.com"));
+    /// ```
+    pub fn normalized(&self) -> Self {
+        Self::new(
+            self.inner
+                .trim()
+                .to_lowercase(),
+        )
+    }
+
     pub(crate) fn inner(&self) -> &str {
         &self.inner
     }
 }
+
+impl FromStr for Email {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for Email {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for Email {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_raw_email_without_normalization() {
+        let email: Email = " User@Example.com "
+            .parse()
+            .unwrap();
+
+        assert_eq!(email.inner(), " User@Example.com ");
+        assert_eq!(
+            email.normalized(),
+            Email::new("user@example.com")
+        );
+    }
+
+    #[test]
+    fn converts_from_strings() {
+        assert_eq!(
+            Email::from("user@example.com"),
+            Email::new("user@example.com")
+        );
+        assert_eq!(
+            Email::from("user@example.com".to_string()),
+            Email::new("user@example.com")
+        );
+    }
+}
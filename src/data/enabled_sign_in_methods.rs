@@ -0,0 +1,153 @@
+use crate::api::FetchProjectConfigResponsePayload;
+use crate::ProviderId;
+
+/// Sign in methods enabled on the Firebase project, e.g. to hide disabled options on a sign in UI.
+///
+/// See also [`crate::Config::fetch_enabled_sign_in_methods`].
+///
+/// ## NOTE
+/// Each field is `None` when the project config omits it, so please treat it as unknown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EnabledSignInMethods {
+    /// Whether the email and password sign in is enabled.
+    pub email_password: Option<bool>,
+    /// Whether the anonymous sign in is enabled.
+    pub anonymous: Option<bool>,
+    /// The enabled OAuth ID providers.
+    pub providers: Option<Vec<ProviderId>>,
+}
+
+impl EnabledSignInMethods {
+    /// Checks whether the provider is enabled.
+    ///
+    /// ## Arguments
+    /// - `provider_id` - The provider ID to check.
+    ///
+    /// ## Returns
+    /// `None` if the project config omits the flag of the provider.
+    pub fn is_enabled(
+        &self,
+        provider_id: &ProviderId,
+    ) -> Option<bool> {
+        match provider_id {
+            | ProviderId::Password => self.email_password,
+            | ProviderId::Anonymous => self.anonymous,
+            | _ => self
+                .providers
+                .as_ref()
+                .map(|providers| providers.contains(provider_id)),
+        }
+    }
+}
+
+impl From<FetchProjectConfigResponsePayload> for EnabledSignInMethods {
+    fn from(payload: FetchProjectConfigResponsePayload) -> Self {
+        Self {
+            email_password: payload.allow_password_user,
+            anonymous: payload.enable_anonymous_user,
+            providers: payload
+                .idp_config
+                .map(|configs| {
+                    configs
+                        .into_iter()
+                        .filter(|config| {
+                            config
+                                .enabled
+                                .unwrap_or(false)
+                        })
+                        .filter_map(|config| config.provider)
+                        .map(parse_provider)
+                        .collect()
+                }),
+        }
+    }
+}
+
+/// Parses a provider of the project config, accepting the legacy provider names of the Identity Toolkit API (v3), e.g. "GOOGLE".
+fn parse_provider(provider: String) -> ProviderId {
+    match provider
+        .trim()
+        .to_ascii_uppercase()
+        .as_str()
+    {
+        | "APPLE" => ProviderId::Apple,
+        | "FACEBOOK" => ProviderId::Facebook,
+        | "GITHUB" => ProviderId::GitHub,
+        | "GOOGLE" => ProviderId::Google,
+        | "MICROSOFT" => ProviderId::Microsoft,
+        | "PLAYGAMES" => ProviderId::GooglePlayGames,
+        | "TWITTER" => ProviderId::Twitter,
+        | "YAHOO" => ProviderId::Yahoo,
+        | _ => ProviderId::parse(provider),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::IdpConfig;
+
+    fn idp_config(
+        provider: &str,
+        enabled: Option<bool>,
+    ) -> IdpConfig {
+        IdpConfig {
+            provider: Some(provider.to_string()),
+            enabled,
+        }
+    }
+
+    #[test]
+    fn converts_enabled_providers() {
+        let methods =
+            EnabledSignInMethods::from(FetchProjectConfigResponsePayload {
+                project_id: None,
+                authorized_domains: None,
+                allow_password_user: Some(true),
+                enable_anonymous_user: None,
+                idp_config: Some(vec![
+                    idp_config("google.com", Some(true)),
+                    idp_config("FACEBOOK", Some(true)),
+                    idp_config("github.com", Some(false)),
+                    idp_config("twitter.com", None),
+                ]),
+            });
+
+        assert_eq!(
+            methods.providers,
+            Some(vec![
+                ProviderId::Google,
+                ProviderId::Facebook
+            ])
+        );
+        assert_eq!(
+            methods.is_enabled(&ProviderId::Password),
+            Some(true)
+        );
+        assert_eq!(
+            methods.is_enabled(&ProviderId::Anonymous),
+            None
+        );
+        assert_eq!(
+            methods.is_enabled(&ProviderId::GitHub),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn treats_omitted_providers_as_unknown() {
+        let methods =
+            EnabledSignInMethods::from(FetchProjectConfigResponsePayload {
+                project_id: None,
+                authorized_domains: None,
+                allow_password_user: None,
+                enable_anonymous_user: None,
+                idp_config: None,
+            });
+
+        assert_eq!(
+            methods.is_enabled(&ProviderId::Google),
+            None
+        );
+    }
+}
@@ -1,9 +1,14 @@
 use std::time::Duration;
 
+use serde::{Deserialize, Deserializer};
+
 use crate::Error;
 use crate::Result;
 
 /// Expiration time in seconds of the Firebase Auth ID token.
+///
+/// This is deserialized from either a string, e.g. `"3600"`, or a number, e.g. `3600`,
+/// because some environments, e.g. the emulator, return it as a number.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
 pub struct ExpiresIn {
     inner: Duration,
@@ -28,3 +33,67 @@ impl ExpiresIn {
         self.inner
     }
 }
+
+impl<'de> Deserialize<'de> for ExpiresIn {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        /// The representations of the expiration time in a response.
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Representation {
+            String(String),
+            Number(u64),
+        }
+
+        match Representation::deserialize(deserializer)? {
+            | Representation::String(string) => {
+                Self::parse(string).map_err(serde::de::Error::custom)
+            },
+            | Representation::Number(seconds) => Ok(Self {
+                inner: Duration::from_secs(seconds),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_string() {
+        let expires_in: ExpiresIn = serde_json::from_str("\"3600\"").unwrap();
+
+        assert_eq!(
+            expires_in.inner(),
+            Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn deserialize_number() {
+        let expires_in: ExpiresIn = serde_json::from_str("3600").unwrap();
+
+        assert_eq!(
+            expires_in.inner(),
+            Duration::from_secs(3600)
+        );
+    }
+
+    #[test]
+    fn deserialize_negative_number() {
+        assert!(serde_json::from_str::<ExpiresIn>("-1").is_err());
+        assert!(serde_json::from_str::<ExpiresIn>("\"-1\"").is_err());
+    }
+
+    #[test]
+    fn deserialize_malformed_string() {
+        assert!(serde_json::from_str::<ExpiresIn>("\"1h\"").is_err());
+        assert!(matches!(
+            ExpiresIn::parse("1h".to_string()),
+            Err(Error::ParseExpiresInFailed { .. })
+        ));
+    }
+}
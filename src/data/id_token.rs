@@ -1,4 +1,11 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
+use crate::data::secret::mask_secret;
+
 /// ID token of the Firebase Auth.
+///
+/// Equality and hash are over the raw token, so it can be used as a key of an in-process cache, e.g. of verified claims.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct IdToken {
     inner: String,
@@ -15,8 +22,74 @@ impl IdToken {
         }
     }
 
+    /// Returns the raw ID token as a secret.
+    ///
+    /// ## NOTE
+    /// The raw ID token is a credential of the user, so be careful not to leak it in logs.
+    /// Use [`IdToken::masked`] to log it.
+    pub fn expose_secret(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns a masked representation of the ID token that is safe to write in logs.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::IdToken;
+    ///
+    /// let token = IdToken::new("abcdefghijklmnopqrstuvwxyz");
+    ///
+    /// assert_eq!(token.masked(), "abcd...wxyz");
+    /// ```
+    pub fn masked(&self) -> String {
+        mask_secret(&self.inner)
+    }
+
+    /// Decodes the payload claims of the ID token without any verification.
+    ///
+    /// ## Returns
+    /// The decoded claims, or `None` if the ID token is malformed.
+    pub(crate) fn decode_unverified_claims<T>(&self) -> Option<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        use base64::Engine;
+
+        // Decode the payload of the ID token.
+        let payload = self.inner.split('.').nth(1)?;
+        let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(payload)
+            .ok()?;
+
+        serde_json::from_slice(&decoded).ok()
+    }
+
     /// Returns the inner representation.
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use `expose_secret` to handle the raw token deliberately."
+    )]
     pub fn inner(&self) -> &str {
         &self.inner
     }
 }
+
+impl FromStr for IdToken {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for IdToken {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for IdToken {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
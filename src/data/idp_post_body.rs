@@ -7,18 +7,21 @@ use crate::Result;
 /// Post body for ID providers contains the OAuth credential and provider ID.
 #[derive(Clone, Debug)]
 pub struct IdpPostBody {
-    pub(crate) query: String,
+    query: String,
 }
 
 impl IdpPostBody {
     /// Creates a new post body for identity providers.
     ///
+    /// The credentials are not validated, please use [`IdpPostBody::try_new`]
+    /// to check the field required by the provider before sending a request.
+    ///
     /// ## Arguments
     /// - `provider_id` - The ID of the identity provider.
     /// - `credentials` - The credentials of the identity provider as hash map.
     ///
     /// ## Errors
-    /// - `UrlEncodeFailed` - Failed to encode the post body as URL encoded string.
+    /// - `Error::UrlEncodeFailed` - Failed to encode the post body as URL encoded string.
     ///
     /// ## Examples
     /// ```
@@ -53,4 +56,253 @@ impl IdpPostBody {
             query,
         })
     }
+
+    /// Creates a new post body for identity providers, validating that the credentials contain the field required by the provider.
+    ///
+    /// The required keys of the credentials are:
+    ///
+    /// | Provider | Required keys |
+    /// | --- | --- |
+    /// | `ProviderId::Google` | `id_token` or `access_token` |
+    /// | `ProviderId::Apple` | `id_token` |
+    /// | `ProviderId::Facebook` | `access_token` or `id_token` (Limited Login) |
+    /// | `ProviderId::GitHub` | `access_token` |
+    /// | `ProviderId::Microsoft` | `id_token` or `access_token` |
+    /// | `ProviderId::Yahoo` | `id_token` or `access_token` |
+    /// | `ProviderId::Twitter` | `access_token` and `oauth_token_secret` |
+    ///
+    /// The credentials of other providers, e.g. `ProviderId::Custom`, are not validated,
+    /// as the same as [`IdpPostBody::new`].
+    ///
+    /// ## Arguments
+    /// - `provider_id` - The ID of the identity provider.
+    /// - `credentials` - The credentials of the identity provider as hash map.
+    ///
+    /// ## Errors
+    /// - `Error::MissingIdpCredential` - The credentials miss the field required by the provider.
+    /// - `Error::UrlEncodeFailed` - Failed to encode the post body as URL encoded string.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use fars::IdpPostBody;
+    /// use fars::ProviderId;
+    ///
+    /// let post_body = IdpPostBody::try_new(
+    ///     ProviderId::Apple,
+    ///     HashMap::from([(
+    ///         "id_token",
+    ///         "apple-id-token".to_string(),
+    ///     )]),
+    /// )?;
+    /// ```
+    pub fn try_new(
+        provider_id: ProviderId,
+        credentials: HashMap<&str, String>,
+    ) -> Result<Self> {
+        for required in required_credentials(&provider_id) {
+            let contains = required.iter().any(|key| {
+                credentials
+                    .get(key)
+                    .is_some_and(|value| !value.is_empty())
+            });
+
+            if !contains {
+                return Err(Error::MissingIdpCredential {
+                    provider_id,
+                    required,
+                });
+            }
+        }
+
+        Self::new(provider_id, credentials)
+    }
+
+    /// Creates a new post body for the Twitter with the OAuth 1.0a credentials.
+    ///
+    /// The Firebase Auth requires both the access token and the token secret of the OAuth 1.0a for the Twitter.
+    ///
+    /// ## Arguments
+    /// - `access_token` - The OAuth 1.0a access token (`oauth_token`) of the Twitter.
+    /// - `oauth_token_secret` - The OAuth 1.0a token secret (`oauth_token_secret`) of the Twitter.
+    ///
+    /// ## Errors
+    /// - `Error::MissingIdpCredential` - The access token or the token secret is empty.
+    /// - `Error::UrlEncodeFailed` - Failed to encode the post body as URL encoded string.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fars::IdpPostBody;
+    ///
+    /// let post_body = IdpPostBody::twitter(
+    ///     "twitter-oauth-token".to_string(),
+    ///     "twitter-oauth-token-secret".to_string(),
+    /// )?;
+    /// ```
+    pub fn twitter(
+        access_token: String,
+        oauth_token_secret: String,
+    ) -> Result<Self> {
+        Self::try_new(
+            ProviderId::Twitter,
+            HashMap::from([
+                ("access_token", access_token),
+                ("oauth_token_secret", oauth_token_secret),
+            ]),
+        )
+    }
+
+    /// Creates a new post body for the Facebook Limited Login with the OpenID Connect ID token.
+    ///
+    /// The Facebook Limited Login returns an ID token instead of an access token,
+    /// so the post body contains `id_token` instead of `access_token`.
+    ///
+    /// See also [the official document](https://developers.facebook.com/docs/facebook-login/limited-login).
+    ///
+    /// ## Arguments
+    /// - `id_token` - The OpenID Connect ID token returned by the Facebook Limited Login.
+    ///
+    /// ## Errors
+    /// - `Error::UrlEncodeFailed` - Failed to encode the post body as URL encoded string.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fars::IdpPostBody;
+    ///
+    /// let post_body = IdpPostBody::facebook_limited_login(
+    ///     "facebook-id-token".to_string(),
+    /// )?;
+    /// ```
+    pub fn facebook_limited_login(id_token: String) -> Result<Self> {
+        Self::new(
+            ProviderId::Facebook,
+            HashMap::from([("id_token", id_token)]),
+        )
+    }
+
+    /// Returns the URL encoded query string of the post body, e.g. for debugging.
+    ///
+    /// ## NOTE
+    /// The query string contains the OAuth credential, so be careful not to leak it in logs.
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::collections::HashMap;
+    /// use fars::IdpPostBody;
+    /// use fars::ProviderId;
+    ///
+    /// let post_body = IdpPostBody::new(
+    ///     ProviderId::Google,
+    ///     HashMap::from([(
+    ///         "access_token",
+    ///         "google-access-token".to_string(),
+    ///     )]),
+    /// )?;
+    ///
+    /// println!("Post body: {}", post_body.as_query());
+    /// ```
+    pub fn as_query(&self) -> &str {
+        &self.query
+    }
+}
+
+/// Returns the keys of the credentials required by the ID provider,
+/// where one of the keys in each group is required.
+fn required_credentials(
+    provider_id: &ProviderId
+) -> &'static [&'static [&'static str]] {
+    match provider_id {
+        | ProviderId::Google | ProviderId::Microsoft | ProviderId::Yahoo => {
+            &[&[
+                "id_token",
+                "access_token",
+            ]]
+        },
+        | ProviderId::Apple => &[&["id_token"]],
+        | ProviderId::Facebook => &[&[
+            "access_token",
+            "id_token",
+        ]],
+        | ProviderId::GitHub => &[&["access_token"]],
+        | ProviderId::Twitter => &[
+            &["access_token"],
+            &["oauth_token_secret"],
+        ],
+        | _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_query_returns_url_encoded_post_body() {
+        let post_body = IdpPostBody::new(
+            ProviderId::Google,
+            HashMap::from([(
+                "access_token",
+                "token with spaces&symbols=".to_string(),
+            )]),
+        )
+        .unwrap();
+
+        let decoded: HashMap<String, String> =
+            serde_urlencoded::from_str(post_body.as_query()).unwrap();
+        assert_eq!(
+            decoded,
+            HashMap::from([
+                (
+                    "providerId".to_string(),
+                    "google.com".to_string()
+                ),
+                (
+                    "access_token".to_string(),
+                    "token with spaces&symbols=".to_string()
+                ),
+            ])
+        );
+        assert!(post_body
+            .as_query()
+            .contains("access_token=token+with+spaces%26symbols%3D"));
+    }
+
+    #[test]
+    fn as_query_contains_twitter_token_secret() {
+        let post_body = IdpPostBody::twitter(
+            "oauth-token".to_string(),
+            "oauth-token-secret".to_string(),
+        )
+        .unwrap();
+
+        let decoded: HashMap<String, String> =
+            serde_urlencoded::from_str(post_body.as_query()).unwrap();
+        assert_eq!(
+            decoded.get("providerId"),
+            Some(&"twitter.com".to_string())
+        );
+        assert_eq!(
+            decoded.get("oauth_token_secret"),
+            Some(&"oauth-token-secret".to_string())
+        );
+    }
+
+    #[test]
+    fn try_new_rejects_missing_credential() {
+        let result = IdpPostBody::try_new(
+            ProviderId::Apple,
+            HashMap::from([(
+                "access_token",
+                "apple-access-token".to_string(),
+            )]),
+        );
+
+        assert!(matches!(
+            result,
+            Err(Error::MissingIdpCredential {
+                provider_id: ProviderId::Apple,
+                required: ["id_token"],
+            })
+        ));
+    }
 }
@@ -1,5 +1,10 @@
 /// The BCP 47 language code.
+///
+/// ## NOTE
+/// This enum is marked as `#[non_exhaustive]` to add new languages without breaking changes,
+/// so please include a wildcard arm (`_ => ...`) when matching on it.
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum LanguageCode {
     /// Arabic (Saudi Arabia)
     ArSA,
@@ -168,4 +173,197 @@ impl LanguageCode {
             | LanguageCode::ZhTW => "zh-TW",
         }
     }
+
+    /// Negotiates the best language code from an `Accept-Language` header value and the supported language codes,
+    /// e.g. to pass the locale of a browser to the Firebase Auth.
+    ///
+    /// The language ranges are tried in the order of the quality values (`q`),
+    /// and each range is matched by the lookup of [RFC 4647](https://datatracker.ietf.org/doc/html/rfc4647#section-3.4)
+    /// that progressively truncates the range, e.g. `en-AU` to `en`, until a supported language code matches.
+    /// A truncated range matches a supported language code that has the range as a prefix, e.g. `en` matches `en-US`.
+    ///
+    /// ## Arguments
+    /// - `accept_language` - The `Accept-Language` header value, e.g. `"fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5"`.
+    /// - `supported` - The supported language codes in the order of the preference.
+    ///
+    /// ## Returns
+    /// The best matched language code, or `None` if no language code matches.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::LanguageCode;
+    ///
+    /// let language_code = LanguageCode::negotiate(
+    ///     "en-AU, ja;q=0.9",
+    ///     &[LanguageCode::JaJP, LanguageCode::EnUS],
+    /// );
+    ///
+    /// assert_eq!(language_code, Some(LanguageCode::EnUS));
+    /// ```
+    pub fn negotiate(
+        accept_language: &str,
+        supported: &[LanguageCode],
+    ) -> Option<LanguageCode> {
+        // Parse the language ranges with the quality values.
+        let mut ranges = accept_language
+            .split(',')
+            .filter_map(|item| {
+                let mut parameters = item.split(';');
+                let range = parameters.next()?.trim();
+                if range.is_empty() {
+                    return None;
+                }
+
+                let quality = parameters
+                    .filter_map(|parameter| {
+                        parameter
+                            .trim()
+                            .strip_prefix("q=")
+                    })
+                    .find_map(|quality| {
+                        quality
+                            .trim()
+                            .parse::<f32>()
+                            .ok()
+                    })
+                    .unwrap_or(1.0);
+
+                Some((range, quality))
+            })
+            .filter(|(_, quality)| *quality > 0.0)
+            .collect::<Vec<_>>();
+
+        // Sort by the quality values keeping the order of the same quality.
+        ranges.sort_by(|(_, left), (_, right)| right.total_cmp(left));
+
+        ranges
+            .into_iter()
+            .find_map(|(range, _)| lookup(range, supported))
+    }
+}
+
+/// Looks up a supported language code by a language range with the progressive truncation.
+fn lookup(
+    range: &str,
+    supported: &[LanguageCode],
+) -> Option<LanguageCode> {
+    if range == "*" {
+        return supported.first().copied();
+    }
+
+    let mut range = range.to_ascii_lowercase();
+    loop {
+        let matched = supported
+            .iter()
+            .find(|language_code| {
+                let tag = language_code
+                    .format()
+                    .to_ascii_lowercase();
+                tag == range || tag.starts_with(&format!("{}-", range))
+            });
+        if let Some(language_code) = matched {
+            return Some(*language_code);
+        }
+
+        // Truncate the last subtag of the range.
+        match range.rfind('-') {
+            | Some(index) => range.truncate(index),
+            | None => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate_prefers_higher_quality() {
+        assert_eq!(
+            LanguageCode::negotiate(
+                "en-US;q=0.5, ja;q=0.9",
+                &[
+                    LanguageCode::EnUS,
+                    LanguageCode::JaJP
+                ],
+            ),
+            Some(LanguageCode::JaJP)
+        );
+    }
+
+    #[test]
+    fn negotiate_keeps_order_of_same_quality() {
+        assert_eq!(
+            LanguageCode::negotiate(
+                "ja, en-US",
+                &[
+                    LanguageCode::EnUS,
+                    LanguageCode::JaJP
+                ],
+            ),
+            Some(LanguageCode::JaJP)
+        );
+    }
+
+    #[test]
+    fn negotiate_truncates_range() {
+        assert_eq!(
+            LanguageCode::negotiate(
+                "en-AU",
+                &[
+                    LanguageCode::JaJP,
+                    LanguageCode::EnUS
+                ],
+            ),
+            Some(LanguageCode::EnUS)
+        );
+    }
+
+    #[test]
+    fn negotiate_matches_case_insensitively() {
+        assert_eq!(
+            LanguageCode::negotiate("EN-us", &[LanguageCode::EnUS]),
+            Some(LanguageCode::EnUS)
+        );
+    }
+
+    #[test]
+    fn negotiate_skips_zero_quality_and_malformed_ranges() {
+        assert_eq!(
+            LanguageCode::negotiate(
+                "ja;q=0, , en;q=abc",
+                &[
+                    LanguageCode::JaJP,
+                    LanguageCode::EnUS
+                ],
+            ),
+            Some(LanguageCode::EnUS)
+        );
+    }
+
+    #[test]
+    fn negotiate_wildcard_returns_first_supported() {
+        assert_eq!(
+            LanguageCode::negotiate(
+                "fr, *;q=0.5",
+                &[
+                    LanguageCode::JaJP,
+                    LanguageCode::EnUS
+                ],
+            ),
+            Some(LanguageCode::JaJP)
+        );
+    }
+
+    #[test]
+    fn negotiate_returns_none_without_match() {
+        assert_eq!(
+            LanguageCode::negotiate("fr-CH, fr", &[LanguageCode::JaJP]),
+            None
+        );
+        assert_eq!(
+            LanguageCode::negotiate("", &[LanguageCode::JaJP]),
+            None
+        );
+    }
 }
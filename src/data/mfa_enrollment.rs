@@ -0,0 +1,20 @@
+use serde::Deserialize;
+
+/// Information of a second factor enrolled by a user for the multi-factor authentication.
+///
+/// See also [API reference](https://cloud.google.com/identity-platform/docs/reference/rest/v1/MfaEnrollment).
+#[derive(Deserialize, PartialEq, Clone, Debug)]
+pub struct MfaEnrollment {
+    /// The ID of the enrolled second factor.
+    #[serde(rename = "mfaEnrollmentId")]
+    pub mfa_enrollment_id: String,
+    /// The display name of the second factor.
+    #[serde(rename = "displayName")]
+    pub display_name: Option<String>,
+    /// The obfuscated phone number of the phone second factor.
+    #[serde(rename = "phoneInfo")]
+    pub phone_info: Option<String>,
+    /// The timestamp when the second factor was enrolled.
+    #[serde(rename = "enrolledAt")]
+    pub enrolled_at: Option<String>,
+}
@@ -1,3 +1,6 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
 /// OAuth continue URI.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct OAuthContinueUri {
@@ -20,3 +23,23 @@ impl OAuthContinueUri {
         &self.inner
     }
 }
+
+impl FromStr for OAuthContinueUri {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for OAuthContinueUri {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for OAuthContinueUri {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
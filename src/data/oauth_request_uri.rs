@@ -1,3 +1,6 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
 /// OAuth request URI.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct OAuthRequestUri {
@@ -20,3 +23,23 @@ impl OAuthRequestUri {
         &self.inner
     }
 }
+
+impl FromStr for OAuthRequestUri {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for OAuthRequestUri {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for OAuthRequestUri {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
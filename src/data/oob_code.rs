@@ -0,0 +1,71 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
+/// Out-of-band (OOB) code of the Firebase Auth sent by emails,
+/// e.g. email verification, password reset and email change.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct OobCode {
+    inner: String,
+}
+
+impl OobCode {
+    /// Creates a new OOB code.
+    pub fn new<S>(inner: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            inner: inner.into(),
+        }
+    }
+
+    /// Extracts an OOB code from the `oobCode` query parameter of an action link that the user clicked.
+    ///
+    /// ## Arguments
+    /// - `link` - The action link, e.g. `https://your-app.com/auth/action?mode=verifyEmail&oobCode=code`.
+    ///
+    /// ## Returns
+    /// The OOB code if the link is a valid URL and contains the `oobCode` query parameter.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::OobCode;
+    ///
+    /// let oob_code = OobCode::from_link(
+    ///     "https://your-app.com/auth/action?mode=verifyEmail&oobCode=code",
+    /// );
+    ///
+    /// assert_eq!(oob_code, Some(OobCode::new("code")));
+    /// ```
+    pub fn from_link(link: &str) -> Option<Self> {
+        let url = reqwest::Url::parse(link).ok()?;
+
+        url.query_pairs()
+            .find(|(key, _)| key == "oobCode")
+            .map(|(_, value)| Self::new(value))
+    }
+
+    pub(crate) fn inner(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl FromStr for OobCode {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for OobCode {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for OobCode {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
@@ -1,3 +1,6 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
 /// Password of an user.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct Password {
@@ -19,3 +22,23 @@ impl Password {
         &self.inner
     }
 }
+
+impl FromStr for Password {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for Password {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for Password {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
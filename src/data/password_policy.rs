@@ -0,0 +1,64 @@
+use crate::api::FetchPasswordPolicyResponsePayload;
+
+/// Password policy of the Firebase project.
+///
+/// See also [document](https://cloud.google.com/identity-platform/docs/password-policy).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    /// Whether the policy is enforced on the server.
+    pub enforced: bool,
+    /// Whether users must upgrade the password to satisfy the policy on signing in.
+    pub force_upgrade_on_sign_in: bool,
+    /// The minimum length of the password.
+    pub min_password_length: Option<u32>,
+    /// The maximum length of the password.
+    pub max_password_length: Option<u32>,
+    /// Whether the password requires a lowercase character.
+    pub requires_lowercase: bool,
+    /// Whether the password requires an uppercase character.
+    pub requires_uppercase: bool,
+    /// Whether the password requires a numeric character.
+    pub requires_numeric: bool,
+    /// Whether the password requires a non-alphanumeric character.
+    pub requires_non_alphanumeric: bool,
+    /// The allowed non-alphanumeric characters.
+    pub allowed_non_alphanumeric_characters: Vec<String>,
+    /// The schema version of the policy.
+    pub schema_version: Option<u32>,
+}
+
+impl From<FetchPasswordPolicyResponsePayload> for PasswordPolicy {
+    fn from(payload: FetchPasswordPolicyResponsePayload) -> Self {
+        let options = payload
+            .custom_strength_options
+            .unwrap_or_default();
+
+        Self {
+            enforced: payload
+                .enforcement_state
+                .as_deref()
+                == Some("ENFORCE"),
+            force_upgrade_on_sign_in: payload
+                .force_upgrade_on_signin
+                .unwrap_or(false),
+            min_password_length: options.min_password_length,
+            max_password_length: options.max_password_length,
+            requires_lowercase: options
+                .contains_lowercase_character
+                .unwrap_or(false),
+            requires_uppercase: options
+                .contains_uppercase_character
+                .unwrap_or(false),
+            requires_numeric: options
+                .contains_numeric_character
+                .unwrap_or(false),
+            requires_non_alphanumeric: options
+                .contains_non_alphanumeric_character
+                .unwrap_or(false),
+            allowed_non_alphanumeric_characters: payload
+                .allowed_non_alphanumeric_characters
+                .unwrap_or_default(),
+            schema_version: payload.schema_version,
+        }
+    }
+}
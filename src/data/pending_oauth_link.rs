@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::data::secret::mask_secret;
+use crate::IdpPostBody;
+use crate::ProviderId;
+use crate::Result;
+
+/// A pending OAuth credential to link to an existing account
+/// when signing in with the OAuth credential requires a confirmation (`needConfirmation`),
+/// i.e. another account with the same email already exists.
+///
+/// Sign in to the existing account by the providers in [`PendingOAuthLink::verified_providers`],
+/// then link the OAuth credential by [`crate::Session::link_with_oauth_credential`] with [`PendingOAuthLink::idp_post_body`].
+/// The [`PendingOAuthLink::pending_token`] can be passed to [`crate::Config::sign_in_with_oauth_credential_continued`]
+/// to continue signing in with the OAuth credential, e.g. when the response omits the raw credential.
+///
+/// See also `Error::AccountLinkingRequired` and [`crate::Config::sign_in_with_oauth_credential`].
+///
+/// ## WARNING
+/// Link the OAuth credential only after the user has proven the ownership of the existing account.
+///
+/// ## Example
+/// ```
+/// use fars::Error;
+///
+/// match config.sign_in_with_oauth_credential(request_uri, post_body).await {
+///     | Ok(session) => {
+///         // Signed in.
+///     },
+///     | Err(Error::AccountLinkingRequired { pending }) => {
+///         // Sign in to the existing account by one of the providers.
+///         println!("Sign in with: {:?}", pending.verified_providers);
+///
+///         let session = sign_in_existing_account().await?;
+///
+///         // Link the pending OAuth credential to the existing account.
+///         let session = session.link_with_oauth_credential(
+///             request_uri,
+///             pending.idp_post_body()?,
+///         ).await?;
+///     },
+///     | Err(error) => {
+///         // Handle other errors.
+///     },
+/// }
+/// ```
+#[derive(Clone)]
+pub struct PendingOAuthLink {
+    /// The provider ID of the OAuth credential, e.g. `ProviderId::Google`.
+    pub provider_id: ProviderId,
+    /// The email of the existing account.
+    pub email: Option<String>,
+    /// The unique ID identifies the IdP account.
+    pub federated_id: Option<String>,
+    /// The providers that the existing account has signed in with.
+    pub verified_providers: Vec<ProviderId>,
+    /// The OIDC ID token of the ID provider.
+    oauth_id_token: Option<String>,
+    /// The OAuth access token of the ID provider.
+    oauth_access_token: Option<String>,
+    /// The OAuth 1.0 token secret of the ID provider.
+    oauth_token_secret: Option<String>,
+    /// The pending token to continue signing in with the OAuth credential.
+    pending_token: Option<String>,
+}
+
+impl std::fmt::Debug for PendingOAuthLink {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        let masked = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(mask_secret)
+        };
+
+        f.debug_struct("PendingOAuthLink")
+            .field("provider_id", &self.provider_id)
+            .field("email", &self.email)
+            .field("federated_id", &self.federated_id)
+            .field(
+                "verified_providers",
+                &self.verified_providers,
+            )
+            .field(
+                "oauth_id_token",
+                &masked(&self.oauth_id_token),
+            )
+            .field(
+                "oauth_access_token",
+                &masked(&self.oauth_access_token),
+            )
+            .field(
+                "oauth_token_secret",
+                &masked(&self.oauth_token_secret),
+            )
+            .field(
+                "pending_token",
+                &masked(&self.pending_token),
+            )
+            .finish()
+    }
+}
+
+impl PendingOAuthLink {
+    /// Parses a pending OAuth credential from the response body of signing in with an OAuth credential.
+    ///
+    /// ## Returns
+    /// The pending OAuth credential if the response requires a confirmation, otherwise `None`.
+    pub(crate) fn parse(json: &str) -> Option<Self> {
+        let response =
+            serde_json::from_str::<NeedConfirmationResponse>(json).ok()?;
+
+        if !response.need_confirmation {
+            return None;
+        }
+
+        Some(Self {
+            provider_id: ProviderId::parse(response.provider_id),
+            email: response.email,
+            federated_id: response.federated_id,
+            verified_providers: response
+                .verified_provider
+                .unwrap_or_default()
+                .into_iter()
+                .map(ProviderId::parse)
+                .collect(),
+            oauth_id_token: response.oauth_id_token,
+            oauth_access_token: response.oauth_access_token,
+            oauth_token_secret: response.oauth_token_secret,
+            pending_token: response.pending_token,
+        })
+    }
+
+    /// Returns the raw OIDC ID token of the ID provider.
+    ///
+    /// ## WARNING
+    /// The raw token grants access to the IdP account, so be careful not to leak it in logs.
+    pub fn oauth_id_token(&self) -> Option<&str> {
+        self.oauth_id_token.as_deref()
+    }
+
+    /// Returns the raw OAuth access token of the ID provider.
+    ///
+    /// ## WARNING
+    /// The raw token grants access to the IdP account, so be careful not to leak it in logs.
+    pub fn oauth_access_token(&self) -> Option<&str> {
+        self.oauth_access_token
+            .as_deref()
+    }
+
+    /// Returns the raw OAuth 1.0 token secret of the ID provider.
+    ///
+    /// ## WARNING
+    /// The raw token grants access to the IdP account, so be careful not to leak it in logs.
+    pub fn oauth_token_secret(&self) -> Option<&str> {
+        self.oauth_token_secret
+            .as_deref()
+    }
+
+    /// Returns the raw pending token to continue signing in with the OAuth credential
+    /// by [`crate::Config::sign_in_with_oauth_credential_continued`].
+    ///
+    /// ## WARNING
+    /// The raw token completes signing in to the IdP account, so be careful not to leak it in logs.
+    pub fn pending_token(&self) -> Option<&str> {
+        self.pending_token.as_deref()
+    }
+
+    /// Creates a post body to link the pending OAuth credential to the existing account
+    /// by [`crate::Session::link_with_oauth_credential`].
+    ///
+    /// ## NOTE
+    /// The Firebase Auth may omit the raw credential in the response,
+    /// then please reuse the post body of the original sign in instead.
+    ///
+    /// ## Errors
+    /// - `Error::MissingIdpCredential` - The response does not contain the credential required by the provider.
+    /// - `Error::UrlEncodeFailed` - Failed to encode the post body as URL encoded string.
+    pub fn idp_post_body(&self) -> Result<IdpPostBody> {
+        let mut credentials = HashMap::new();
+        if let Some(id_token) = &self.oauth_id_token {
+            credentials.insert("id_token", id_token.clone());
+        }
+        if let Some(access_token) = &self.oauth_access_token {
+            credentials.insert("access_token", access_token.clone());
+        }
+        if let Some(token_secret) = &self.oauth_token_secret {
+            credentials.insert(
+                "oauth_token_secret",
+                token_secret.clone(),
+            );
+        }
+
+        IdpPostBody::try_new(self.provider_id.clone(), credentials)
+    }
+}
+
+/// Sign in response payload that requires a confirmation to link the OAuth credential.
+#[derive(Deserialize)]
+struct NeedConfirmationResponse {
+    #[serde(rename = "needConfirmation", default)]
+    need_confirmation: bool,
+    #[serde(rename = "providerId")]
+    provider_id: String,
+    #[serde(rename = "email")]
+    email: Option<String>,
+    #[serde(rename = "federatedId")]
+    federated_id: Option<String>,
+    #[serde(rename = "verifiedProvider")]
+    verified_provider: Option<Vec<String>>,
+    #[serde(rename = "oauthIdToken")]
+    oauth_id_token: Option<String>,
+    #[serde(rename = "oauthAccessToken")]
+    oauth_access_token: Option<String>,
+    #[serde(rename = "oauthTokenSecret")]
+    oauth_token_secret: Option<String>,
+    #[serde(rename = "pendingToken")]
+    pending_token: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_need_confirmation_with_pending_token() {
+        let pending = PendingOAuthLink::parse(
+            r#"{
+                "needConfirmation": true,
+                "providerId": "google.com",
+                "email": "user@example.com",
+                "federatedId": "https://accounts.google.com/1234567890",
+                "verifiedProvider": ["password"],
+                "oauthIdToken": "google-id-token",
+                "pendingToken": "pending-token"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(pending.provider_id, ProviderId::Google);
+        assert_eq!(
+            pending.verified_providers,
+            vec![ProviderId::Password]
+        );
+        assert_eq!(
+            pending.pending_token(),
+            Some("pending-token")
+        );
+        assert!(!format!("{:?}", pending).contains("pending-token"));
+    }
+
+    #[test]
+    fn parse_without_need_confirmation() {
+        assert!(PendingOAuthLink::parse(
+            r#"{
+                "providerId": "google.com",
+                "pendingToken": "pending-token"
+            }"#,
+        )
+        .is_none());
+    }
+}
@@ -1,3 +1,6 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
 /// A photo URL of a user.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct PhotoUrl {
@@ -20,3 +23,23 @@ impl PhotoUrl {
         &self.inner
     }
 }
+
+impl FromStr for PhotoUrl {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for PhotoUrl {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for PhotoUrl {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
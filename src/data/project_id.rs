@@ -1,4 +1,6 @@
+use std::convert::Infallible;
 use std::env::VarError;
+use std::str::FromStr;
 
 /// The Firebase project ID.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
@@ -7,6 +9,9 @@ pub struct ProjectId {
 }
 
 impl ProjectId {
+    /// The default environment variable name of the project ID.
+    pub const DEFAULT_ENV_KEY: &'static str = "FIREBASE_PROJECT_ID";
+
     /// Creates a new project ID.
     pub fn new<S>(inner: S) -> Self
     where
@@ -17,9 +22,14 @@ impl ProjectId {
         }
     }
 
-    /// Loads API key from environment variable: `"FIREBASE_PROJECT_ID"`.
+    /// Loads project ID from the default environment variable: `"FIREBASE_PROJECT_ID"`.
     pub fn from_env() -> std::result::Result<Self, VarError> {
-        let id = std::env::var("FIREBASE_PROJECT_ID")?;
+        Self::from_env_key(Self::DEFAULT_ENV_KEY)
+    }
+
+    /// Loads project ID from specified environment variable.
+    pub fn from_env_key(key: &str) -> std::result::Result<Self, VarError> {
+        let id = std::env::var(key)?;
 
         Ok(Self::new(id))
     }
@@ -29,3 +39,23 @@ impl ProjectId {
         &self.inner
     }
 }
+
+impl FromStr for ProjectId {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for ProjectId {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for ProjectId {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
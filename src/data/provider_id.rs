@@ -1,10 +1,21 @@
 use std::fmt::Display;
 
 /// Identity provider IDs defined at [document](https://firebase.google.com/docs/projects/provisioning/configure-oauth#add-idp).
+///
+/// Provider IDs that are not listed here are represented by [`ProviderId::Custom`].
+///
+/// ## NOTE
+/// This enum is marked as `#[non_exhaustive]` to add new providers without breaking changes,
+/// so please include a wildcard arm (`_ => ...`) when matching on it.
 #[derive(Clone, Debug, PartialEq, Hash, Eq)]
+#[non_exhaustive]
 pub enum ProviderId {
-    /// Password,
+    /// Email and password.
     Password,
+    /// Phone number.
+    Phone,
+    /// Anonymous.
+    Anonymous,
     /// Apple.
     Apple,
     /// Apple Game Center.
@@ -34,6 +45,8 @@ impl Display for ProviderId {
     ) -> std::fmt::Result {
         match self {
             | ProviderId::Password => write!(f, "password"),
+            | ProviderId::Phone => write!(f, "phone"),
+            | ProviderId::Anonymous => write!(f, "anonymous"),
             | ProviderId::Apple => write!(f, "apple.com"),
             | ProviderId::AppleGameCenter => write!(f, "gc.apple.com"),
             | ProviderId::Facebook => write!(f, "facebook.com"),
@@ -56,6 +69,8 @@ impl ProviderId {
     pub fn format(&self) -> String {
         match self {
             | ProviderId::Password => "password".to_string(),
+            | ProviderId::Phone => "phone".to_string(),
+            | ProviderId::Anonymous => "anonymous".to_string(),
             | ProviderId::Apple => "apple.com".to_string(),
             | ProviderId::AppleGameCenter => "gc.apple.com".to_string(),
             | ProviderId::Facebook => "facebook.com".to_string(),
@@ -71,14 +86,36 @@ impl ProviderId {
 
     /// Tries to parse a string to a identity provider ID.
     ///
+    /// The string is trimmed and matched case-insensitively, e.g. `" Google.com "` is parsed as [`ProviderId::Google`],
+    /// to be robust against inconsistencies of the API and inputs of users.
+    ///
+    /// ## NOTE
+    /// Unknown IDs are parsed as [`ProviderId::Custom`] with the trimmed string keeping its case,
+    /// because custom provider IDs may be case-sensitive.
+    ///
     /// ## Arguments
     /// - `string` - String to parse.
     ///
     /// ## Returns
     /// Identity provider ID if the string is a valid ID.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::ProviderId;
+    ///
+    /// assert_eq!(ProviderId::parse(" Google.com ".to_string()), ProviderId::Google);
+    /// assert_eq!(ProviderId::parse("oidc.Custom".to_string()), ProviderId::Custom("oidc.Custom".to_string()));
+    /// ```
     pub fn parse(string: String) -> Self {
-        match string.as_str() {
+        let trimmed = string.trim();
+
+        match trimmed
+            .to_ascii_lowercase()
+            .as_str()
+        {
             | "password" => ProviderId::Password,
+            | "phone" => ProviderId::Phone,
+            | "anonymous" => ProviderId::Anonymous,
             | "apple.com" => ProviderId::Apple,
             | "gc.apple.com" => ProviderId::AppleGameCenter,
             | "facebook.com" => ProviderId::Facebook,
@@ -88,7 +125,77 @@ impl ProviderId {
             | "microsoft.com" => ProviderId::Microsoft,
             | "twitter.com" => ProviderId::Twitter,
             | "yahoo.com" => ProviderId::Yahoo,
-            | _ => ProviderId::Custom(string),
+            | _ => ProviderId::Custom(trimmed.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_format_and_parse() {
+        for provider_id in [
+            ProviderId::Password,
+            ProviderId::Phone,
+            ProviderId::Anonymous,
+            ProviderId::Apple,
+            ProviderId::AppleGameCenter,
+            ProviderId::Facebook,
+            ProviderId::GitHub,
+            ProviderId::Google,
+            ProviderId::GooglePlayGames,
+            ProviderId::Microsoft,
+            ProviderId::Twitter,
+            ProviderId::Yahoo,
+            ProviderId::Custom("oidc.example".to_string()),
+        ] {
+            assert_eq!(
+                ProviderId::parse(provider_id.format()),
+                provider_id
+            );
+            assert_eq!(
+                provider_id.to_string(),
+                provider_id.format()
+            );
         }
     }
+
+    #[test]
+    fn formats_non_oauth_providers() {
+        assert_eq!(
+            ProviderId::Password.format(),
+            "password"
+        );
+        assert_eq!(ProviderId::Phone.format(), "phone");
+        assert_eq!(
+            ProviderId::Anonymous.format(),
+            "anonymous"
+        );
+    }
+
+    #[test]
+    fn parses_case_insensitively() {
+        assert_eq!(
+            ProviderId::parse("Google.com".to_string()),
+            ProviderId::Google
+        );
+    }
+
+    #[test]
+    fn parses_with_surrounding_whitespace() {
+        assert_eq!(
+            ProviderId::parse(" google.com ".to_string()),
+            ProviderId::Google
+        );
+    }
+
+    #[test]
+    fn parses_unknown_as_custom() {
+        assert_eq!(
+            ProviderId::parse(" oidc.Example ".to_string()),
+            ProviderId::Custom("oidc.Example".to_string())
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use serde::Deserialize;
 
+use crate::ProviderId;
+
 /// User information provided from an identity provider.
 #[derive(Deserialize, PartialEq, Clone, Debug)]
 pub struct ProviderUserInfo {
@@ -25,3 +27,23 @@ pub struct ProviderUserInfo {
     #[serde(rename = "screenName")]
     pub screen_name: Option<String>,
 }
+
+impl ProviderUserInfo {
+    /// Returns the provider identifier as a typed [`ProviderId`].
+    ///
+    /// Unknown providers are parsed as [`ProviderId::Custom`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::ProviderId;
+    ///
+    /// for info in user_data.provider_user_info.unwrap_or_default() {
+    ///     if info.typed_provider_id() == ProviderId::Google {
+    ///         // Show the Google account as connected.
+    ///     }
+    /// }
+    /// ```
+    pub fn typed_provider_id(&self) -> ProviderId {
+        ProviderId::parse(self.provider_id.clone())
+    }
+}
@@ -1,4 +1,11 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
+use crate::data::secret::mask_secret;
+
 /// Refresh token of the Firebase Auth.
+///
+/// Equality and hash are over the raw token, so it can be used as a key of an in-process cache.
 #[derive(Clone, Debug, Hash, Eq, PartialEq)]
 pub struct RefreshToken {
     inner: String,
@@ -15,8 +22,55 @@ impl RefreshToken {
         }
     }
 
+    /// Returns the raw refresh token as a secret.
+    ///
+    /// ## NOTE
+    /// The raw refresh token is a credential of the user, so be careful not to leak it in logs.
+    /// Use [`RefreshToken::masked`] to log it.
+    pub fn expose_secret(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns a masked representation of the refresh token that is safe to write in logs.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::RefreshToken;
+    ///
+    /// let token = RefreshToken::new("abcdefghijklmnopqrstuvwxyz");
+    ///
+    /// assert_eq!(token.masked(), "abcd...wxyz");
+    /// ```
+    pub fn masked(&self) -> String {
+        mask_secret(&self.inner)
+    }
+
     /// Returns the inner representation.
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use `expose_secret` to handle the raw token deliberately."
+    )]
     pub fn inner(&self) -> &str {
         &self.inner
     }
 }
+
+impl FromStr for RefreshToken {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+impl From<String> for RefreshToken {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for RefreshToken {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
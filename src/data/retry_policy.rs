@@ -0,0 +1,642 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::mem::Discriminant;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::error::CommonErrorCode;
+use crate::Error;
+
+/// The future of the sleep function of a [`RetryPolicy`].
+#[cfg(not(target_arch = "wasm32"))]
+type SleepFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// The future of the sleep function of a [`RetryPolicy`].
+#[cfg(target_arch = "wasm32")]
+type SleepFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+/// The sleep function of a [`RetryPolicy`].
+type SleepFn = Arc<dyn Fn(Duration) -> SleepFuture + Send + Sync>;
+
+/// The jitter to randomize the backoff of a [`RetryPolicy`], e.g. to avoid retrying in lockstep with other clients.
+///
+/// See also [Exponential Backoff And Jitter](https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Jitter {
+    /// Does not randomize the backoff.
+    #[default]
+    None,
+    /// Randomizes the backoff between zero and the backoff.
+    Full,
+    /// Randomizes the backoff between the half of the backoff and the backoff.
+    Equal,
+}
+
+/// Policy to retry the requests to the Firebase Auth API with the exponential backoff.
+///
+/// The requests rejected by the rate limiting (`TOO_MANY_ATTEMPTS_TRY_LATER`) are retried by default,
+/// and other error codes can be added by [`RetryPolicy::with_error_code`].
+/// Other errors, e.g. a wrong password, are returned immediately without any retry.
+///
+/// The transient failures out of the Firebase Auth API are also retried with the backoff before the first retry,
+/// i.e. fetching the public keys by [`crate::verification::GoogleKeySource`] on the client
+/// and polling the token endpoint by [`crate::oauth::DeviceCodeClient::with_retry_policy`]
+/// and [`crate::oauth::FacebookDeviceCodeClient::with_retry_policy`].
+///
+/// See also [`crate::Client::with_retry_policy`] and [`crate::Config::with_retry_policy`].
+///
+/// ## Example
+/// ```
+/// use std::time::Duration;
+/// use fars::error::CommonErrorCode;
+/// use fars::Jitter;
+/// use fars::RetryPolicy;
+///
+/// let retry_policy = RetryPolicy::new(tokio::time::sleep)
+///     .with_max_retries(5)
+///     .with_base_delay(Duration::from_millis(500))
+///     .with_jitter(Jitter::Full)
+///     .with_error_code(
+///         CommonErrorCode::TooManyAttemptsTryLater,
+///         Duration::from_secs(5),
+///     );
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    /// The maximum number of retries.
+    max_retries: usize,
+    /// The backoff before the first retry.
+    base_delay: Duration,
+    /// The maximum backoff between retries.
+    max_delay: Duration,
+    /// The multiplier of the backoff for each subsequent retry.
+    multiplier: f64,
+    /// The jitter to randomize the backoff.
+    jitter: Jitter,
+    /// The retryable error codes with the backoff before the first retry.
+    error_codes: Vec<(Discriminant<CommonErrorCode>, Duration)>,
+    /// The function to sleep for the backoff.
+    sleep_fn: SleepFn,
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("base_delay", &self.base_delay)
+            .field("max_delay", &self.max_delay)
+            .field("multiplier", &self.multiplier)
+            .field("jitter", &self.jitter)
+            .field("error_codes", &self.error_codes)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Creates a new retry policy with the default parameters and the sleep function of tokio.
+///
+/// ## NOTE
+/// This is only available when the feature "tokio" is enabled on native targets.
+#[cfg(all(
+    feature = "tokio",
+    not(target_arch = "wasm32")
+))]
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(crate::sleep::tokio_sleep)
+    }
+}
+
+impl RetryPolicy {
+    /// The default maximum number of retries: 3.
+    pub const DEFAULT_MAX_RETRIES: usize = 3;
+
+    /// The default backoff before the first retry: 1 second.
+    pub const DEFAULT_BASE_DELAY: Duration = Duration::from_secs(1);
+
+    /// The default maximum backoff between retries: 30 seconds.
+    pub const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(30);
+
+    /// The default multiplier of the backoff: 2.
+    pub const DEFAULT_MULTIPLIER: f64 = 2.0;
+
+    /// Creates a new retry policy with the default parameters.
+    ///
+    /// ## Arguments
+    /// - `sleep_fn` - The function to sleep for the backoff of your async runtime, e.g. `tokio::time::sleep`. See also [`crate::sleep`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn new<S, SF>(sleep_fn: S) -> Self
+    where
+        S: Fn(Duration) -> SF + Send + Sync + 'static,
+        SF: Future<Output = ()> + Send + 'static,
+    {
+        Self::with_sleep_fn(Arc::new(move |duration| {
+            Box::pin(sleep_fn(duration))
+        }))
+    }
+
+    /// Creates a new retry policy with the default parameters.
+    ///
+    /// ## Arguments
+    /// - `sleep_fn` - The function to sleep for the backoff of your async runtime. See also [`crate::sleep`].
+    #[cfg(target_arch = "wasm32")]
+    pub fn new<S, SF>(sleep_fn: S) -> Self
+    where
+        S: Fn(Duration) -> SF + Send + Sync + 'static,
+        SF: Future<Output = ()> + 'static,
+    {
+        Self::with_sleep_fn(Arc::new(move |duration| {
+            Box::pin(sleep_fn(duration))
+        }))
+    }
+
+    fn with_sleep_fn(sleep_fn: SleepFn) -> Self {
+        Self {
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+            base_delay: Self::DEFAULT_BASE_DELAY,
+            max_delay: Self::DEFAULT_MAX_DELAY,
+            multiplier: Self::DEFAULT_MULTIPLIER,
+            jitter: Jitter::None,
+            error_codes: Vec::new(),
+            sleep_fn,
+        }
+    }
+
+    /// Sets the maximum number of retries.
+    ///
+    /// ## Arguments
+    /// - `max_retries` - The maximum number of retries.
+    pub fn with_max_retries(
+        mut self,
+        max_retries: usize,
+    ) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the backoff before the first retry.
+    ///
+    /// ## Arguments
+    /// - `base_delay` - The backoff before the first retry.
+    pub fn with_base_delay(
+        mut self,
+        base_delay: Duration,
+    ) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the maximum backoff between retries.
+    ///
+    /// ## Arguments
+    /// - `max_delay` - The maximum backoff between retries.
+    pub fn with_max_delay(
+        mut self,
+        max_delay: Duration,
+    ) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Sets the multiplier of the backoff for each subsequent retry.
+    ///
+    /// ## Arguments
+    /// - `multiplier` - The multiplier of the backoff.
+    pub fn with_multiplier(
+        mut self,
+        multiplier: f64,
+    ) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets the jitter to randomize the backoff.
+    ///
+    /// ## Arguments
+    /// - `jitter` - The jitter to randomize the backoff.
+    pub fn with_jitter(
+        mut self,
+        jitter: Jitter,
+    ) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Retries the requests failed with the error code, overriding the backoff before the first retry.
+    ///
+    /// The error code is compared by the variant, e.g. any [`CommonErrorCode::Unknown`] matches `CommonErrorCode::Unknown(String::new())`.
+    ///
+    /// ## NOTE
+    /// Only the error codes returned as `Error::ApiError` are retried.
+    /// [`CommonErrorCode::InvalidIdToken`] and [`CommonErrorCode::UserDisabled`] can NOT be retried by this
+    /// because they are promoted to `Error::InvalidIdToken`, which is retried after refreshing the tokens by [`crate::Session`],
+    /// and `Error::AccountDisabled`.
+    ///
+    /// ## Arguments
+    /// - `error_code` - The error code to retry.
+    /// - `base_delay` - The backoff before the first retry for the error code.
+    pub fn with_error_code(
+        mut self,
+        error_code: CommonErrorCode,
+        base_delay: Duration,
+    ) -> Self {
+        let discriminant = std::mem::discriminant(&error_code);
+        self.error_codes
+            .retain(|(code, _)| *code != discriminant);
+        self.error_codes
+            .push((discriminant, base_delay));
+        self
+    }
+
+    /// Computes the backoff before the retry, which is deterministic for the given random value.
+    ///
+    /// ## Arguments
+    /// - `retry` - The zero-based index of the retry.
+    /// - `error` - The error of the last attempt.
+    /// - `random` - A random value in `[0, 1)` for the jitter.
+    ///
+    /// ## Returns
+    /// The backoff before the retry, or `None` if the error should not be retried or the retries are exhausted.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::time::Duration;
+    /// use fars::RetryPolicy;
+    ///
+    /// let retry_policy = RetryPolicy::new(tokio::time::sleep);
+    ///
+    /// match session.get_user_data().await {
+    ///     | Ok(_) => {},
+    ///     | Err(error) => {
+    ///         // 2 seconds before the second retry for `TOO_MANY_ATTEMPTS_TRY_LATER`.
+    ///         let delay = retry_policy.delay(1, &error, 0.5);
+    ///     },
+    /// }
+    /// ```
+    pub fn delay(
+        &self,
+        retry: usize,
+        error: &Error,
+        random: f64,
+    ) -> Option<Duration> {
+        self.backoff(
+            retry,
+            self.base_delay_of(error)?,
+            random,
+        )
+    }
+
+    /// Sleeps for the backoff before the retry if the error should be retried.
+    ///
+    /// ## Returns
+    /// Whether to retry the request.
+    pub(crate) async fn wait(
+        &self,
+        retry: usize,
+        error: &Error,
+    ) -> bool {
+        match self.delay(retry, error, random()) {
+            | Some(delay) => {
+                (self.sleep_fn)(delay).await;
+                true
+            },
+            | None => false,
+        }
+    }
+
+    /// Sleeps for the backoff before the retry of a transient failure out of the Firebase Auth API,
+    /// e.g. a timeout to fetch the public keys or a transport error while polling the OAuth token endpoint.
+    ///
+    /// ## Returns
+    /// Whether to retry the request.
+    #[cfg(any(
+        feature = "verify",
+        all(
+            feature = "oauth",
+            not(target_arch = "wasm32")
+        )
+    ))]
+    pub(crate) async fn wait_transient(
+        &self,
+        retry: usize,
+    ) -> bool {
+        match self.backoff(retry, self.base_delay, random()) {
+            | Some(delay) => {
+                (self.sleep_fn)(delay).await;
+                true
+            },
+            | None => false,
+        }
+    }
+
+    /// Computes the backoff before the retry from the backoff before the first retry.
+    ///
+    /// ## Returns
+    /// The backoff before the retry, or `None` if the retries are exhausted.
+    fn backoff(
+        &self,
+        retry: usize,
+        base_delay: Duration,
+        random: f64,
+    ) -> Option<Duration> {
+        if retry >= self.max_retries {
+            return None;
+        }
+
+        // Exponential backoff capped by the maximum.
+        let backoff = base_delay.as_secs_f64()
+            * self
+                .multiplier
+                .powi(retry as i32);
+        let backoff = backoff.min(self.max_delay.as_secs_f64());
+
+        // Randomize the backoff.
+        let random = random.clamp(0.0, 1.0);
+        let backoff = match self.jitter {
+            | Jitter::None => backoff,
+            | Jitter::Full => backoff * random,
+            | Jitter::Equal => backoff / 2.0 + backoff / 2.0 * random,
+        };
+
+        Some(Duration::from_secs_f64(
+            backoff.max(0.0),
+        ))
+    }
+
+    /// Returns the backoff before the first retry for the error if it is retryable.
+    fn base_delay_of(
+        &self,
+        error: &Error,
+    ) -> Option<Duration> {
+        let Error::ApiError {
+            error_code,
+            ..
+        } = error
+        else {
+            return None;
+        };
+
+        let discriminant = std::mem::discriminant(error_code);
+        self.error_codes
+            .iter()
+            .find(|(code, _)| *code == discriminant)
+            .map(|(_, base_delay)| *base_delay)
+            .or(match error_code {
+                | CommonErrorCode::TooManyAttemptsTryLater => {
+                    Some(self.base_delay)
+                },
+                | _ => None,
+            })
+    }
+}
+
+/// Generates a random value in `[0, 1)` without any extra dependency.
+fn random() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(0);
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Creates a retry policy that records the backoffs instead of sleeping.
+    fn recording_policy() -> (RetryPolicy, Arc<Mutex<Vec<Duration>>>) {
+        let sleeps = Arc::new(Mutex::new(Vec::new()));
+        let recorder = sleeps.clone();
+        let retry_policy = RetryPolicy::new(move |duration| {
+            recorder
+                .lock()
+                .unwrap()
+                .push(duration);
+            std::future::ready(())
+        });
+
+        (retry_policy, sleeps)
+    }
+
+    /// Creates an API error with the error message.
+    fn api_error(message: &str) -> Error {
+        let response = serde_json::from_value(serde_json::json!({
+            "error": {
+                "code": 400,
+                "message": message,
+                "errors": [],
+            },
+        }))
+        .unwrap();
+
+        Error::from_api_error_response(
+            "accounts:lookup",
+            reqwest::StatusCode::BAD_REQUEST,
+            response,
+        )
+    }
+
+    #[test]
+    fn delay_backs_off_exponentially_up_to_max_delay() {
+        let (retry_policy, _) = recording_policy();
+        let retry_policy = retry_policy
+            .with_max_retries(5)
+            .with_max_delay(Duration::from_secs(5));
+        let error = api_error("TOO_MANY_ATTEMPTS_TRY_LATER");
+
+        let delays = (0..6)
+            .map(|retry| retry_policy.delay(retry, &error, 0.5))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            delays,
+            vec![
+                Some(Duration::from_secs(1)),
+                Some(Duration::from_secs(2)),
+                Some(Duration::from_secs(4)),
+                Some(Duration::from_secs(5)),
+                Some(Duration::from_secs(5)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn delay_applies_multiplier() {
+        let (retry_policy, _) = recording_policy();
+        let retry_policy = retry_policy.with_multiplier(3.0);
+        let error = api_error("TOO_MANY_ATTEMPTS_TRY_LATER");
+
+        assert_eq!(
+            retry_policy.delay(2, &error, 0.5),
+            Some(Duration::from_secs(9))
+        );
+    }
+
+    #[test]
+    fn delay_applies_jitter() {
+        let (retry_policy, _) = recording_policy();
+        let error = api_error("TOO_MANY_ATTEMPTS_TRY_LATER");
+
+        let full = retry_policy
+            .clone()
+            .with_jitter(Jitter::Full);
+        assert_eq!(
+            full.delay(1, &error, 0.0),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(
+            full.delay(1, &error, 0.25),
+            Some(Duration::from_millis(500))
+        );
+
+        let equal = retry_policy.with_jitter(Jitter::Equal);
+        assert_eq!(
+            equal.delay(1, &error, 0.0),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(
+            equal.delay(1, &error, 0.5),
+            Some(Duration::from_millis(1500))
+        );
+    }
+
+    #[test]
+    fn delay_overrides_base_delay_per_error_code() {
+        let (retry_policy, _) = recording_policy();
+        let retry_policy = retry_policy
+            .with_error_code(
+                CommonErrorCode::TooManyAttemptsTryLater,
+                Duration::from_secs(5),
+            )
+            .with_error_code(
+                CommonErrorCode::Unknown(String::new()),
+                Duration::from_millis(100),
+            );
+
+        assert_eq!(
+            retry_policy.delay(
+                0,
+                &api_error("TOO_MANY_ATTEMPTS_TRY_LATER"),
+                0.5
+            ),
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(
+            retry_policy.delay(1, &api_error("QUOTA_EXCEEDED"), 0.5),
+            Some(Duration::from_millis(200))
+        );
+    }
+
+    #[test]
+    fn delay_does_not_retry_promoted_errors() {
+        let (retry_policy, _) = recording_policy();
+        let retry_policy = retry_policy
+            .with_error_code(
+                CommonErrorCode::InvalidIdToken,
+                Duration::from_millis(100),
+            )
+            .with_error_code(
+                CommonErrorCode::UserDisabled,
+                Duration::from_millis(100),
+            );
+
+        assert_eq!(
+            retry_policy.delay(0, &api_error("INVALID_ID_TOKEN"), 0.5),
+            None
+        );
+        assert_eq!(
+            retry_policy.delay(0, &api_error("USER_DISABLED"), 0.5),
+            None
+        );
+    }
+
+    #[test]
+    fn delay_does_not_retry_other_errors() {
+        let (retry_policy, _) = recording_policy();
+
+        assert_eq!(
+            retry_policy.delay(0, &api_error("INVALID_PASSWORD"), 0.5),
+            None
+        );
+        assert_eq!(
+            retry_policy.delay(
+                0,
+                &api_error("INVALID_PASSWORD").map_invalid_credentials(),
+                0.5
+            ),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn wait_sleeps_for_backoff_until_retries_are_exhausted() {
+        let (retry_policy, sleeps) = recording_policy();
+        let error = api_error("TOO_MANY_ATTEMPTS_TRY_LATER");
+
+        let mut retries = Vec::new();
+        for retry in 0..4 {
+            retries.push(
+                retry_policy
+                    .wait(retry, &error)
+                    .await,
+            );
+        }
+
+        assert_eq!(retries, vec![true, true, true, false]);
+        assert_eq!(
+            *sleeps.lock().unwrap(),
+            vec![
+                Duration::from_secs(1),
+                Duration::from_secs(2),
+                Duration::from_secs(4),
+            ]
+        );
+    }
+
+    #[cfg(any(
+        feature = "verify",
+        all(
+            feature = "oauth",
+            not(target_arch = "wasm32")
+        )
+    ))]
+    #[tokio::test]
+    async fn wait_transient_uses_base_delay() {
+        let (retry_policy, sleeps) = recording_policy();
+        let retry_policy = retry_policy
+            .with_max_retries(2)
+            .with_base_delay(Duration::from_millis(100))
+            .with_error_code(
+                CommonErrorCode::TooManyAttemptsTryLater,
+                Duration::from_secs(5),
+            );
+
+        assert!(
+            retry_policy
+                .wait_transient(0)
+                .await
+        );
+        assert!(
+            retry_policy
+                .wait_transient(1)
+                .await
+        );
+        assert!(
+            !retry_policy
+                .wait_transient(2)
+                .await
+        );
+        assert_eq!(
+            *sleeps.lock().unwrap(),
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+            ]
+        );
+    }
+}
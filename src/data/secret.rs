@@ -0,0 +1,26 @@
+//! Helpers for secret values.
+
+/// The number of visible characters at each end of a masked secret.
+const VISIBLE_LENGTH: usize = 4;
+
+/// Masks a secret value to be safe to write in logs.
+///
+/// Shows only first and last 4 characters when the secret is long enough,
+/// otherwise hides all characters.
+pub(crate) fn mask_secret(secret: &str) -> String {
+    let length = secret.chars().count();
+    if length <= VISIBLE_LENGTH * 4 {
+        return "****".to_string();
+    }
+
+    let prefix: String = secret
+        .chars()
+        .take(VISIBLE_LENGTH)
+        .collect();
+    let suffix: String = secret
+        .chars()
+        .skip(length - VISIBLE_LENGTH)
+        .collect();
+
+    format!("{}...{}", prefix, suffix)
+}
@@ -0,0 +1,19 @@
+use crate::OobCode;
+
+/// The out-of-band (OOB) code and link returned by sending an email with an action code,
+/// e.g. email verification and password reset.
+///
+/// See also [`crate::Config::send_reset_password_email_returning_oob_code`]
+/// and [`crate::Session::send_email_verification_returning_oob_code`].
+///
+/// ## NOTE
+/// The Firebase Auth only returns them against the [Firebase Local Emulator Suite](https://firebase.google.com/docs/emulator-suite)
+/// to click the link without a real inbox in integration tests.
+/// Production responses do not include them, so both fields are `None`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SentOobCode {
+    /// The OOB code sent by the email.
+    pub oob_code: Option<OobCode>,
+    /// The action link sent by the email.
+    pub oob_link: Option<String>,
+}
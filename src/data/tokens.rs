@@ -0,0 +1,17 @@
+use crate::ExpiresIn;
+use crate::IdToken;
+use crate::RefreshToken;
+
+/// A consistent snapshot of the tokens of a session.
+///
+/// Because APIs through a session consume the session and return a new one that may have refreshed tokens,
+/// take a snapshot from the returned session by [`crate::Session::tokens`] instead of holding the tokens of the consumed session.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Tokens {
+    /// Firebase Auth ID token.
+    pub id_token: IdToken,
+    /// The number of seconds in which the ID token expires.
+    pub expires_in: ExpiresIn,
+    /// Firebase Auth refresh token.
+    pub refresh_token: RefreshToken,
+}
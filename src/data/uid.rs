@@ -0,0 +1,77 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// The UID (local ID) of an user of the Firebase Auth.
+///
+/// ## Example
+/// ```
+/// use fars::Uid;
+///
+/// let uid = Uid::new("user-uid");
+///
+/// assert_eq!(uid.as_str(), "user-uid");
+///
+/// let raw: String = uid.into();
+/// ```
+#[derive(
+    Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Deserialize, Serialize,
+)]
+#[serde(transparent)]
+pub struct Uid {
+    inner: String,
+}
+
+impl Uid {
+    /// Creates a new UID.
+    pub fn new<S>(inner: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            inner: inner.into(),
+        }
+    }
+
+    /// Returns the UID as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.inner
+    }
+}
+
+impl Display for Uid {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl From<String> for Uid {
+    fn from(inner: String) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<&str> for Uid {
+    fn from(inner: &str) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl From<Uid> for String {
+    fn from(uid: Uid) -> Self {
+        uid.inner
+    }
+}
+
+impl FromStr for Uid {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
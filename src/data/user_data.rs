@@ -1,13 +1,41 @@
-use serde::Deserialize;
+use std::fmt::Display;
+
+use serde::{Deserialize, Deserializer};
 
 use crate::ProviderUserInfo;
+use crate::Uid;
 
 /// User data of the Firebase Auth.
+///
+/// [`Display`] prints a one-line summary of key fields without secrets, e.g. for logging,
+/// and [`Debug`] prints the full structure.
+///
+/// ## Example
+/// ```
+/// use fars::Config;
+/// use fars::ApiKey;
+/// use fars::Email;
+/// use fars::Password;
+///
+/// let config = Config::new(
+///     ApiKey::new("your-firebase-project-api-key"),
+/// );
+///
+/// let session = config.sign_in_with_email_password(
+///     Email::new("user@example"),
+///     Password::new("password"),
+/// ).await?;
+///
+/// let (new_session, user_data) = session.get_user_data().await?;
+///
+/// // e.g. "uid: abc123, email: user@example, email_verified: true, providers: 1"
+/// println!("{}", user_data);
+/// ```
 #[derive(Deserialize, PartialEq, Clone, Debug)]
 pub struct UserData {
     /// The uid of the current user.
     #[serde(rename = "localId")]
-    pub local_id: String,
+    pub local_id: Uid,
     /// The email of the account.
     #[serde(rename = "email")]
     pub email: Option<String>,
@@ -47,4 +75,71 @@ pub struct UserData {
     /// Whether the account is authenticated by the developer.
     #[serde(rename = "customAuth")]
     pub custom_auth: Option<bool>,
+    /// The tenant ID of the account in a multi-tenant project of the Identity Platform.
+    #[serde(rename = "tenantId")]
+    pub tenant_id: Option<String>,
+    /// The custom attributes of the account set by the Admin SDK, e.g. custom claims for RBAC,
+    /// parsed from the JSON string of the response.
+    #[serde(
+        rename = "customAttributes",
+        default,
+        deserialize_with = "deserialize_custom_attributes"
+    )]
+    pub custom_attributes: Option<serde_json::Value>,
+}
+
+impl UserData {
+    /// Returns whether the account is an anonymous user, i.e. without any linked identity provider and email.
+    ///
+    /// ## NOTE
+    /// This also returns `true` for a user signed in with a custom token
+    /// who has no linked identity provider and no email, because it is indistinguishable from an anonymous user in the user data.
+    pub fn is_anonymous(&self) -> bool {
+        let no_providers = self
+            .provider_user_info
+            .as_ref()
+            .map_or(true, Vec::is_empty);
+        let no_email = self
+            .email
+            .as_deref()
+            .map_or(true, str::is_empty);
+
+        no_providers && no_email
+    }
+}
+
+impl Display for UserData {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(
+            f,
+            "uid: {}, email: {}, email_verified: {}, providers: {}",
+            self.local_id,
+            self.email
+                .as_deref()
+                .unwrap_or("none"),
+            self.email_verified
+                .unwrap_or(false),
+            self.provider_user_info
+                .as_ref()
+                .map_or(0, Vec::len),
+        )
+    }
+}
+
+/// Deserializes the custom attributes from the JSON string, e.g. `"{\"role\":\"admin\"}"`.
+fn deserialize_custom_attributes<'de, D>(
+    deserializer: D
+) -> std::result::Result<Option<serde_json::Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        | Some(custom_attributes) => serde_json::from_str(&custom_attributes)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+        | None => Ok(None),
+    }
 }
@@ -0,0 +1,164 @@
+//! A session wrapper that deletes the account of an ephemeral user on drop.
+//!
+//! See [`crate::DeleteOnDrop`].
+
+use crate::Result;
+use crate::Session;
+
+/// A wrapper of [`Session`] for an ephemeral user that deletes the account on drop,
+/// e.g. in short-lived tools and test harnesses.
+///
+/// See also [`Session::delete_on_drop`].
+///
+/// ## WARNING
+/// This **deletes the account** of the user on drop,
+/// which also invalidates the refresh token because the Firebase Auth REST API
+/// has no endpoint to revoke a refresh token with an API key.
+///
+/// To avoid deleting a permanent account by mistake,
+/// only a session of an anonymous user is deleted on drop by default.
+/// Call [`DeleteOnDrop::allow_non_anonymous`] to opt in to deleting a non-anonymous user,
+/// e.g. a user signed up for tests.
+///
+/// ## NOTE
+/// This is only available when the feature "tokio" is enabled on native (non-`wasm32`) targets.
+///
+/// Dropping cannot await, so deleting the account on drop is best-effort:
+/// it spawns an asynchronous task on the current [tokio](https://github.com/tokio-rs/tokio) runtime,
+/// so the account may remain when the wrapper is dropped outside of a runtime
+/// or the runtime is shut down before the task completes.
+/// Call [`DeleteOnDrop::delete`] to delete the account deterministically.
+///
+/// ## Example
+/// ```
+/// use fars::Config;
+/// use fars::ApiKey;
+///
+/// let config = Config::new(
+///     ApiKey::new("your-firebase-project-api-key"),
+/// );
+///
+/// let ephemeral = config
+///     .sign_in_anonymously()
+///     .await?
+///     .delete_on_drop();
+///
+/// let (session, user_data) = ephemeral
+///     .session()
+///     .clone()
+///     .get_user_data()
+///     .await?;
+///
+/// // The account is deleted when `ephemeral` is dropped.
+/// ```
+#[derive(Debug)]
+pub struct DeleteOnDrop {
+    /// The wrapped session, taken on deletion or unwrapping.
+    session: Option<Session>,
+    /// Whether to delete the account of a non-anonymous user on drop.
+    allow_non_anonymous: bool,
+}
+
+impl DeleteOnDrop {
+    /// Creates a new wrapper of the session to delete on drop.
+    ///
+    /// ## Arguments
+    /// - `session` - The session of an ephemeral user.
+    ///
+    /// ## NOTE
+    /// The account of a non-anonymous user is not deleted on drop
+    /// unless [`DeleteOnDrop::allow_non_anonymous`] is called.
+    pub fn new(session: Session) -> Self {
+        Self {
+            session: Some(session),
+            allow_non_anonymous: false,
+        }
+    }
+
+    /// Opts in to deleting the account of a non-anonymous user on drop,
+    /// e.g. a user signed up with an email and a password for tests.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let ephemeral = config
+    ///     .sign_up_with_email_password(
+    ///         Email::new("user@example.com"),
+    ///         Password::new("password"),
+    ///     )
+    ///     .await?
+    ///     .delete_on_drop()
+    ///     .allow_non_anonymous();
+    /// ```
+    pub fn allow_non_anonymous(mut self) -> Self {
+        self.allow_non_anonymous = true;
+        self
+    }
+
+    /// Returns the wrapped session.
+    pub fn session(&self) -> &Session {
+        // NOTE: The session is only taken on the methods that consume `self`.
+        self.session.as_ref().expect(
+            "session of a delete on drop wrapper has been already taken",
+        )
+    }
+
+    /// Replaces the wrapped session, e.g. by the new session returned from the APIs.
+    ///
+    /// ## Arguments
+    /// - `session` - The new session of the same user.
+    pub fn replace_session(
+        &mut self,
+        session: Session,
+    ) {
+        self.session = Some(session);
+    }
+
+    /// Unwraps the session without deleting the account.
+    pub fn into_session(mut self) -> Session {
+        self.session.take().expect(
+            "session of a delete on drop wrapper has been already taken",
+        )
+    }
+
+    /// Deletes the account immediately instead of on drop.
+    ///
+    /// This deletes the account of a non-anonymous user as well,
+    /// because it is called explicitly.
+    ///
+    /// See also [`Session::delete_account`].
+    pub async fn delete(mut self) -> Result<()> {
+        match self.session.take() {
+            | Some(session) => session.delete_account().await,
+            | None => Ok(()),
+        }
+    }
+}
+
+impl Drop for DeleteOnDrop {
+    fn drop(&mut self) {
+        let Some(session) = self.session.take() else {
+            return;
+        };
+
+        // NOTE: Keep the account of a non-anonymous user unless opted in.
+        if !session.anonymous && !self.allow_non_anonymous {
+            return;
+        }
+
+        // NOTE: Ignore when dropped outside of a tokio runtime.
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move {
+                let _ = session.delete_account().await;
+            });
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Preconfigured clients for the [Firebase Local Emulator Suite](https://firebase.google.com/docs/emulator-suite).
+//!
+//! ## NOTE
+//! This feature is only available when the feature `emulator` is enabled.
+//!
+//! [`crate::emulator::EmulatorConfig`] bundles the configurations to work against the Firebase Auth Emulator:
+//!
+//! - A [`crate::Config`] that sends the requests to the emulator by [`crate::Client::with_emulator_host`].
+//! - A [`crate::verification::VerificationConfig`] that accepts the unsigned ID tokens issued by the emulator (optional feature: `verify`).
+//!
+//! The OOB codes are also available on the emulator,
+//! e.g. by [`crate::Config::send_reset_password_email_returning_oob_code`]
+//! and [`crate::Session::send_email_verification_returning_oob_code`].
+//!
+//! ## Examples
+//! An example of an integration test against the emulator with [tokio](https://github.com/tokio-rs/tokio) and [anyhow](https://github.com/dtolnay/anyhow) is as follows:
+//!
+//! ```rust
+//! use fars::emulator::EmulatorConfig;
+//! use fars::Email;
+//! use fars::Password;
+//! use fars::ProjectId;
+//!
+//! #[tokio::test]
+//! async fn sign_up() -> anyhow::Result<()> {
+//!     // Create an emulator config.
+//!     let emulator = EmulatorConfig::new(
+//!         ProjectId::new("demo-project"),
+//!         "localhost:9099",
+//!     );
+//!
+//!     // Sign up a user on the emulator.
+//!     let session = emulator
+//!         .config()
+//!         .sign_up_with_email_password(
+//!             Email::new("user@example.com"),
+//!             Password::new("password"),
+//!         )
+//!         .await?;
+//!
+//!     Ok(())
+//! }
+//! ```
+
+use crate::ApiKey;
+use crate::Client;
+use crate::Config;
+use crate::ProjectId;
+
+/// Configuration for the Firebase Auth Emulator of the [Firebase Local Emulator Suite](https://firebase.google.com/docs/emulator-suite).
+///
+/// ## NOTE
+/// This is only available when the feature "emulator" is enabled.
+///
+/// ## WARNING
+/// Never use this for the production because the verification config from this accepts unsigned ID tokens.
+///
+/// ## Example
+/// ```
+/// use fars::emulator::EmulatorConfig;
+/// use fars::ProjectId;
+///
+/// let emulator = EmulatorConfig::new(
+///     ProjectId::new("demo-project"),
+///     "localhost:9099",
+/// );
+///
+/// let config = emulator.config();
+/// ```
+#[derive(Clone, Debug)]
+pub struct EmulatorConfig {
+    /// The project ID of the emulator.
+    project_id: ProjectId,
+    /// The host and port of the emulator.
+    host: String,
+}
+
+impl EmulatorConfig {
+    /// The default host of the Firebase Auth Emulator: `localhost:9099`.
+    pub const DEFAULT_HOST: &'static str = "localhost:9099";
+
+    /// The environment variable of the host of the Firebase Auth Emulator: `FIREBASE_AUTH_EMULATOR_HOST`.
+    pub const HOST_ENV: &'static str = "FIREBASE_AUTH_EMULATOR_HOST";
+
+    /// The API key for the emulator, which accepts any API key.
+    const API_KEY: &'static str = "fake-api-key";
+
+    /// Creates a new configuration for the Firebase Auth Emulator.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The project ID of the emulator, e.g. `demo-project`.
+    /// - `host` - The host and port of the emulator, e.g. `localhost:9099`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::emulator::EmulatorConfig;
+    /// use fars::ProjectId;
+    ///
+    /// let emulator = EmulatorConfig::new(
+    ///     ProjectId::new("demo-project"),
+    ///     "localhost:9099",
+    /// );
+    /// ```
+    pub fn new<S>(
+        project_id: ProjectId,
+        host: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            project_id,
+            host: host.into(),
+        }
+    }
+
+    /// Creates a new configuration for the Firebase Auth Emulator
+    /// with the host of the `FIREBASE_AUTH_EMULATOR_HOST` environment variable,
+    /// or [`EmulatorConfig::DEFAULT_HOST`] if it is not set.
+    ///
+    /// ## Arguments
+    /// - `project_id` - The project ID of the emulator, e.g. `demo-project`.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::emulator::EmulatorConfig;
+    /// use fars::ProjectId;
+    ///
+    /// let emulator = EmulatorConfig::from_env(
+    ///     ProjectId::new("demo-project"),
+    /// );
+    /// ```
+    pub fn from_env(project_id: ProjectId) -> Self {
+        let host = std::env::var(Self::HOST_ENV)
+            .unwrap_or_else(|_| Self::DEFAULT_HOST.to_string());
+
+        Self::new(project_id, host)
+    }
+
+    /// Returns the project ID of the emulator.
+    pub fn project_id(&self) -> &ProjectId {
+        &self.project_id
+    }
+
+    /// Returns the host and port of the emulator.
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Creates a HTTP client that sends the requests to the emulator.
+    pub fn client(&self) -> Client {
+        Client::new().with_emulator_host(self.host.clone())
+    }
+
+    /// Creates a config that sends the requests to the emulator with a fake API key.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::emulator::EmulatorConfig;
+    /// use fars::ProjectId;
+    ///
+    /// let emulator = EmulatorConfig::new(
+    ///     ProjectId::new("demo-project"),
+    ///     "localhost:9099",
+    /// );
+    ///
+    /// let session = emulator
+    ///     .config()
+    ///     .sign_in_anonymously()
+    ///     .await?;
+    /// ```
+    pub fn config(&self) -> Config {
+        Config::new(ApiKey::new(Self::API_KEY)).with_client(self.client())
+    }
+
+    /// Creates a verification config that accepts the unsigned ID tokens issued by the emulator.
+    ///
+    /// This is the only way to accept the unsigned ID tokens,
+    /// so they are rejected by any [`crate::verification::VerificationConfig`] for the production.
+    ///
+    /// The claims, e.g. `aud`, `iss` and `exp`, are verified as the same as the production,
+    /// but the signature is not because the emulator does not sign ID tokens.
+    ///
+    /// ## NOTE
+    /// This is only available when the features "emulator" and "verify" are enabled.
+    ///
+    /// ## WARNING
+    /// Never use this for the production because this accepts unsigned ID tokens.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::emulator::EmulatorConfig;
+    /// use fars::ProjectId;
+    ///
+    /// let emulator = EmulatorConfig::new(
+    ///     ProjectId::new("demo-project"),
+    ///     "localhost:9099",
+    /// );
+    ///
+    /// let session = emulator
+    ///     .config()
+    ///     .sign_in_anonymously()
+    ///     .await?;
+    ///
+    /// let claims = emulator
+    ///     .verification_config()
+    ///     .verify_id_token(&session.id_token)
+    ///     .await?;
+    /// ```
+    #[cfg(feature = "verify")]
+    pub fn verification_config(
+        &self
+    ) -> crate::verification::VerificationConfig {
+        crate::verification::VerificationConfig::new(self.project_id.clone())
+            .insecure_allow_unsigned(self.host.clone())
+    }
+}
@@ -2,9 +2,12 @@
 //!
 //! See also [API reference](https://firebase.google.com/docs/reference/rest/auth).
 
+use std::fmt::Display;
+
 /// The endpoint to send the request to.
 ///
 /// See also [API reference](https://firebase.google.com/docs/reference/rest/auth).
+#[derive(Clone, Copy)]
 pub(crate) enum Endpoint {
     /// accounts:signInWithCustomToken
     SignInWithCustomToken,
@@ -28,9 +31,41 @@ pub(crate) enum Endpoint {
     Lookup,
     /// accounts:delete
     Delete,
+    /// passwordPolicy (v2)
+    PasswordPolicy,
+    /// relyingparty/getProjectConfig (v3)
+    GetProjectConfig,
 }
 
 impl Endpoint {
+    /// Returns the API version of the endpoint.
+    pub(crate) fn version(&self) -> &'static str {
+        match self {
+            | Endpoint::PasswordPolicy => "v2",
+            | Endpoint::GetProjectConfig => "v3",
+            | _ => "v1",
+        }
+    }
+
+    /// Returns the host of the endpoint on the production, including the path prefix of the legacy Identity Toolkit API (v3).
+    pub(crate) fn host(&self) -> &'static str {
+        match self {
+            | Endpoint::GetProjectConfig => {
+                "www.googleapis.com/identitytoolkit"
+            },
+            | _ => "identitytoolkit.googleapis.com",
+        }
+    }
+
+    /// Returns the host of the endpoint on the Firebase Local Emulator Suite,
+    /// which proxies the APIs by the paths of the production hosts.
+    pub(crate) fn emulator_host(&self) -> &'static str {
+        match self {
+            | Endpoint::Token => "securetoken.googleapis.com",
+            | _ => self.host(),
+        }
+    }
+
     /// Formats the endpoint to a string.
     pub(crate) fn format(self) -> &'static str {
         match self {
@@ -47,6 +82,17 @@ impl Endpoint {
             | Endpoint::Update => "accounts:update",
             | Endpoint::Lookup => "accounts:lookup",
             | Endpoint::Delete => "accounts:delete",
+            | Endpoint::PasswordPolicy => "passwordPolicy",
+            | Endpoint::GetProjectConfig => "relyingparty/getProjectConfig",
         }
     }
 }
+
+impl Display for Endpoint {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.format())
+    }
+}
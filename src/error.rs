@@ -3,8 +3,18 @@
 use serde::Deserialize;
 use std::fmt::{Display, Formatter};
 
+use crate::MfaEnrollment;
+use crate::PendingOAuthLink;
+use crate::ProjectId;
+use crate::ProviderId;
+
 /// The error type for APIs.
+///
+/// ## NOTE
+/// This enum is marked as `#[non_exhaustive]` to add new variants without breaking changes,
+/// so please include a wildcard arm (`_ => ...`) when matching on it.
 #[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
     // HTTP client errors
     /// Invalid header value.
@@ -16,20 +26,113 @@ pub enum Error {
     /// HTTP request error.
     #[error("HTTP request error: {0:?}")]
     HttpRequestError(reqwest::Error),
+    /// Build HTTP client failed.
+    ///
+    /// See also [`crate::ClientBuilder::build`].
+    #[error("Build HTTP client failed: {0:?}")]
+    BuildClientFailed(reqwest::Error),
 
     // API errors
     /// API error on the Firebase Auth.
     #[error(
-        "Firebase Auth API error: ({status_code:?}) {error_code:?} - {response:?}"
+        "Firebase Auth API error on {endpoint}: ({status_code:?}) {error_code:?} - {response:?}"
     )]
     ApiError {
+        /// The path of the endpoint, e.g. `accounts:signInWithPassword`.
+        endpoint: &'static str,
         status_code: reqwest::StatusCode,
         error_code: CommonErrorCode,
         response: ApiErrorResponse,
     },
     /// Invalid ID token error.
     #[error("Invalid ID token")]
-    InvalidIdToken,
+    InvalidIdToken {
+        /// The original error response, e.g. to read the raw message by [`Error::api_message`].
+        response: ApiErrorResponse,
+    },
+    /// The user account has been disabled by an administrator.
+    #[error("Account disabled")]
+    AccountDisabled {
+        /// The original error response, e.g. to read the raw message by [`Error::api_message`].
+        response: ApiErrorResponse,
+    },
+    /// The email or the password is wrong on signing in with email and password.
+    ///
+    /// The Firebase Auth returns the generic `INVALID_LOGIN_CREDENTIALS` instead of `EMAIL_NOT_FOUND` and `INVALID_PASSWORD`
+    /// when the email enumeration protection is enabled, which is the default for new projects,
+    /// not to reveal whether the email has been registered.
+    /// All of them are mapped to this error to handle "wrong email or password" in one place.
+    ///
+    /// See also [`crate::Config::sign_in_with_email_password`].
+    #[error("Invalid credentials: wrong email or password")]
+    InvalidCredentials {
+        /// The original error response, e.g. to read the raw message by [`Error::api_message`].
+        response: ApiErrorResponse,
+    },
+    /// The operation requires a recent sign-in, e.g. deleting the account, so the user must sign in again.
+    ///
+    /// See also [`crate::Session::delete_account_reauth`].
+    #[error("Reauthentication required")]
+    ReauthenticationRequired,
+    /// The operation is not supported for an anonymous user, so please link the user with other credentials first.
+    ///
+    /// See also [`crate::Session::started_anonymously`].
+    #[error(
+        "The operation is not supported for an anonymous user: {operation}"
+    )]
+    NotSupportedForAnonymousUser {
+        operation: &'static str,
+    },
+    /// The user has not linked the email and password provider, so the user can not sign in again with the password,
+    /// e.g. the user who has signed in with OAuth or the phone number.
+    ///
+    /// See also [`crate::Session::delete_account_reauth`].
+    #[error("The email and password provider is not linked to the user")]
+    PasswordProviderNotLinked,
+    /// Multi-factor authentication is required to complete signing in.
+    #[error("Multi-factor authentication required: {hints:?}")]
+    MfaRequired {
+        /// The pending credential to complete signing in with a second factor.
+        pending_credential: String,
+        /// The second factors enrolled by the user.
+        hints: Vec<MfaEnrollment>,
+    },
+    /// Signing in with the OAuth credential requires a confirmation (`needConfirmation`)
+    /// because another account with the same email already exists.
+    ///
+    /// See also [`crate::PendingOAuthLink`].
+    #[error("Account linking required: {pending:?}")]
+    AccountLinkingRequired {
+        /// The pending OAuth credential to link to the existing account.
+        pending: Box<PendingOAuthLink>,
+    },
+    /// The project of the exchanged tokens does not match the expected one,
+    /// returned by [`crate::Config::exchange_refresh_token`] and [`crate::Config::sign_in_anonymously_or_restore`]
+    /// with [`crate::Config::with_expected_project_id`].
+    ///
+    /// See also [`Error::CustomTokenProjectMismatch`] for custom tokens.
+    #[error("Project ID mismatch: expected {expected:?}, but got {actual:?}")]
+    ProjectIdMismatch {
+        /// The expected project ID.
+        expected: ProjectId,
+        /// The project ID returned by the API.
+        actual: String,
+    },
+    /// The custom token has been minted for a different Firebase project (`CREDENTIAL_MISMATCH`),
+    /// e.g. by a service account of another project than the API key.
+    ///
+    /// This is returned by [`crate::Config::sign_in_with_custom_token`] regardless of [`crate::Config::with_expected_project_id`],
+    /// which is checked only for the refresh tokens by [`Error::ProjectIdMismatch`].
+    #[error("The custom token corresponds to a different Firebase project")]
+    CustomTokenProjectMismatch,
+    /// The email is already registered with the providers.
+    ///
+    /// See also [`crate::Config::sign_up_with_email_password_checked`].
+    #[error("Email already registered: {providers:?}")]
+    EmailAlreadyRegistered {
+        /// The providers linked to the email.
+        providers: Vec<ProviderId>,
+    },
 
     // Response errors
     /// Read response text failed.
@@ -43,6 +146,18 @@ pub enum Error {
         error: serde_json::Error,
         json: String,
     },
+    /// Empty response body on a successful response that expects a payload.
+    #[error("Empty response body: ({status_code:?})")]
+    EmptyResponseBody {
+        status_code: reqwest::StatusCode,
+    },
+    /// The response body exceeds the maximum size.
+    ///
+    /// See also [`crate::Client::with_max_response_size`].
+    #[error("Response body exceeds the maximum size: {max_size} bytes")]
+    ResponseTooLarge {
+        max_size: usize,
+    },
     /// Deserialize error response JSON failed.
     #[error("Deserialize error response JSON failed: {error:?} - {json:?}")]
     DeserializeErrorResponseJsonFailed {
@@ -62,6 +177,486 @@ pub enum Error {
     UrlEncodeFailed {
         error: serde_urlencoded::ser::Error,
     },
+    /// The credentials of the post body miss the field required by the ID provider.
+    ///
+    /// See also [`crate::IdpPostBody::try_new`].
+    #[error("Missing credential of the ID provider {provider_id}: one of {required:?}")]
+    MissingIdpCredential {
+        /// The ID of the ID provider.
+        provider_id: ProviderId,
+        /// The keys of the credential, one of which is required.
+        required: &'static [&'static str],
+    },
+    /// No attribute to delete is specified, which would be a no-op.
+    ///
+    /// See also [`crate::Session::delete_profile`].
+    #[error("No attribute to delete is specified")]
+    NoDeleteAttribute,
+    /// The operation is a privileged operation of the Admin SDK that requires the credentials of a service account,
+    /// which is not available with a plain API key.
+    ///
+    /// This is a configuration error of the client, not of the user.
+    #[error("The operation requires admin credentials (a service account), not an API key: {operation}")]
+    RequiresAdminCredentials {
+        operation: &'static str,
+    },
+    /// Decode ID token failed.
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "verify" is enabled.
+    #[cfg(feature = "verify")]
+    #[error("Decode ID token failed: {error:?}")]
+    DecodeIdTokenFailed {
+        error: crate::verification::VerificationError,
+    },
+}
+
+impl Error {
+    /// Returns the coarse kind of the error to branch on without matching every variant.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::error::ErrorKind;
+    ///
+    /// match error.kind() {
+    ///     | ErrorKind::Transport => {
+    ///         // Retry the request.
+    ///     },
+    ///     | ErrorKind::Api => {
+    ///         // Report the error to the user.
+    ///     },
+    ///     | _ => {
+    ///         // Handle other errors.
+    ///     },
+    /// }
+    /// ```
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            | Error::InvalidHeaderValue {
+                ..
+            } => ErrorKind::InvalidInput,
+            | Error::HttpRequestError(_) => ErrorKind::Transport,
+            | Error::BuildClientFailed(_) => ErrorKind::InvalidInput,
+            | Error::ApiError {
+                ..
+            } => ErrorKind::Api,
+            | Error::InvalidIdToken {
+                ..
+            } => ErrorKind::Auth,
+            | Error::AccountDisabled {
+                ..
+            } => ErrorKind::Auth,
+            | Error::InvalidCredentials {
+                ..
+            } => ErrorKind::Auth,
+            | Error::ReauthenticationRequired => ErrorKind::Auth,
+            | Error::NotSupportedForAnonymousUser {
+                ..
+            } => ErrorKind::Auth,
+            | Error::PasswordProviderNotLinked => ErrorKind::Auth,
+            | Error::MfaRequired {
+                ..
+            } => ErrorKind::Auth,
+            | Error::AccountLinkingRequired {
+                ..
+            } => ErrorKind::Auth,
+            | Error::EmailAlreadyRegistered {
+                ..
+            } => ErrorKind::Api,
+            | Error::CustomTokenProjectMismatch => ErrorKind::Auth,
+            | Error::ProjectIdMismatch {
+                ..
+            } => ErrorKind::Auth,
+            | Error::ReadResponseTextFailed {
+                ..
+            } => ErrorKind::Transport,
+            | Error::DeserializeResponseJsonFailed {
+                ..
+            } => ErrorKind::Deserialization,
+            | Error::EmptyResponseBody {
+                ..
+            } => ErrorKind::Deserialization,
+            | Error::ResponseTooLarge {
+                ..
+            } => ErrorKind::Deserialization,
+            | Error::DeserializeErrorResponseJsonFailed {
+                ..
+            } => ErrorKind::Deserialization,
+            | Error::ParseExpiresInFailed {
+                ..
+            } => ErrorKind::Deserialization,
+            | Error::NotFoundAnyUserData => ErrorKind::Deserialization,
+            | Error::UrlEncodeFailed {
+                ..
+            } => ErrorKind::InvalidInput,
+            | Error::MissingIdpCredential {
+                ..
+            } => ErrorKind::InvalidInput,
+            | Error::NoDeleteAttribute => ErrorKind::InvalidInput,
+            | Error::RequiresAdminCredentials {
+                ..
+            } => ErrorKind::InvalidInput,
+            #[cfg(feature = "verify")]
+            | Error::DecodeIdTokenFailed {
+                ..
+            } => ErrorKind::Deserialization,
+        }
+    }
+
+    /// Returns the raw error message of the Firebase Auth API, e.g. for logging and support.
+    ///
+    /// ## Returns
+    /// The original `message` of the error response if the error is an [`Error::ApiError`]
+    /// or promoted from it, i.e. [`Error::InvalidIdToken`], [`Error::AccountDisabled`] and [`Error::InvalidCredentials`],
+    /// otherwise `None`.
+    ///
+    /// ## Example
+    /// ```
+    /// if let Some(message) = error.api_message() {
+    ///     eprintln!("Firebase Auth API error: {}", message);
+    /// }
+    /// ```
+    pub fn api_message(&self) -> Option<&str> {
+        match self {
+            | Error::ApiError {
+                response,
+                ..
+            }
+            | Error::InvalidIdToken {
+                response,
+            }
+            | Error::AccountDisabled {
+                response,
+            }
+            | Error::InvalidCredentials {
+                response,
+            } => Some(&response.error.message),
+            | _ => None,
+        }
+    }
+
+    /// Returns the underlying `reqwest::Error`, e.g. for detailed diagnostics of the transport.
+    ///
+    /// ## Returns
+    /// The original error if the error is an [`Error::HttpRequestError`], [`Error::BuildClientFailed`]
+    /// or [`Error::ReadResponseTextFailed`], otherwise `None`.
+    ///
+    /// ## Example
+    /// ```
+    /// if let Some(error) = error.as_reqwest() {
+    ///     eprintln!("Timeout: {}", error.is_timeout());
+    /// }
+    /// ```
+    pub fn as_reqwest(&self) -> Option<&reqwest::Error> {
+        match self {
+            | Error::HttpRequestError(error) => Some(error),
+            | Error::BuildClientFailed(error) => Some(error),
+            | Error::ReadResponseTextFailed {
+                error,
+            } => Some(error),
+            | _ => None,
+        }
+    }
+
+    /// Returns the underlying `serde_json::Error`, e.g. to report the line and column of a malformed response.
+    ///
+    /// ## Returns
+    /// The original error if the error is an [`Error::DeserializeResponseJsonFailed`]
+    /// or [`Error::DeserializeErrorResponseJsonFailed`], otherwise `None`.
+    ///
+    /// ## Example
+    /// ```
+    /// if let Some(error) = error.as_serde() {
+    ///     eprintln!("Malformed JSON at {}:{}", error.line(), error.column());
+    /// }
+    /// ```
+    pub fn as_serde(&self) -> Option<&serde_json::Error> {
+        match self {
+            | Error::DeserializeResponseJsonFailed {
+                error,
+                ..
+            } => Some(error),
+            | Error::DeserializeErrorResponseJsonFailed {
+                error,
+                ..
+            } => Some(error),
+            | _ => None,
+        }
+    }
+
+    /// Creates an error from the error response of the Firebase Auth API,
+    /// promoting `INVALID_ID_TOKEN` into [`Error::InvalidIdToken`] and `USER_DISABLED` into [`Error::AccountDisabled`].
+    pub(crate) fn from_api_error_response(
+        endpoint: &'static str,
+        status_code: reqwest::StatusCode,
+        response: ApiErrorResponse,
+    ) -> Self {
+        // Check error message and create error code.
+        let error_code: CommonErrorCode = response
+            .error
+            .message
+            .clone()
+            .into();
+
+        match error_code {
+            // Take invalid ID token error as special case.
+            | CommonErrorCode::InvalidIdToken => Error::InvalidIdToken {
+                response,
+            },
+            // Take disabled account error as special case.
+            | CommonErrorCode::UserDisabled => Error::AccountDisabled {
+                response,
+            },
+            | _ => Error::ApiError {
+                endpoint,
+                status_code,
+                error_code,
+                response,
+            },
+        }
+    }
+
+    /// Maps a deserialization failure of a sign in response into [`Error::MfaRequired`]
+    /// when the response requires the multi-factor authentication instead of returning tokens.
+    pub(crate) fn map_mfa_required(self) -> Self {
+        match self {
+            | Error::DeserializeResponseJsonFailed {
+                error,
+                json,
+            } => match serde_json::from_str::<MfaRequiredResponse>(&json) {
+                | Ok(response) => Error::MfaRequired {
+                    pending_credential: response.mfa_pending_credential,
+                    hints: response
+                        .mfa_info
+                        .unwrap_or_default(),
+                },
+                | Err(_) => Error::DeserializeResponseJsonFailed {
+                    error,
+                    json,
+                },
+            },
+            | _ => self,
+        }
+    }
+
+    /// Maps a sign in response that requires a confirmation (`needConfirmation`) without tokens
+    /// into [`Error::AccountLinkingRequired`] with the pending OAuth credential.
+    pub(crate) fn map_account_linking_required(self) -> Self {
+        match self {
+            | Error::DeserializeResponseJsonFailed {
+                error,
+                json,
+            } => match PendingOAuthLink::parse(&json) {
+                | Some(pending) => Error::AccountLinkingRequired {
+                    pending: Box::new(pending),
+                },
+                | None => Error::DeserializeResponseJsonFailed {
+                    error,
+                    json,
+                },
+            },
+            | _ => self,
+        }
+    }
+
+    /// Maps an `INVALID_LOGIN_CREDENTIALS`, `EMAIL_NOT_FOUND` or `INVALID_PASSWORD` API error into [`Error::InvalidCredentials`]
+    /// to handle the wrong email or password regardless of the email enumeration protection.
+    pub(crate) fn map_invalid_credentials(self) -> Self {
+        match self {
+            | Error::ApiError {
+                error_code:
+                    CommonErrorCode::InvalidLoginCredentials
+                    | CommonErrorCode::EmailNotFound
+                    | CommonErrorCode::InvalidPassword,
+                response,
+                ..
+            } => Error::InvalidCredentials {
+                response,
+            },
+            | _ => self,
+        }
+    }
+
+    /// Maps a `CREDENTIAL_TOO_OLD_LOGIN_AGAIN` API error into [`Error::ReauthenticationRequired`]
+    /// to explain that the user must sign in again before the operation.
+    pub(crate) fn map_reauthentication_required(self) -> Self {
+        match self {
+            | Error::ApiError {
+                error_code: CommonErrorCode::CredentialTooOldLoginAgain,
+                ..
+            } => Error::ReauthenticationRequired,
+            | _ => self,
+        }
+    }
+
+    /// Maps a `CREDENTIAL_MISMATCH` API error into [`Error::CustomTokenProjectMismatch`]
+    /// to explain that the custom token has been minted for a different project.
+    pub(crate) fn map_custom_token_project_mismatch(self) -> Self {
+        match self {
+            | Error::ApiError {
+                error_code: CommonErrorCode::CredentialMismatch,
+                ..
+            } => Error::CustomTokenProjectMismatch,
+            | _ => self,
+        }
+    }
+
+    /// Maps an `ADMIN_ONLY_OPERATION` API error into [`Error::RequiresAdminCredentials`]
+    /// to explain that the operation is not available with a plain API key.
+    pub(crate) fn map_requires_admin_credentials(
+        self,
+        operation: &'static str,
+    ) -> Self {
+        match self {
+            | Error::ApiError {
+                error_code: CommonErrorCode::AdminOnlyOperation,
+                ..
+            } => Error::RequiresAdminCredentials {
+                operation,
+            },
+            | _ => self,
+        }
+    }
+}
+
+// Feature "axum"
+#[cfg(feature = "axum")]
+impl Error {
+    /// Returns the HTTP status code to respond to the client of your server, e.g. on an axum handler.
+    ///
+    /// - `401 Unauthorized` - Invalid or expired credentials, e.g. `INVALID_ID_TOKEN` and `TOKEN_EXPIRED`.
+    /// - `403 Forbidden` - The user or the operation is not allowed, e.g. `USER_DISABLED`.
+    /// - `409 Conflict` - The account already exists, e.g. `EMAIL_EXISTS`.
+    /// - `429 Too Many Requests` - Rate limited by `TOO_MANY_ATTEMPTS_TRY_LATER`.
+    /// - `400 Bad Request` - Invalid input, e.g. `INVALID_EMAIL` and `WEAK_PASSWORD`.
+    /// - `500 Internal Server Error` - Misconfiguration of your server, e.g. `INVALID_API_KEY`.
+    /// - `502 Bad Gateway` - Failed to communicate with the Firebase Auth or to parse its response.
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "axum" is enabled.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Error;
+    ///
+    /// let error = Error::NoDeleteAttribute;
+    ///
+    /// assert_eq!(error.status_code(), axum::http::StatusCode::BAD_REQUEST);
+    /// ```
+    pub fn status_code(&self) -> axum::http::StatusCode {
+        use axum::http::StatusCode;
+
+        match self {
+            | Error::ApiError {
+                error_code,
+                ..
+            } => match error_code {
+                | CommonErrorCode::TooManyAttemptsTryLater => {
+                    StatusCode::TOO_MANY_REQUESTS
+                },
+                | CommonErrorCode::InvalidIdToken
+                | CommonErrorCode::TokenExpired
+                | CommonErrorCode::InvalidRefreshToken
+                | CommonErrorCode::MissingRefreshToken
+                | CommonErrorCode::CredentialTooOldLoginAgain
+                | CommonErrorCode::InvalidLoginCredentials
+                | CommonErrorCode::InvalidPassword
+                | CommonErrorCode::InvalidCustomToken
+                | CommonErrorCode::InvalidIdpResponse
+                | CommonErrorCode::InvalidCredentialOrProviderId(_)
+                | CommonErrorCode::EmailNotFound
+                | CommonErrorCode::UserNotFound => StatusCode::UNAUTHORIZED,
+                | CommonErrorCode::UserDisabled
+                | CommonErrorCode::OperationNotAllowed(_) => {
+                    StatusCode::FORBIDDEN
+                },
+                | CommonErrorCode::EmailExists
+                | CommonErrorCode::FederatedUserIdAlreadyLinked => {
+                    StatusCode::CONFLICT
+                },
+                | CommonErrorCode::InvalidApiKey
+                | CommonErrorCode::AdminOnlyOperation
+                | CommonErrorCode::CredentialMismatch
+                | CommonErrorCode::InvalidGrantType
+                | CommonErrorCode::InvalidJsonPayloadReceived(_) => {
+                    StatusCode::INTERNAL_SERVER_ERROR
+                },
+                | _ => StatusCode::BAD_REQUEST,
+            },
+            | Error::AccountDisabled {
+                ..
+            }
+            | Error::PasswordProviderNotLinked
+            | Error::NotSupportedForAnonymousUser {
+                ..
+            } => StatusCode::FORBIDDEN,
+            | Error::EmailAlreadyRegistered {
+                ..
+            } => StatusCode::CONFLICT,
+            | Error::RequiresAdminCredentials {
+                ..
+            }
+            | Error::CustomTokenProjectMismatch
+            | Error::BuildClientFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            | _ => match self.kind() {
+                | ErrorKind::Auth => StatusCode::UNAUTHORIZED,
+                | ErrorKind::InvalidInput => StatusCode::BAD_REQUEST,
+                | ErrorKind::Transport
+                | ErrorKind::Deserialization
+                | ErrorKind::Api => StatusCode::BAD_GATEWAY,
+            },
+        }
+    }
+}
+
+/// Responds only the status code of [`Error::status_code`] without any body
+/// not to leak the details of the error, e.g. the response of the Firebase Auth, to the client.
+///
+/// ## NOTE
+/// This is only available when the feature "axum" is enabled.
+#[cfg(feature = "axum")]
+impl axum::response::IntoResponse for Error {
+    fn into_response(self) -> axum::response::Response {
+        self.status_code()
+            .into_response()
+    }
+}
+
+/// The coarse kind of [`Error`].
+///
+/// See also [`Error::kind`].
+///
+/// ## NOTE
+/// This enum is marked as `#[non_exhaustive]` to add new kinds without breaking changes,
+/// so please include a wildcard arm (`_ => ...`) when matching on it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Failed to communicate with the server, e.g. connection errors and timeouts.
+    Transport,
+    /// The Firebase Auth API returned an error.
+    Api,
+    /// Failed to parse a response from the server.
+    Deserialization,
+    /// Invalid input to build a request, e.g. an invalid header value.
+    InvalidInput,
+    /// The user needs to authenticate (again), e.g. an invalid ID token and the multi-factor authentication.
+    Auth,
+}
+
+impl From<&Error> for ErrorKind {
+    fn from(error: &Error) -> Self {
+        error.kind()
+    }
+}
+
+/// Sign in response payload that requires the multi-factor authentication.
+#[derive(Deserialize)]
+struct MfaRequiredResponse {
+    #[serde(rename = "mfaPendingCredential")]
+    mfa_pending_credential: String,
+    #[serde(rename = "mfaInfo")]
+    mfa_info: Option<Vec<MfaEnrollment>>,
 }
 
 /// Error response payload for the auth endpoints.
@@ -109,7 +704,14 @@ pub struct ErrorElement {
 }
 
 /// Common error codes for the Firebase Auth API.
+///
+/// Error codes that are not listed here are mapped to [`CommonErrorCode::Unknown`].
+///
+/// ## NOTE
+/// This enum is marked as `#[non_exhaustive]` to add new error codes without breaking changes,
+/// so please include a wildcard arm (`_ => ...`) when matching on it.
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum CommonErrorCode {
     /// OPERATION_NOT_ALLOWED: The operation is disabled for this project.
     OperationNotAllowed(String),
@@ -167,6 +769,23 @@ pub enum CommonErrorCode {
     Unknown(String),
 }
 
+impl CommonErrorCode {
+    /// Returns the raw error message of the parameterized error codes,
+    /// e.g. [`CommonErrorCode::OperationNotAllowed`] and [`CommonErrorCode::Unknown`].
+    ///
+    /// ## Returns
+    /// The raw error message, or `None` for the error codes without any parameter.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            | CommonErrorCode::OperationNotAllowed(message)
+            | CommonErrorCode::InvalidJsonPayloadReceived(message)
+            | CommonErrorCode::InvalidCredentialOrProviderId(message)
+            | CommonErrorCode::Unknown(message) => Some(message),
+            | _ => None,
+        }
+    }
+}
+
 impl From<String> for CommonErrorCode {
     fn from(val: String) -> Self {
         if val
@@ -226,3 +845,136 @@ impl From<String> for CommonErrorCode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Creates an error response of the Firebase Auth with the message.
+    fn error_response(message: &str) -> ApiErrorResponse {
+        serde_json::from_value(serde_json::json!({
+            "error": {
+                "code": 400,
+                "message": message,
+                "errors": [],
+            },
+        }))
+        .unwrap()
+    }
+
+    /// Creates an error from the error response with the message.
+    fn api_error(message: &str) -> Error {
+        Error::from_api_error_response(
+            "accounts:lookup",
+            reqwest::StatusCode::BAD_REQUEST,
+            error_response(message),
+        )
+    }
+
+    #[test]
+    fn api_message_of_api_error() {
+        let error = api_error("EMAIL_EXISTS");
+
+        assert!(matches!(error, Error::ApiError { .. }));
+        assert_eq!(
+            error.api_message(),
+            Some("EMAIL_EXISTS")
+        );
+    }
+
+    #[test]
+    fn api_message_of_invalid_id_token() {
+        let error = api_error("INVALID_ID_TOKEN");
+
+        assert!(matches!(
+            error,
+            Error::InvalidIdToken { .. }
+        ));
+        assert_eq!(
+            error.api_message(),
+            Some("INVALID_ID_TOKEN")
+        );
+    }
+
+    #[test]
+    fn api_message_of_account_disabled() {
+        let error = api_error("USER_DISABLED");
+
+        assert!(matches!(
+            error,
+            Error::AccountDisabled { .. }
+        ));
+        assert_eq!(
+            error.api_message(),
+            Some("USER_DISABLED")
+        );
+    }
+
+    #[test]
+    fn api_message_of_invalid_credentials() {
+        for message in [
+            "INVALID_LOGIN_CREDENTIALS",
+            "EMAIL_NOT_FOUND",
+            "INVALID_PASSWORD",
+        ] {
+            let error = api_error(message).map_invalid_credentials();
+
+            assert!(matches!(
+                error,
+                Error::InvalidCredentials { .. }
+            ));
+            assert_eq!(error.api_message(), Some(message));
+        }
+    }
+
+    #[test]
+    fn api_message_of_other_errors() {
+        assert_eq!(
+            Error::NoDeleteAttribute.api_message(),
+            None
+        );
+    }
+
+    /// Creates a `reqwest::Error` without sending any request.
+    #[cfg(feature = "axum")]
+    fn reqwest_error() -> reqwest::Error {
+        reqwest::Client::new()
+            .get("invalid url")
+            .build()
+            .unwrap_err()
+    }
+
+    #[cfg(feature = "axum")]
+    #[test]
+    fn status_code_of_errors() {
+        use axum::http::StatusCode;
+
+        let cases = [
+            (
+                api_error("INVALID_ID_TOKEN"),
+                StatusCode::UNAUTHORIZED,
+            ),
+            (
+                api_error("TOO_MANY_ATTEMPTS_TRY_LATER"),
+                StatusCode::TOO_MANY_REQUESTS,
+            ),
+            (
+                api_error("INVALID_EMAIL"),
+                StatusCode::BAD_REQUEST,
+            ),
+            (
+                Error::HttpRequestError(reqwest_error()),
+                StatusCode::BAD_GATEWAY,
+            ),
+        ];
+
+        for (error, status_code) in cases {
+            assert_eq!(
+                error.status_code(),
+                status_code,
+                "{:?}",
+                error
+            );
+        }
+    }
+}
@@ -11,24 +11,61 @@
 //!     - HTTP client customization. See [`crate::client`].
 //! - (Optional) `oauth`
 //!    - OAuth 2.0 client. See [`crate::oauth`].
+//! - (Optional) `testing`
+//!     - Helpers for integration tests. See [`crate::testing`].
+//! - (Optional) `emulator`
+//!     - Preconfigured clients for the Firebase Local Emulator Suite. See [`crate::emulator`].
+//! - (Optional) `tokio`
+//!     - The sleep function of tokio for retries and polling. See [`crate::sleep`].
+//!     - The session wrapper that deletes the account of an ephemeral user on drop. See [`crate::DeleteOnDrop`].
+//! - (Optional) `axum`
+//!     - `IntoResponse` of the errors with HTTP status codes for axum handlers. See [`crate::Error::status_code`].
+//!
+//! ## WebAssembly
+//! [`Config`] and [`Session`] can be compiled for `wasm32-unknown-unknown` with the WASM backend of `reqwest` that uses the `fetch` API of the browser.
+//!
+//! NOTE: Timeouts of `reqwest::ClientBuilder` are not available on WASM, and the feature `testing` is only available on native targets.
+//! The polling loops of the Device Code grant type in the feature `oauth`, e.g. `oauth::FacebookDeviceCodeSession::poll_exchange_token`,
+//! are also only available on native targets, so please poll once by `oauth::FacebookDeviceCodeSession::exchange_token` on WASM.
+//!
+//! ## Thread safety
+//! [`Client`], [`Config`], [`Session`] and `verification::VerificationConfig` are `Send + Sync + 'static`,
+//! which is asserted at compile time, so they can be shared between tasks, e.g. by `Arc` or in the state of a web server.
 
 // public modules
 pub mod api;
 pub mod client;
 pub mod config;
 pub mod error;
+pub mod metrics;
 pub mod session;
+pub mod sleep;
 
 // Internal modules
 pub(crate) mod endpoint;
 
 // Private modules
+#[cfg(not(target_arch = "wasm32"))]
+mod cached_session;
 mod data;
+#[cfg(all(
+    feature = "tokio",
+    not(target_arch = "wasm32")
+))]
+mod delete_on_drop;
 mod result;
 
 // Re-exports
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::cached_session::CachedSession;
 pub use crate::client::Client;
+pub use crate::client::ClientBuilder;
 pub use crate::config::Config;
+#[cfg(all(
+    feature = "tokio",
+    not(target_arch = "wasm32")
+))]
+pub use crate::delete_on_drop::DeleteOnDrop;
 pub use crate::error::Error;
 pub use crate::result::Result;
 pub use crate::session::Session;
@@ -37,22 +74,37 @@ pub use crate::session::Session;
 pub(crate) use crate::endpoint::Endpoint;
 
 // Re-exports for data module
+pub use crate::data::account_info::AccountInfo;
+pub use crate::data::action_code_info::ActionCodeInfo;
+pub use crate::data::action_code_operation::ActionCodeOperation;
+pub use crate::data::action_code_settings::ActionCodeSettings;
 pub use crate::data::api_key::ApiKey;
+pub use crate::data::auto_link::AutoLink;
 pub use crate::data::delete_attribute::DeleteAttribute;
 pub use crate::data::display_name::DisplayName;
 pub use crate::data::email::Email;
+pub use crate::data::enabled_sign_in_methods::EnabledSignInMethods;
 pub use crate::data::expires_in::ExpiresIn;
 pub use crate::data::id_token::IdToken;
 pub use crate::data::idp_post_body::IdpPostBody;
 pub use crate::data::language_code::LanguageCode;
+pub use crate::data::mfa_enrollment::MfaEnrollment;
 pub use crate::data::oauth_continue_uri::OAuthContinueUri;
 pub use crate::data::oauth_request_uri::OAuthRequestUri;
+pub use crate::data::oob_code::OobCode;
 pub use crate::data::password::Password;
+pub use crate::data::password_policy::PasswordPolicy;
+pub use crate::data::pending_oauth_link::PendingOAuthLink;
 pub use crate::data::photo_url::PhotoUrl;
 pub use crate::data::project_id::ProjectId;
 pub use crate::data::provider_id::ProviderId;
 pub use crate::data::provider_user_info::ProviderUserInfo;
 pub use crate::data::refresh_token::RefreshToken;
+pub use crate::data::retry_policy::Jitter;
+pub use crate::data::retry_policy::RetryPolicy;
+pub use crate::data::sent_oob_code::SentOobCode;
+pub use crate::data::tokens::Tokens;
+pub use crate::data::uid::Uid;
 pub use crate::data::user_data::UserData;
 
 // Feature "verify"
@@ -67,3 +119,25 @@ pub use reqwest;
 // Feature "oauth"
 #[cfg(feature = "oauth")]
 pub mod oauth;
+
+// Feature "emulator"
+#[cfg(feature = "emulator")]
+pub mod emulator;
+
+// Feature "testing"
+#[cfg(all(
+    feature = "testing",
+    not(target_arch = "wasm32")
+))]
+pub mod testing;
+
+// Compile-time assertions of the thread safety.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+    assert_send_sync::<Client>();
+    assert_send_sync::<Config>();
+    assert_send_sync::<Session>();
+    #[cfg(feature = "verify")]
+    assert_send_sync::<verification::VerificationConfig>();
+};
@@ -0,0 +1,109 @@
+//! Provides a hook to record metrics of API calls on the transport layer.
+//!
+//! ## Examples
+//! An example to count API calls per endpoint and outcome is as follows:
+//!
+//! ```rust
+//! use std::sync::Arc;
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//! use std::time::Duration;
+//! use fars::Client;
+//! use fars::metrics::Metrics;
+//! use fars::error::CommonErrorCode;
+//!
+//! #[derive(Default)]
+//! struct CallCounter {
+//!     succeeded: AtomicU64,
+//!     failed: AtomicU64,
+//! }
+//!
+//! impl Metrics for CallCounter {
+//!     fn record(
+//!         &self,
+//!         endpoint: &str,
+//!         status: fars::reqwest::StatusCode,
+//!         latency: Duration,
+//!         error_code: Option<&CommonErrorCode>,
+//!     ) {
+//!         if status.is_success() {
+//!             self.succeeded.fetch_add(1, Ordering::Relaxed);
+//!         } else {
+//!             self.failed.fetch_add(1, Ordering::Relaxed);
+//!         }
+//!     }
+//!
+//!     fn record_transport_error(
+//!         &self,
+//!         endpoint: &str,
+//!         latency: Duration,
+//!         error: &fars::reqwest::Error,
+//!     ) {
+//!         self.failed.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! // Create a client with the metrics hook.
+//! let client = Client::new()
+//!     .with_metrics(Arc::new(CallCounter::default()));
+//! ```
+
+use std::time::Duration;
+
+use crate::error::CommonErrorCode;
+
+/// A hook to record metrics of API calls, e.g. to integrate with the `metrics` or `prometheus` crates.
+///
+/// ## NOTE
+/// [`Metrics::record`] is invoked when a response has been received,
+/// and [`Metrics::record_transport_error`] is invoked when sending a request has failed (`Error::HttpRequestError`),
+/// e.g. by a connection error or a timeout.
+///
+/// The latency is always zero on `wasm32` targets because `std::time::Instant` is not available.
+pub trait Metrics: Send + Sync {
+    /// Records an API call.
+    ///
+    /// ## Arguments
+    /// - `endpoint` - The endpoint of the API, e.g. `accounts:signInWithPassword`.
+    /// - `status` - The HTTP status code of the response.
+    /// - `latency` - The latency from sending the request to receiving the response body.
+    /// - `error_code` - The error code of the Firebase Auth if the API returned an error.
+    fn record(
+        &self,
+        endpoint: &str,
+        status: reqwest::StatusCode,
+        latency: Duration,
+        error_code: Option<&CommonErrorCode>,
+    );
+
+    /// Records an API call that has failed to send a request without any response,
+    /// e.g. by a connection error or a timeout.
+    ///
+    /// The default implementation records nothing.
+    ///
+    /// ## Arguments
+    /// - `endpoint` - The endpoint of the API, e.g. `accounts:signInWithPassword`.
+    /// - `latency` - The latency from sending the request to failing.
+    /// - `error` - The error of the HTTP client.
+    fn record_transport_error(
+        &self,
+        _endpoint: &str,
+        _latency: Duration,
+        _error: &reqwest::Error,
+    ) {
+    }
+}
+
+/// A metrics hook that records nothing.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopMetrics;
+
+impl Metrics for NoopMetrics {
+    fn record(
+        &self,
+        _endpoint: &str,
+        _status: reqwest::StatusCode,
+        _latency: Duration,
+        _error_code: Option<&CommonErrorCode>,
+    ) {
+    }
+}
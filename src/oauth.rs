@@ -41,6 +41,7 @@ mod result;
 mod token;
 
 pub use auth_code_client::AuthorizationCodeClient;
+pub use auth_code_session::validate_state;
 pub use auth_code_session::AuthorizationCodeSession;
 pub use data::AccessToken;
 pub use data::AuthorizationCode;
@@ -63,6 +64,7 @@ pub use device_code_session::DeviceCodeSession;
 pub use error::OAuthError;
 pub use idp::facebook_auth_code::FacebookAuthorizationCodeClient;
 pub use idp::facebook_device_code::FacebookDeviceCodeClient;
+pub use idp::facebook_device_code::FacebookDeviceCodeSession;
 pub use idp::github_auth_code::GitHubAuthorizationCodeClient;
 pub use idp::google_auth_code::GoogleAuthorizationCodeClient;
 pub use idp::google_device_code::GoogleDeviceCodeClient;
@@ -9,6 +9,7 @@ use crate::oauth::AuthorizeEndpoint;
 use crate::oauth::AuthorizeUrl;
 use crate::oauth::ClientId;
 use crate::oauth::ClientSecret;
+use crate::oauth::CsrfState;
 use crate::oauth::OAuthResult;
 use crate::oauth::OAuthScope;
 use crate::oauth::PkceOption;
@@ -162,11 +163,58 @@ impl AuthorizationCodeClient {
     pub fn generate_session(
         &self,
         scopes: HashSet<OAuthScope>,
+    ) -> AuthorizationCodeSession {
+        self.generate_session_with_state(scopes, CsrfState::new_random())
+    }
+
+    /// Generates an Authorization Code flow session with authorize URL and the specified CSRF state.
+    ///
+    /// This is useful to embed application data into the state
+    /// or to store the state on the server side for multi-instance deployments.
+    ///
+    /// ## Arguments
+    /// - `scopes` - Scopes to request authorization.
+    /// - `state` - The CSRF state of the authorization request, it must be unguessable.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::collections::HashSet;
+    /// use fars::oauth::AuthorizationCodeClient;
+    /// use fars::oauth::ClientId;
+    /// use fars::oauth::ClientSecret;
+    /// use fars::oauth::AuthorizeEndpoint;
+    /// use fars::oauth::TokenEndpoint;
+    /// use fars::oauth::RedirectUrl;
+    /// use fars::oauth::PkceOption;
+    /// use fars::oauth::OAuthScope;
+    /// use fars::oauth::CsrfState;
+    ///
+    /// let client = AuthorizationCodeClient::new(
+    ///     ClientId::new("client-id"),
+    ///     Some(ClientSecret::new("client-secret")),
+    ///     AuthorizeEndpoint::new("https://example.com/auth")?,
+    ///     TokenEndpoint::new("https://example.com/token")?,
+    ///     RedirectUrl::new("https://my.app.com/callback")?,
+    ///     PkceOption::S256,
+    /// )?;
+    ///
+    /// let session = client.generate_session_with_state(
+    ///     HashSet::from([OAuthScope::new("scope1")]),
+    ///     CsrfState::new_random(),
+    /// );
+    ///
+    /// // Store the state on the server side.
+    /// let state = session.csrf_state().inner();
+    /// ```
+    pub fn generate_session_with_state(
+        &self,
+        scopes: HashSet<OAuthScope>,
+        state: CsrfState,
     ) -> AuthorizationCodeSession {
         // Generate an authorization request.
         let mut request = self
             .client
-            .authorize_url(CsrfToken::new_random);
+            .authorize_url(|| CsrfToken::new(state.inner().to_owned()));
 
         // Add a PKCE code challenge and verifier if supported.
         let code_verifier;
@@ -203,7 +251,7 @@ impl AuthorizationCodeClient {
             authorize_url: AuthorizeUrl::new(authorize_url),
             client: self.clone(),
             pkce_code_verifier: code_verifier,
-            csrf_state,
+            csrf_state: CsrfState::new(csrf_state.secret().to_owned()),
         }
     }
 }
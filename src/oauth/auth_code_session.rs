@@ -1,6 +1,5 @@
-use oauth2::{CsrfToken, PkceCodeVerifier, TokenResponse};
+use oauth2::PkceCodeVerifier;
 
-use crate::oauth::AccessToken;
 use crate::oauth::AuthorizationCode;
 use crate::oauth::AuthorizationCodeClient;
 use crate::oauth::AuthorizeUrl;
@@ -8,7 +7,6 @@ use crate::oauth::CsrfState;
 use crate::oauth::OAuthError;
 use crate::oauth::OAuthResult;
 use crate::oauth::OAuthToken;
-use crate::oauth::RefreshToken;
 
 /// A session published by ['crate::oauth::AuthorizationCodeClient'].
 ///
@@ -49,10 +47,15 @@ pub struct AuthorizationCodeSession {
     /// The PKCE code verifier.
     pub(crate) pkce_code_verifier: Option<String>,
     /// The CSRF state.
-    pub(crate) csrf_state: CsrfToken,
+    pub(crate) csrf_state: CsrfState,
 }
 
 impl AuthorizationCodeSession {
+    /// Returns the CSRF state of this session, e.g. to store it on the server side.
+    pub fn csrf_state(&self) -> &CsrfState {
+        &self.csrf_state
+    }
+
     /// Exchanges an authorization code into an access token.
     ///
     /// ## Arguments
@@ -104,12 +107,7 @@ impl AuthorizationCodeSession {
         state: CsrfState,
     ) -> OAuthResult<OAuthToken> {
         // Check the CSRF state.
-        if state
-            .inner()
-            .ne(self.csrf_state.secret())
-        {
-            return Err(OAuthError::StateMismatch);
-        }
+        validate_state(&state, &self.csrf_state)?;
 
         // Create a request
         let mut request = self
@@ -130,16 +128,54 @@ impl AuthorizationCodeSession {
             .await
             .map_err(OAuthError::AuthCodeExchangeTokenFailed)?;
 
-        Ok(OAuthToken {
-            access_token: AccessToken::new(
-                token_response
-                    .access_token()
-                    .secret(),
-            ),
-            refresh_token: token_response
-                .refresh_token()
-                .map(|token| RefreshToken::new(token.secret())),
-            expires_in: token_response.expires_in(),
-        })
+        Ok(OAuthToken::from_response(
+            &token_response,
+        ))
+    }
+}
+
+/// Validates the CSRF state received by the redirect with the expected one.
+///
+/// This is useful to validate the state stored on the server side, e.g. in stateless backends.
+///
+/// ## NOTE
+/// This is only available when the feature "oauth" is enabled.
+///
+/// ## Arguments
+/// - `received` - The state received by the redirect.
+/// - `expected` - The state issued for the authorization request.
+///
+/// ## Errors
+/// - `OAuthError::StateMismatch` - The received state does not match the expected one.
+///
+/// ## Example
+/// ```
+/// use fars::oauth::validate_state;
+/// use fars::oauth::CsrfState;
+///
+/// let expected = CsrfState::new("stored-state");
+/// let received = CsrfState::new("stored-state");
+///
+/// validate_state(&received, &expected)?;
+/// ```
+pub fn validate_state(
+    received: &CsrfState,
+    expected: &CsrfState,
+) -> OAuthResult<()> {
+    let received = received.inner().as_bytes();
+    let expected = expected.inner().as_bytes();
+
+    // NOTE: Compare in constant time to avoid timing attacks.
+    let matched = received.len() == expected.len()
+        && received
+            .iter()
+            .zip(expected.iter())
+            .fold(0, |acc, (a, b)| acc | (a ^ b))
+            == 0;
+
+    if matched {
+        Ok(())
+    } else {
+        Err(OAuthError::StateMismatch)
     }
 }
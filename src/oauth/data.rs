@@ -1,7 +1,12 @@
+use crate::data::secret::mask_secret;
 use crate::oauth::OAuthError;
 use crate::oauth::OAuthResult;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::convert::Infallible;
 use std::env::VarError;
+use std::fmt::Display;
+use std::str::FromStr;
 
 /// The PKCE code challenge option.
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -155,7 +160,8 @@ impl RedirectUrl {
 }
 
 /// The scope of the OAuth 2.0.
-#[derive(Clone, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct OAuthScope {
     inner: oauth2::Scope,
 }
@@ -174,6 +180,52 @@ impl OAuthScope {
         &self.inner
     }
 
+    /// Returns the scope without leading and trailing whitespace.
+    ///
+    /// ## NOTE
+    /// The scope is case-sensitive by the [RFC 6749](https://datatracker.ietf.org/doc/html/rfc6749#section-3.3),
+    /// so the case is kept as is.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::OAuthScope;
+    ///
+    /// let scope = OAuthScope::new(" email ").normalize();
+    ///
+    /// assert_eq!(scope, OAuthScope::open_id_email());
+    /// ```
+    pub fn normalize(&self) -> Self {
+        Self::new(self.inner.trim())
+    }
+
+    /// Normalizes the scopes by [`OAuthScope::normalize`] and removes the empty and duplicated ones.
+    ///
+    /// ## Arguments
+    /// - `scopes` - The scopes to normalize.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::OAuthScope;
+    ///
+    /// let scopes = OAuthScope::normalize_set([
+    ///     OAuthScope::new("email"),
+    ///     OAuthScope::new("email "),
+    ///     OAuthScope::new(" "),
+    /// ]);
+    ///
+    /// assert_eq!(scopes.len(), 1);
+    /// ```
+    pub fn normalize_set<I>(scopes: I) -> HashSet<Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        scopes
+            .into_iter()
+            .map(|scope| scope.normalize())
+            .filter(|scope| !scope.inner.is_empty())
+            .collect()
+    }
+
     /// The "openid" scope for the OpenID Connect.
     pub fn open_id() -> Self {
         Self::new("openid")
@@ -203,6 +255,62 @@ impl OAuthScope {
     pub fn open_id_phone() -> Self {
         Self::new("phone")
     }
+
+    /// The standard set of scopes for the OpenID Connect: "openid", "email" and "profile".
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::OAuthScope;
+    ///
+    /// let scopes = OAuthScope::open_id_standard_set();
+    /// ```
+    pub fn open_id_standard_set() -> HashSet<Self> {
+        HashSet::from([
+            Self::open_id(),
+            Self::open_id_email(),
+            Self::open_id_profile(),
+        ])
+    }
+
+    /// The standard set of scopes for the OpenID Connect with extra scopes.
+    ///
+    /// ## Arguments
+    /// - `extra` - Extra scopes to add to the standard set.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::OAuthScope;
+    ///
+    /// let scopes = OAuthScope::open_id_with([
+    ///     OAuthScope::open_id_offline_access(),
+    /// ]);
+    /// ```
+    pub fn open_id_with<I>(extra: I) -> HashSet<Self>
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        let mut scopes = Self::open_id_standard_set();
+        scopes.extend(extra);
+        scopes
+    }
+}
+
+impl Display for OAuthScope {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        write!(f, "{}", self.inner.as_str())
+    }
+}
+
+impl FromStr for OAuthScope {
+    type Err = Infallible;
+
+    /// Parses the scope with normalizing by [`OAuthScope::normalize`].
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(Self::new(s.trim()))
+    }
 }
 
 /// The authorize request URL of the OAuth 2.0.
@@ -249,6 +357,10 @@ impl AuthorizationCode {
 }
 
 /// The CSRF state of the OAuth 2.0.
+///
+/// The state can be generated by your own, e.g. to embed application data such as a return URL,
+/// and stored on the server side to validate it after the redirect by [`crate::oauth::validate_state`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub struct CsrfState {
     inner: String,
 }
@@ -263,7 +375,17 @@ impl CsrfState {
         }
     }
 
-    pub(crate) fn inner(&self) -> &str {
+    /// Generates a new random state.
+    pub fn new_random() -> Self {
+        Self::new(
+            oauth2::CsrfToken::new_random()
+                .secret()
+                .to_owned(),
+        )
+    }
+
+    /// Returns the state as a string, e.g. to store it on the server side.
+    pub fn inner(&self) -> &str {
         &self.inner
     }
 }
@@ -288,7 +410,7 @@ pub struct VerificationUriComplete {
 
 impl VerificationUriComplete {
     pub fn inner(&self) -> &str {
-        &self.inner.secret()
+        self.inner.secret()
     }
 }
 
@@ -300,12 +422,15 @@ pub struct UserCode {
 
 impl UserCode {
     pub fn inner(&self) -> &str {
-        &self.inner.secret()
+        self.inner.secret()
     }
 }
 
 /// The access token of the OAuth 2.0.
-#[derive(Clone)]
+///
+/// The [`Debug`] representation is masked not to leak the raw token into logs.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct AccessToken {
     inner: String,
 }
@@ -320,13 +445,46 @@ impl AccessToken {
         }
     }
 
+    /// Returns the raw access token as a secret.
+    ///
+    /// ## NOTE
+    /// The raw access token is a credential of the user on the provider, so be careful not to leak it in logs.
+    /// Use [`AccessToken::masked`] to log it.
+    pub fn expose_secret(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns a masked representation of the access token that is safe to write in logs.
+    pub fn masked(&self) -> String {
+        mask_secret(&self.inner)
+    }
+
+    /// Returns the inner representation.
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use `expose_secret` to handle the raw token deliberately."
+    )]
     pub fn inner(&self) -> &str {
         &self.inner
     }
 }
 
+impl std::fmt::Debug for AccessToken {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_tuple("AccessToken")
+            .field(&self.masked())
+            .finish()
+    }
+}
+
 /// The refresh token of the OAuth 2.0.
-#[derive(Clone)]
+///
+/// The [`Debug`] representation is masked not to leak the raw token into logs.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct RefreshToken {
     inner: String,
 }
@@ -341,7 +499,37 @@ impl RefreshToken {
         }
     }
 
+    /// Returns the raw refresh token as a secret.
+    ///
+    /// ## NOTE
+    /// The raw refresh token is a credential of the user on the provider, so be careful not to leak it in logs.
+    /// Use [`RefreshToken::masked`] to log it.
+    pub fn expose_secret(&self) -> &str {
+        &self.inner
+    }
+
+    /// Returns a masked representation of the refresh token that is safe to write in logs.
+    pub fn masked(&self) -> String {
+        mask_secret(&self.inner)
+    }
+
+    /// Returns the inner representation.
+    #[deprecated(
+        since = "0.3.0",
+        note = "Use `expose_secret` to handle the raw token deliberately."
+    )]
     pub fn inner(&self) -> &str {
         &self.inner
     }
 }
+
+impl std::fmt::Debug for RefreshToken {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_tuple("RefreshToken")
+            .field(&self.masked())
+            .finish()
+    }
+}
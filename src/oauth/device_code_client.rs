@@ -1,4 +1,6 @@
 use std::collections::HashSet;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
 
 use oauth2::basic::BasicClient;
 
@@ -13,6 +15,8 @@ use crate::oauth::TokenEndpoint;
 use crate::oauth::UserCode;
 use crate::oauth::VerificationUri;
 use crate::oauth::VerificationUriComplete;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::RetryPolicy;
 
 /// A client for the Device Code grant type of the OAuth 2.0.
 ///
@@ -42,6 +46,13 @@ use crate::oauth::VerificationUriComplete;
 #[derive(Clone)]
 pub struct DeviceCodeClient {
     pub(crate) client: BasicClient,
+    /// The client secret sent in the request body to poll the token endpoint.
+    pub(crate) client_secret: Option<oauth2::ClientSecret>,
+    /// The HTTP client to poll the token endpoint.
+    pub(crate) http_client: reqwest::Client,
+    /// The policy to retry polling the token endpoint on transport errors.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) retry_policy: Option<Arc<RetryPolicy>>,
 }
 
 impl DeviceCodeClient {
@@ -83,7 +94,7 @@ impl DeviceCodeClient {
         // Create an internal OAuth client with settings.
         let client = BasicClient::new(
             client_id.inner().to_owned(),
-            client_secret,
+            client_secret.clone(),
             // NOTE: This is not used in the Device Code flow but required to the `oauth2` crate implementation.
             oauth2::AuthUrl::new("https://dummy.com/auth".to_string()).unwrap(),
             Some(
@@ -101,9 +112,48 @@ impl DeviceCodeClient {
 
         Ok(Self {
             client,
+            client_secret,
+            http_client: reqwest::Client::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            retry_policy: None,
         })
     }
 
+    /// Sets the policy to retry polling the token endpoint on transport errors in [`DeviceCodeSession::poll_exchange_token`].
+    ///
+    /// The polling is not retried by default.
+    ///
+    /// ## NOTE
+    /// This is not available on the `wasm32` targets because the polling loop is not available.
+    ///
+    /// ## Arguments
+    /// - `retry_policy` - The policy to retry polling.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::DeviceCodeClient;
+    /// use fars::oauth::ClientId;
+    /// use fars::oauth::DeviceEndpoint;
+    /// use fars::oauth::TokenEndpoint;
+    /// use fars::RetryPolicy;
+    ///
+    /// let client = DeviceCodeClient::new(
+    ///     ClientId::new("client-id"),
+    ///     None,
+    ///     DeviceEndpoint::new("https://example.com/device")?,
+    ///     TokenEndpoint::new("https://example.com/token")?,
+    /// )?
+    /// .with_retry_policy(RetryPolicy::new(tokio::time::sleep));
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_retry_policy(
+        mut self,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        self.retry_policy = Some(Arc::new(retry_policy));
+        self
+    }
+
     /// Requests authorization and generate a Device Code flow session with verification URI and user code.
     ///
     /// ## Arguments
@@ -133,8 +183,8 @@ impl DeviceCodeClient {
     ///     ]))
     ///     .await?;
     ///
-    ///     let verification_uri = session.verification_uri();
-    ///     let user_code = session.user_code();
+    ///     let verification_uri = session.verification_uri.inner();
+    ///     let user_code = session.user_code.inner();
     ///
     ///     // Display the verification URI and user code to the user.
     /// }
@@ -176,6 +226,7 @@ impl DeviceCodeClient {
             user_code: UserCode {
                 inner: response.user_code().clone(),
             },
+            interval: response.interval(),
             response,
             client: self.clone(),
         })
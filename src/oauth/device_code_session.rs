@@ -1,12 +1,14 @@
-use oauth2::{StandardDeviceAuthorizationResponse, TokenResponse};
+use oauth2::basic::BasicTokenResponse;
+use oauth2::{
+    DeviceCodeErrorResponse, DeviceCodeErrorResponseType,
+    StandardDeviceAuthorizationResponse,
+};
 use std::time::Duration;
 
-use crate::oauth::AccessToken;
 use crate::oauth::DeviceCodeClient;
 use crate::oauth::OAuthError;
 use crate::oauth::OAuthResult;
 use crate::oauth::OAuthToken;
-use crate::oauth::RefreshToken;
 use crate::oauth::UserCode;
 use crate::oauth::VerificationUri;
 use crate::oauth::VerificationUriComplete;
@@ -52,14 +54,50 @@ pub struct DeviceCodeSession {
     pub(crate) response: StandardDeviceAuthorizationResponse,
     /// The OAuth client.
     pub(crate) client: DeviceCodeClient,
+    /// The current interval to poll the token endpoint.
+    pub(crate) interval: Duration,
 }
 
 impl DeviceCodeSession {
+    /// Expiration of the device code received from the authorization response,
+    /// e.g. to time out a custom polling loop with [`DeviceCodeSession::exchange_token`].
+    pub fn expires_in(&self) -> Duration {
+        self.response.expires_in()
+    }
+
+    /// Current interval to poll the token endpoint.
+    ///
+    /// This is the interval received from the authorization response, defaults to 5 seconds when the response does not include it,
+    /// and it is increased while polling when the server requests to slow down.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
     /// Polls to token endpoint to exchange a device code into an access token.
     ///
+    /// This is the equivalent of the polling of [`crate::oauth::FacebookDeviceCodeClient`] for the standard Device Code grant type.
+    ///
+    /// ## NOTE
+    /// Polling starts with [`crate::oauth::DeviceCodeSession::interval`] and
+    /// the interval is increased by 5 seconds for subsequent polls when the server returns the `slow_down` error
+    /// along with [RFC 8628](https://datatracker.ietf.org/doc/html/rfc8628#section-3.5).
+    ///
+    /// The pending and slow down responses are handled in the polling loop,
+    /// so `OAuthError::ContinuePolling` and `OAuthError::SlowDown` are never returned.
+    ///
+    /// Use [`DeviceCodeSession::exchange_token`] to build a custom polling loop,
+    /// e.g. on the `wasm32` targets where this is not available because `std::time::Instant` is not available.
+    ///
     /// ## Arguments
-    /// - `sleep_fn` - The function to sleep.
-    /// - `timeout` - The timeout duration.
+    /// - `sleep_fn` - The function to sleep of your async runtime, e.g. `tokio::time::sleep`. See also [`crate::sleep`].
+    /// - `timeout` - The timeout duration, defaults to the expiration of the device code.
+    ///
+    /// ## Errors
+    /// - `OAuthError::Timeout` - The device code has expired or the polling has timed out.
+    /// - `OAuthError::DeviceExchangeTokenFailed` - Other error responses to exchange the device code.
+    /// - `OAuthError::ReqwestError` - Failed to send a request, after the retries if the retry policy is set by [`crate::oauth::DeviceCodeClient::with_retry_policy`].
+    /// - `OAuthError::JsonDeserializationFailed` - Failed to deserialize the response.
+    /// - `OAuthError::ManualApiCallFailed` - Other errors of the API.
     ///
     /// ## Example
     /// ```
@@ -79,14 +117,14 @@ impl DeviceCodeSession {
     ///         TokenEndpoint::new("https://example.com/token")?,
     ///     )?;
     ///
-    ///     let session = client.request_authorization(HashSet::from([
+    ///     let mut session = client.request_authorization(HashSet::from([
     ///         OAuthScope::new("scope1"),
     ///         OAuthScope::new("scope2"),
     ///     ]))
     ///     .await?;
     ///
-    ///     let verification_uri = session.verification_uri();
-    ///     let user_code = session.user_code();
+    ///     let verification_uri = session.verification_uri.inner();
+    ///     let user_code = session.user_code.inner();
     ///
     ///     // Display the verification URI and user code to the user.
     ///
@@ -96,8 +134,9 @@ impl DeviceCodeSession {
     ///     ).await?;
     /// }
     /// ```
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn poll_exchange_token<S, SF>(
-        self,
+        &mut self,
         sleep_fn: S,
         timeout: Option<Duration>,
     ) -> OAuthResult<OAuthToken>
@@ -105,32 +144,383 @@ impl DeviceCodeSession {
         S: Fn(Duration) -> SF,
         SF: std::future::Future<Output = ()>,
     {
-        // Create a request
-        let request = self
+        let timeout = timeout.unwrap_or(self.expires_in());
+
+        let retry_policy = self
             .client
+            .retry_policy
+            .clone();
+
+        let timer = std::time::Instant::now();
+        let mut retry = 0;
+
+        while timer.elapsed() < timeout {
+            match self.exchange_token().await {
+                // Success
+                | Ok(token) => return Ok(token),
+                // Continue polling
+                | Err(OAuthError::ContinuePolling) => {
+                    retry = 0;
+                    sleep_fn(self.interval).await;
+                },
+                // Continue polling with a longer interval
+                | Err(OAuthError::SlowDown) => {
+                    retry = 0;
+                    self.interval = slow_down_interval(self.interval);
+                    sleep_fn(self.interval).await;
+                },
+                // Retry on transport errors with the retry policy
+                | Err(error @ OAuthError::ReqwestError(_)) => {
+                    match &retry_policy {
+                        | Some(retry_policy)
+                            if retry_policy
+                                .wait_transient(retry)
+                                .await =>
+                        {
+                            retry += 1;
+                        },
+                        | _ => return Err(error),
+                    }
+                },
+                // Error
+                | Err(error) => return Err(error),
+            }
+        }
+
+        Err(OAuthError::Timeout)
+    }
+
+    /// Polls the token endpoint once to exchange the device code into an access token,
+    /// e.g. to build a custom polling loop with a UI.
+    ///
+    /// This is the equivalent of [`crate::oauth::FacebookDeviceCodeSession::exchange_token`] for the standard Device Code grant type.
+    ///
+    /// ## NOTE
+    /// The interval is not increased by this method, so please wait for [`DeviceCodeSession::interval`]
+    /// between polls and increase it by yourself on `OAuthError::SlowDown`.
+    ///
+    /// ## Errors
+    /// - `OAuthError::ContinuePolling` - The authorization is pending, so poll again after the interval.
+    /// - `OAuthError::SlowDown` - Polling too frequently, so poll again after a longer interval.
+    /// - `OAuthError::Timeout` - The device code has expired.
+    /// - `OAuthError::DeviceExchangeTokenFailed` - Other error responses to exchange the device code.
+    /// - `OAuthError::ReqwestError` - Failed to send a request.
+    /// - `OAuthError::JsonDeserializationFailed` - Failed to deserialize the response.
+    /// - `OAuthError::ManualApiCallFailed` - Other errors of the API.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::collections::HashSet;
+    /// use std::time::Duration;
+    /// use fars::oauth::DeviceCodeClient;
+    /// use fars::oauth::ClientId;
+    /// use fars::oauth::DeviceEndpoint;
+    /// use fars::oauth::TokenEndpoint;
+    /// use fars::oauth::OAuthError;
+    /// use fars::oauth::OAuthScope;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = DeviceCodeClient::new(
+    ///         ClientId::new("client-id"),
+    ///         None,
+    ///         DeviceEndpoint::new("https://example.com/device")?,
+    ///         TokenEndpoint::new("https://example.com/token")?,
+    ///     )?;
+    ///
+    ///     let session = client.request_authorization(HashSet::from([
+    ///         OAuthScope::new("scope1"),
+    ///     ]))
+    ///     .await?;
+    ///
+    ///     let mut interval = session.interval();
+    ///     let token = loop {
+    ///         match session.exchange_token().await {
+    ///             | Ok(token) => break token,
+    ///             | Err(OAuthError::ContinuePolling) => {},
+    ///             | Err(OAuthError::SlowDown) => interval += Duration::from_secs(5),
+    ///             | Err(error) => return Err(error.into()),
+    ///         }
+    ///
+    ///         // Update the UI, e.g. a countdown, here.
+    ///         tokio::time::sleep(interval).await;
+    ///     };
+    /// }
+    /// ```
+    pub async fn exchange_token(&self) -> OAuthResult<OAuthToken> {
+        let token_url = self
             .client
-            .exchange_device_access_token(&self.response);
-
-        // Exchange the authorization code into an access token.
-        let token_response = request
-            .request_async(
-                oauth2::reqwest::async_http_client,
-                sleep_fn,
-                timeout,
-            )
-            .await
-            .map_err(OAuthError::DeviceExchangeTokenFailed)?;
+            .client
+            .token_url()
+            .ok_or_else(|| {
+                OAuthError::InvalidTokenUrl("no token URL".to_string())
+            })?;
 
-        Ok(OAuthToken {
-            access_token: AccessToken::new(
-                token_response
-                    .access_token()
+        // NOTE: The client credentials are sent in the request body.
+        let mut params = vec![
+            (
+                "grant_type",
+                "urn:ietf:params:oauth:grant-type:device_code",
+            ),
+            (
+                "device_code",
+                self.response
+                    .device_code()
                     .secret(),
             ),
-            refresh_token: token_response
-                .refresh_token()
-                .map(|token| RefreshToken::new(token.secret())),
-            expires_in: token_response.expires_in(),
-        })
+            (
+                "client_id",
+                self.client.client.client_id(),
+            ),
+        ];
+        if let Some(client_secret) = &self.client.client_secret {
+            params.push(("client_secret", client_secret.secret()));
+        }
+
+        let response = self
+            .client
+            .http_client
+            .post(token_url.url().clone())
+            .header(
+                reqwest::header::ACCEPT,
+                "application/json",
+            )
+            .form(&params)
+            .send()
+            .await
+            .map_err(OAuthError::ReqwestError)?;
+
+        let status = response.status();
+
+        let response_text = response
+            .text()
+            .await
+            .map_err(OAuthError::ReqwestError)?;
+
+        if status.is_success() {
+            return serde_json::from_str::<BasicTokenResponse>(&response_text)
+                .map(|response| OAuthToken::from_response(&response))
+                .map_err(|error| {
+                    OAuthError::JsonDeserializationFailed(error, response_text)
+                });
+        }
+
+        // NOTE: Pending states are returned as error responses.
+        match serde_json::from_str::<DeviceCodeErrorResponse>(&response_text) {
+            | Ok(error_response) => Err(polling_error(&error_response)
+                .unwrap_or_else(|| {
+                    OAuthError::DeviceExchangeTokenFailed(
+                        oauth2::RequestTokenError::ServerResponse(
+                            error_response,
+                        ),
+                    )
+                })),
+            | Err(_) => Err(OAuthError::ManualApiCallFailed(
+                status,
+                response_text,
+            )),
+        }
+    }
+}
+
+/// The increment of the polling interval when the server requests to slow down,
+/// along with [RFC 8628](https://datatracker.ietf.org/doc/html/rfc8628#section-3.5).
+#[cfg(not(target_arch = "wasm32"))]
+const SLOW_DOWN_INTERVAL_INCREMENT: Duration = Duration::from_secs(5);
+
+/// Increases the polling interval for a slow down request.
+#[cfg(not(target_arch = "wasm32"))]
+fn slow_down_interval(interval: Duration) -> Duration {
+    interval + SLOW_DOWN_INTERVAL_INCREMENT
+}
+
+/// Maps an error response of the token endpoint to an error to continue polling,
+/// along with [RFC 8628](https://datatracker.ietf.org/doc/html/rfc8628#section-3.5).
+fn polling_error(
+    error_response: &DeviceCodeErrorResponse
+) -> Option<OAuthError> {
+    match error_response.error() {
+        | DeviceCodeErrorResponseType::AuthorizationPending => {
+            Some(OAuthError::ContinuePolling)
+        },
+        | DeviceCodeErrorResponseType::SlowDown => Some(OAuthError::SlowDown),
+        | DeviceCodeErrorResponseType::ExpiredToken => {
+            Some(OAuthError::Timeout)
+        },
+        | _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use axum::routing::post;
+    use axum::Json;
+    use axum::Router;
+
+    use super::*;
+    use crate::oauth::ClientId;
+    use crate::oauth::DeviceEndpoint;
+    use crate::oauth::TokenEndpoint;
+
+    /// Serves a device endpoint and a token endpoint that returns the error once before issuing a token.
+    async fn serve_error_once(error: &'static str) -> String {
+        let polls = Arc::new(AtomicUsize::new(0));
+        let app = Router::new()
+            .route(
+                "/device",
+                post(|| async {
+                    Json(serde_json::json!({
+                        "device_code": "device-code",
+                        "user_code": "user-code",
+                        "verification_uri": "https://example.com/verify",
+                        "expires_in": 600,
+                        "interval": 1,
+                    }))
+                }),
+            )
+            .route(
+                "/token",
+                post(move || async move {
+                    match polls.fetch_add(1, Ordering::SeqCst) {
+                        | 0 => (
+                            axum::http::StatusCode::BAD_REQUEST,
+                            Json(serde_json::json!({
+                                "error": error,
+                            })),
+                        ),
+                        | _ => (
+                            axum::http::StatusCode::OK,
+                            Json(serde_json::json!({
+                                "access_token": "access-token",
+                                "token_type": "bearer",
+                                "expires_in": 3600,
+                            })),
+                        ),
+                    }
+                }),
+            );
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+            .await
+            .unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, app)
+                .await
+                .unwrap();
+        });
+
+        format!("http://{}", address)
+    }
+
+    /// Requests authorization to a token endpoint that returns the error once before issuing a token.
+    async fn request_session(error: &'static str) -> DeviceCodeSession {
+        let base_url = serve_error_once(error).await;
+        let client = DeviceCodeClient::new(
+            ClientId::new("client-id"),
+            None,
+            DeviceEndpoint::new(format!("{}/device", base_url)).unwrap(),
+            TokenEndpoint::new(format!("{}/token", base_url)).unwrap(),
+        )
+        .unwrap();
+
+        client
+            .request_authorization(HashSet::new())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn exchange_token_polls_once() {
+        let session = request_session("authorization_pending").await;
+
+        assert!(matches!(
+            session.exchange_token().await,
+            Err(OAuthError::ContinuePolling)
+        ));
+        assert_eq!(
+            session.interval(),
+            Duration::from_secs(1)
+        );
+
+        let token = session
+            .exchange_token()
+            .await
+            .unwrap();
+        assert_eq!(
+            token
+                .access_token()
+                .expose_secret(),
+            "access-token"
+        );
+    }
+
+    #[tokio::test]
+    async fn poll_exchange_token_increases_interval_on_slow_down() {
+        let mut session = request_session("slow_down").await;
+        assert_eq!(
+            session.interval(),
+            Duration::from_secs(1)
+        );
+
+        let sleeps = Arc::new(Mutex::new(Vec::new()));
+        let token = session
+            .poll_exchange_token(
+                |duration| {
+                    sleeps
+                        .lock()
+                        .unwrap()
+                        .push(duration);
+                    async {}
+                },
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            token
+                .access_token()
+                .expose_secret(),
+            "access-token"
+        );
+        assert_eq!(
+            session.interval(),
+            Duration::from_secs(6)
+        );
+        assert_eq!(
+            *sleeps.lock().unwrap(),
+            vec![Duration::from_secs(
+                6
+            )]
+        );
+    }
+
+    #[test]
+    fn polling_error_maps_pending_states() {
+        let error_response = |error: &str| {
+            serde_json::from_value::<DeviceCodeErrorResponse>(
+                serde_json::json!({ "error": error }),
+            )
+            .unwrap()
+        };
+
+        assert!(matches!(
+            polling_error(&error_response("authorization_pending")),
+            Some(OAuthError::ContinuePolling)
+        ));
+        assert!(matches!(
+            polling_error(&error_response("slow_down")),
+            Some(OAuthError::SlowDown)
+        ));
+        assert!(matches!(
+            polling_error(&error_response("expired_token")),
+            Some(OAuthError::Timeout)
+        ));
+        assert!(polling_error(&error_response("access_denied")).is_none());
     }
 }
@@ -61,10 +61,23 @@ pub enum OAuthError {
     /// Manual API call failed.
     #[error("Manual API call failed: {0:?}, {1:?}")]
     ManualApiCallFailed(reqwest::StatusCode, String),
-    /// Continue polling.
+    /// The authorization is pending on the Device Code grant type, so continue polling with the current interval.
+    ///
+    /// This is returned by a single poll, e.g. [`crate::oauth::DeviceCodeSession::exchange_token`] and [`crate::oauth::FacebookDeviceCodeSession::exchange_token`],
+    /// to build a custom polling loop, and never returned by the polling loops of this crate,
+    /// e.g. [`crate::oauth::FacebookDeviceCodeSession::poll_exchange_token`] and [`crate::oauth::DeviceCodeSession::poll_exchange_token`].
     #[error("Continue polling")]
     ContinuePolling,
-    /// Timeout.
+    /// The server requests to slow down polling on the Device Code grant type,
+    /// so continue polling with a longer interval, e.g. increased by 5 seconds along with [RFC 8628](https://datatracker.ietf.org/doc/html/rfc8628#section-3.5).
+    ///
+    /// This is returned by a single poll as the same as [`OAuthError::ContinuePolling`].
+    #[error("Slow down polling")]
+    SlowDown,
+    /// The device code has expired or the polling has timed out on the Device Code grant type,
+    /// so request a new authorization to retry.
+    ///
+    /// This is returned by both a single poll and the polling loops of this crate.
     #[error("Timeout")]
     Timeout,
 }
@@ -4,6 +4,7 @@ use crate::oauth::AuthorizationCodeClient;
 use crate::oauth::AuthorizationCodeSession;
 use crate::oauth::AuthorizeEndpoint;
 use crate::oauth::ClientId;
+use crate::oauth::CsrfState;
 use crate::oauth::OAuthResult;
 use crate::oauth::OAuthScope;
 use crate::oauth::PkceOption;
@@ -50,7 +51,7 @@ use crate::oauth::TokenEndpoint;
 ///     CsrfState::new(state),
 /// )?;
 ///
-/// let access_token = token.access_token.inner();
+/// let access_token = token.access_token().expose_secret();
 /// ```
 pub struct FacebookAuthorizationCodeClient {
     inner: AuthorizationCodeClient,
@@ -127,4 +128,43 @@ impl FacebookAuthorizationCodeClient {
         self.inner
             .generate_session(scopes)
     }
+
+    /// Generates a new authorization session with the specified CSRF state.
+    ///
+    /// This is useful to embed application data into the state
+    /// or to store the state on the server side for multi-instance deployments.
+    ///
+    /// ## Arguments
+    /// - `scopes` - The scopes to request authorization defined at [here](https://developers.facebook.com/docs/permissions).
+    /// - `state` - The CSRF state of the authorization request, it must be unguessable.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::FacebookAuthorizationCodeClient;
+    /// use fars::oauth::ClientId;
+    /// use fars::oauth::RedirectUrl;
+    /// use std::collections::HashSet;
+    /// use fars::oauth::OAuthScope;
+    /// use fars::oauth::CsrfState;
+    ///
+    /// let client = FacebookAuthorizationCodeClient::new(
+    ///     ClientId::new("client-id"),
+    ///     RedirectUrl::new("https://my.app.com/callback")?,
+    /// )?;
+    ///
+    /// let session = client.generate_authorization_session_with_state(
+    ///     HashSet::from([OAuthScope::open_id_email()]),
+    ///     CsrfState::new_random(),
+    /// );
+    ///
+    /// let authorize_url = session.authorize_url.inner();
+    /// ```
+    pub fn generate_authorization_session_with_state(
+        &self,
+        scopes: HashSet<OAuthScope>,
+        state: CsrfState,
+    ) -> AuthorizationCodeSession {
+        self.inner
+            .generate_session_with_state(scopes, state)
+    }
 }
@@ -3,14 +3,17 @@
 //! this client is implemented as a custom client, not using the `oauth2` crate.
 
 use std::collections::HashSet;
-use std::future::Future;
-use std::time::{Duration, Instant};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+use std::time::Duration;
 
 use crate::oauth::AccessToken;
 use crate::oauth::OAuthError;
 use crate::oauth::OAuthResult;
 use crate::oauth::OAuthScope;
 use crate::oauth::OAuthToken;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::RetryPolicy;
 
 /// A client for the Facebook's Device Code grant type of the OAuth 2.0.
 ///
@@ -38,7 +41,7 @@ use crate::oauth::OAuthToken;
 ///          "client-token".to_string(),
 ///     )?;
 ///
-///     let session = client.request_authorization(HashSet::from([
+///     let mut session = client.request_authorization(HashSet::from([
 ///        OAuthScope::open_id_email(),
 ///     ]))
 ///     .await?;
@@ -54,13 +57,16 @@ use crate::oauth::OAuthToken;
 ///         None,
 ///     ).await?;
 ///
-///     let access_token = token.access_token().inner();
+///     let access_token = token.access_token().expose_secret();
 /// }
 /// ```
 #[derive(Clone)]
 pub struct FacebookDeviceCodeClient {
     client: reqwest::Client,
     access_token: String, // NOTE: Not the access token of the OAuth 2.0.
+    /// The policy to retry polling the token endpoint on transport errors.
+    #[cfg(not(target_arch = "wasm32"))]
+    retry_policy: Option<Arc<RetryPolicy>>,
 }
 
 impl FacebookDeviceCodeClient {
@@ -85,9 +91,41 @@ impl FacebookDeviceCodeClient {
         Ok(Self {
             client: reqwest::Client::new(),
             access_token: format!("{}|{}", app_id, client_token),
+            #[cfg(not(target_arch = "wasm32"))]
+            retry_policy: None,
         })
     }
 
+    /// Sets the policy to retry polling the token endpoint on transport errors in [`FacebookDeviceCodeSession::poll_exchange_token`].
+    ///
+    /// The polling is not retried by default.
+    ///
+    /// ## NOTE
+    /// This is not available on the `wasm32` targets because the polling loop is not available.
+    ///
+    /// ## Arguments
+    /// - `retry_policy` - The policy to retry polling.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::FacebookDeviceCodeClient;
+    /// use fars::RetryPolicy;
+    ///
+    /// let client = FacebookDeviceCodeClient::new(
+    ///     "app-id".to_string(),
+    ///     "client-token".to_string(),
+    /// )?
+    /// .with_retry_policy(RetryPolicy::new(tokio::time::sleep));
+    /// ```
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_retry_policy(
+        mut self,
+        retry_policy: RetryPolicy,
+    ) -> Self {
+        self.retry_policy = Some(Arc::new(retry_policy));
+        self
+    }
+
     /// Requests authorization and generates a new session of the Facebook's Device Code grant type of the OAuth 2.0.
     ///
     /// See also [the official guide](https://developers.facebook.com/docs/facebook-login/for-devices#tech-step1).
@@ -166,6 +204,7 @@ impl FacebookDeviceCodeClient {
 
             Ok(FacebookDeviceCodeSession {
                 client: self.clone(),
+                interval: Duration::from_secs(response.interval),
                 response,
             })
         } else {
@@ -190,6 +229,7 @@ struct FacebookDeviceCodeResponse {
 pub struct FacebookDeviceCodeSession {
     client: FacebookDeviceCodeClient,
     response: FacebookDeviceCodeResponse,
+    interval: Duration,
 }
 
 impl FacebookDeviceCodeSession {
@@ -203,12 +243,38 @@ impl FacebookDeviceCodeSession {
         &self.response.user_code
     }
 
+    /// Expiration of the device code received from the authorization response,
+    /// e.g. to time out a custom polling loop with [`FacebookDeviceCodeSession::exchange_token`].
+    pub fn expires_in(&self) -> Duration {
+        Duration::from_secs(self.response.expires_in)
+    }
+
+    /// Current interval to poll the token endpoint.
+    ///
+    /// This is the interval received from the authorization response and it is increased while polling when the server requests to slow down.
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
     /// Polls the token endpoint and exchanges the device code into an access token.
     ///
     /// See also [the official guide](https://developers.facebook.com/docs/facebook-login/for-devices#tech-step3).
     ///
+    /// ## NOTE
+    /// When the server requests to slow down polling, the interval is increased by 5 seconds for subsequent polls.
+    ///
+    /// Use [`FacebookDeviceCodeSession::exchange_token`] to build a custom polling loop,
+    /// e.g. on the `wasm32` targets where this is not available because `std::time::Instant` is not available.
+    ///
     /// ## Arguments
-    /// - `interval_fn` - A function to sleep for the interval time, e.g. `tokio::time::sleep`.
+    /// - `interval_fn` - A function to sleep for the interval time of your async runtime, e.g. `tokio::time::sleep`. See also [`crate::sleep`].
+    /// - `timeout` - The timeout duration, defaults to the expiration of the device code.
+    ///
+    /// ## Errors
+    /// - `OAuthError::Timeout` - The device code has expired or the polling has timed out.
+    /// - `OAuthError::ReqwestError` - Failed to send a request, after the retries if the retry policy is set by [`FacebookDeviceCodeClient::with_retry_policy`].
+    /// - `OAuthError::JsonDeserializationFailed` - Failed to deserialize the response.
+    /// - `OAuthError::ManualApiCallFailed` - Other errors of the API.
     ///
     /// ## Example
     /// ```
@@ -223,7 +289,7 @@ impl FacebookDeviceCodeSession {
     ///          "client-token".to_string(),
     ///     )?;
     ///
-    ///     let session = client.request_authorization(HashSet::from([
+    ///     let mut session = client.request_authorization(HashSet::from([
     ///        OAuthScope::open_id_email(),
     ///     ]))
     ///     .await?;
@@ -240,21 +306,25 @@ impl FacebookDeviceCodeSession {
     ///     ).await?;
     /// }
     /// ```
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn poll_exchange_token<I, IF>(
-        self,
+        &mut self,
         interval_fn: I,
         timeout: Option<Duration>,
     ) -> OAuthResult<OAuthToken>
     where
         I: Fn(Duration) -> IF,
-        IF: Future<Output = ()>,
+        IF: std::future::Future<Output = ()>,
     {
-        let timeout = timeout.unwrap_or(Duration::from_secs(
-            self.response.expires_in,
-        ));
-        let interval = Duration::from_secs(self.response.interval);
+        let timeout = timeout.unwrap_or(self.expires_in());
+
+        let retry_policy = self
+            .client
+            .retry_policy
+            .clone();
 
-        let timer = Instant::now();
+        let timer = std::time::Instant::now();
+        let mut retry = 0;
 
         while timer.elapsed() < timeout {
             match self.exchange_token().await {
@@ -262,7 +332,27 @@ impl FacebookDeviceCodeSession {
                 | Ok(token) => return Ok(token),
                 // Continue polling
                 | Err(OAuthError::ContinuePolling) => {
-                    interval_fn(interval).await;
+                    retry = 0;
+                    interval_fn(self.interval).await;
+                },
+                // Continue polling with a longer interval
+                | Err(OAuthError::SlowDown) => {
+                    retry = 0;
+                    self.interval = slow_down_interval(self.interval);
+                    interval_fn(self.interval).await;
+                },
+                // Retry on transport errors with the retry policy
+                | Err(error @ OAuthError::ReqwestError(_)) => {
+                    match &retry_policy {
+                        | Some(retry_policy)
+                            if retry_policy
+                                .wait_transient(retry)
+                                .await =>
+                        {
+                            retry += 1;
+                        },
+                        | _ => return Err(error),
+                    }
                 },
                 // Error
                 | Err(error) => return Err(error),
@@ -272,7 +362,58 @@ impl FacebookDeviceCodeSession {
         Err(OAuthError::Timeout)
     }
 
-    async fn exchange_token(&self) -> OAuthResult<OAuthToken> {
+    /// Polls the token endpoint once to exchange the device code into an access token,
+    /// e.g. to build a custom polling loop with a UI.
+    ///
+    /// See also [the official guide](https://developers.facebook.com/docs/facebook-login/for-devices#tech-step3).
+    ///
+    /// ## NOTE
+    /// The interval is not increased by this method, so please wait for [`FacebookDeviceCodeSession::interval`]
+    /// between polls and increase it by yourself on `OAuthError::SlowDown`.
+    ///
+    /// ## Errors
+    /// - `OAuthError::ContinuePolling` - The authorization is pending, so poll again after the interval.
+    /// - `OAuthError::SlowDown` - Polling too frequently, so poll again after a longer interval.
+    /// - `OAuthError::Timeout` - The device code has expired.
+    /// - `OAuthError::ReqwestError` - Failed to send a request.
+    /// - `OAuthError::JsonDeserializationFailed` - Failed to deserialize the response.
+    /// - `OAuthError::ManualApiCallFailed` - Other errors of the API.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::FacebookDeviceCodeClient;
+    /// use fars::oauth::OAuthError;
+    /// use fars::oauth::OAuthScope;
+    /// use std::collections::HashSet;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> anyhow::Result<()> {
+    ///     let client = FacebookDeviceCodeClient::new(
+    ///          "app-id".to_string(),
+    ///          "client-token".to_string(),
+    ///     )?;
+    ///
+    ///     let session = client.request_authorization(HashSet::from([
+    ///        OAuthScope::open_id_email(),
+    ///     ]))
+    ///     .await?;
+    ///
+    ///     let mut interval = session.interval();
+    ///     let token = loop {
+    ///         match session.exchange_token().await {
+    ///             | Ok(token) => break token,
+    ///             | Err(OAuthError::ContinuePolling) => {},
+    ///             | Err(OAuthError::SlowDown) => interval += Duration::from_secs(5),
+    ///             | Err(error) => return Err(error.into()),
+    ///         }
+    ///
+    ///         // Update the UI, e.g. a countdown, here.
+    ///         tokio::time::sleep(interval).await;
+    ///     };
+    /// }
+    /// ```
+    pub async fn exchange_token(&self) -> OAuthResult<OAuthToken> {
         let endpoint = "https://graph.facebook.com/v2.6/device/login_status";
         let url = format!(
             "{}?access_token={}&code={}",
@@ -295,60 +436,78 @@ impl FacebookDeviceCodeSession {
             .map_err(OAuthError::ReqwestError)?;
 
         if status.is_success() {
-            match serde_json::from_str::<FacebookTokenResponse>(&response_text)
-                .map_err(|error| {
-                    OAuthError::JsonDeserializationFailed(
-                        error,
-                        response_text.clone(),
-                    )
-                }) {
-                | Ok(response) => {
-                    return Ok(OAuthToken {
-                        access_token: AccessToken::new(response.access_token),
-                        refresh_token: None,
-                        expires_in: Some(Duration::from_secs(
-                            response.expires_in,
-                        )),
-                    })
-                },
-                | Err(_) => {
-                    let error_response = serde_json::from_str::<
-                        FacebookTokenErrorResponse,
-                    >(&response_text)
-                    .map_err(|error| {
-                        OAuthError::JsonDeserializationFailed(
-                            error,
-                            response_text.clone(),
-                        )
-                    })?;
-
-                    return match error_response
-                        .error
-                        .error_subcode
-                    {
-                        // Continue polling.
-                        | 1349174 | 1349172 => Err(OAuthError::ContinuePolling),
-                        // Other errors.
-                        | _ => Err(OAuthError::ManualApiCallFailed(
-                            status,
-                            response_text,
-                        )),
-                    };
-                },
+            if let Ok(response) =
+                serde_json::from_str::<FacebookTokenResponse>(&response_text)
+            {
+                return Ok(OAuthToken::new(
+                    AccessToken::new(response.access_token),
+                    None,
+                    Some(Duration::from_secs(response.expires_in)),
+                    Vec::new(),
+                    response
+                        .token_type
+                        .unwrap_or_else(|| "bearer".to_string()),
+                ));
             }
-        } else {
-            Err(OAuthError::ManualApiCallFailed(
-                status,
+        }
+
+        // NOTE: Pending states are returned as error responses.
+        match serde_json::from_str::<FacebookTokenErrorResponse>(&response_text)
+        {
+            | Ok(error_response) => match polling_error(
+                error_response
+                    .error
+                    .error_subcode,
+            ) {
+                | Some(error) => Err(error),
+                | None => Err(OAuthError::ManualApiCallFailed(
+                    status,
+                    response_text,
+                )),
+            },
+            | Err(_) if !status.is_success() => Err(
+                OAuthError::ManualApiCallFailed(status, response_text),
+            ),
+            | Err(error) => Err(OAuthError::JsonDeserializationFailed(
+                error,
                 response_text,
-            ))
+            )),
         }
     }
 }
 
+/// The increment of the polling interval when the server requests to slow down,
+/// along with [RFC 8628](https://datatracker.ietf.org/doc/html/rfc8628#section-3.5).
+#[cfg(not(target_arch = "wasm32"))]
+const SLOW_DOWN_INTERVAL_INCREMENT: Duration = Duration::from_secs(5);
+
+/// Increases the polling interval for a slow down request.
+#[cfg(not(target_arch = "wasm32"))]
+fn slow_down_interval(interval: Duration) -> Duration {
+    interval + SLOW_DOWN_INTERVAL_INCREMENT
+}
+
+/// Maps an error subcode of the Facebook's login status API to an error to continue polling.
+///
+/// See also [the official guide](https://developers.facebook.com/docs/facebook-login/for-devices#tech-step3).
+fn polling_error(error_subcode: u64) -> Option<OAuthError> {
+    match error_subcode {
+        // Authorization pending.
+        | 1349174 => Some(OAuthError::ContinuePolling),
+        // Polling too frequently.
+        | 1349172 => Some(OAuthError::SlowDown),
+        // Device code has expired.
+        | 1349152 => Some(OAuthError::Timeout),
+        // Other errors.
+        | _ => None,
+    }
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 struct FacebookTokenResponse {
     access_token: String,
     expires_in: u64,
+    token_type: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -358,9 +517,5 @@ struct FacebookTokenErrorResponse {
 
 #[derive(Debug, Clone, serde::Deserialize)]
 struct FacebookTokenError {
-    message: String,
-    code: u64,
     error_subcode: u64,
-    error_user_title: String,
-    error_user_msg: String,
 }
@@ -5,6 +5,7 @@ use crate::oauth::AuthorizationCodeSession;
 use crate::oauth::AuthorizeEndpoint;
 use crate::oauth::ClientId;
 use crate::oauth::ClientSecret;
+use crate::oauth::CsrfState;
 use crate::oauth::OAuthResult;
 use crate::oauth::OAuthScope;
 use crate::oauth::PkceOption;
@@ -63,7 +64,7 @@ use crate::oauth::TokenEndpoint;
 ///     CsrfState::new(state),
 /// )?;
 ///
-/// let access_token = token.access_token.inner();
+/// let access_token = token.access_token().expose_secret();
 /// ```
 pub struct GitHubAuthorizationCodeClient {
     inner: AuthorizationCodeClient,
@@ -143,4 +144,48 @@ impl GitHubAuthorizationCodeClient {
         self.inner
             .generate_session(scopes)
     }
+
+    /// Generates a new authorization session with the specified CSRF state.
+    ///
+    /// This is useful to embed application data into the state
+    /// or to store the state on the server side for multi-instance deployments.
+    ///
+    /// ## Arguments
+    /// - `scopes` - The scopes to request authorization defined at [here](https://docs.github.com/en/apps/oauth-apps/building-oauth-apps/scopes-for-oauth-apps).
+    /// - `state` - The CSRF state of the authorization request, it must be unguessable.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::GitHubAuthorizationCodeClient;
+    /// use fars::oauth::ClientId;
+    /// use fars::oauth::ClientSecret;
+    /// use fars::oauth::RedirectUrl;
+    /// use fars::oauth::OAuthScope;
+    /// use fars::oauth::CsrfState;
+    /// use std::collections::HashSet;
+    ///
+    /// let client = GitHubAuthorizationCodeClient::new(
+    ///     ClientId::new("client-id"),
+    ///     ClientSecret::new("client-secret"),
+    ///     RedirectUrl::new("https://my.app.com/callback")?,
+    /// )?;
+    ///
+    /// let session = client.generate_authorization_session_with_state(
+    ///     HashSet::from([
+    ///         OAuthScope::new("read:user"),
+    ///         OAuthScope::new("user:email"),
+    ///     ]),
+    ///     CsrfState::new_random(),
+    /// );
+    ///
+    /// let authorize_url = session.authorize_url.inner();
+    /// ```
+    pub fn generate_authorization_session_with_state(
+        &self,
+        scopes: HashSet<OAuthScope>,
+        state: CsrfState,
+    ) -> AuthorizationCodeSession {
+        self.inner
+            .generate_session_with_state(scopes, state)
+    }
 }
@@ -5,6 +5,7 @@ use crate::oauth::AuthorizationCodeSession;
 use crate::oauth::AuthorizeEndpoint;
 use crate::oauth::ClientId;
 use crate::oauth::ClientSecret;
+use crate::oauth::CsrfState;
 use crate::oauth::OAuthResult;
 use crate::oauth::OAuthScope;
 use crate::oauth::PkceOption;
@@ -62,7 +63,7 @@ use crate::oauth::TokenEndpoint;
 ///     CsrfState::new(state),
 /// )?;
 ///
-/// let access_token = token.access_token.inner();
+/// let access_token = token.access_token().expose_secret();
 /// ```
 pub struct GoogleAuthorizationCodeClient {
     inner: AuthorizationCodeClient,
@@ -147,4 +148,51 @@ impl GoogleAuthorizationCodeClient {
         self.inner
             .generate_session(scopes)
     }
+
+    /// Generates a new session of the Google's Authorization Code grant type of the OAuth 2.0 with the specified CSRF state.
+    ///
+    /// This is useful to embed application data into the state
+    /// or to store the state on the server side for multi-instance deployments.
+    ///
+    /// ## Arguments
+    /// - `scopes` - Scopes to request authorization defined at [here](https://developers.google.com/identity/protocols/oauth2/scopes).
+    /// - `state` - The CSRF state of the authorization request, it must be unguessable.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::GoogleAuthorizationCodeClient;
+    /// use fars::oauth::ClientId;
+    /// use fars::oauth::ClientSecret;
+    /// use fars::oauth::RedirectUrl;
+    /// use fars::oauth::OAuthScope;
+    /// use fars::oauth::CsrfState;
+    /// use std::collections::HashSet;
+    ///
+    /// let client = GoogleAuthorizationCodeClient::new(
+    ///     ClientId::new("client-id"),
+    ///     ClientSecret::new("client-secret"),
+    ///     RedirectUrl::new("https://my.app.com/callback")?,
+    /// )?;
+    ///
+    /// let session = client.generate_session_with_state(
+    ///     HashSet::from([
+    ///         OAuthScope::open_id(),
+    ///         OAuthScope::open_id_email(),
+    ///         OAuthScope::open_id_profile(),
+    ///     ]),
+    ///     CsrfState::new_random(),
+    /// );
+    ///
+    /// let authorize_url = session.authorize_url.inner();
+    ///
+    /// // Redirect the user to the authorize URL and get the code and state from URL.
+    /// ```
+    pub fn generate_session_with_state(
+        &self,
+        scopes: HashSet<OAuthScope>,
+        state: CsrfState,
+    ) -> AuthorizationCodeSession {
+        self.inner
+            .generate_session_with_state(scopes, state)
+    }
 }
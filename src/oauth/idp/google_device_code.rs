@@ -40,7 +40,7 @@ use crate::oauth::TokenEndpoint;
 ///         ClientSecret::new("client-secret"),
 ///     )?;
 ///
-///     let session = client.request_authorization(HashSet::from([
+///     let mut session = client.request_authorization(HashSet::from([
 ///        OAuthScope::open_id(),
 ///        OAuthScope::open_id_email(),
 ///        OAuthScope::open_id_profile()
@@ -58,7 +58,7 @@ use crate::oauth::TokenEndpoint;
 ///         None,
 ///     ).await?;
 ///
-///     let access_token = token.access_token().inner();
+///     let access_token = token.access_token().expose_secret();
 /// }
 /// ```
 pub struct GoogleDeviceCodeClient {
@@ -3,6 +3,7 @@ use std::collections::HashSet;
 use crate::oauth::AuthorizationCodeClient;
 use crate::oauth::AuthorizationCodeSession;
 use crate::oauth::ClientId;
+use crate::oauth::CsrfState;
 use crate::oauth::MicrosoftIssuer;
 use crate::oauth::OAuthResult;
 use crate::oauth::OAuthScope;
@@ -57,7 +58,7 @@ use crate::oauth::{AuthorizeEndpoint, ClientSecret};
 ///     CsrfState::new(state),
 /// )?;
 ///
-/// let access_token = token.access_token.inner();
+/// let access_token = token.access_token().expose_secret();
 /// ```
 pub struct MicrosoftAuthorizationCodeClient {
     inner: AuthorizationCodeClient,
@@ -145,4 +146,46 @@ impl MicrosoftAuthorizationCodeClient {
         self.inner
             .generate_session(scopes)
     }
+
+    /// Generates a new authorization session with the specified CSRF state.
+    ///
+    /// This is useful to embed application data into the state
+    /// or to store the state on the server side for multi-instance deployments.
+    ///
+    /// ## Arguments
+    /// - `scopes` - The scopes to request authorization defined at [here](https://learn.microsoft.com/en-us/entra/identity-platform/permissions-consent-overview).
+    /// - `state` - The CSRF state of the authorization request, it must be unguessable.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::MicrosoftAuthorizationCodeClient;
+    /// use fars::oauth::ClientId;
+    /// use fars::oauth::RedirectUrl;
+    /// use fars::oauth::MicrosoftIssuer;
+    /// use std::collections::HashSet;
+    /// use fars::oauth::OAuthScope;
+    /// use fars::oauth::CsrfState;
+    ///
+    /// let client = MicrosoftAuthorizationCodeClient::new(
+    ///     ClientId::new("client-id"),
+    ///     None,
+    ///     RedirectUrl::new("https://my.app.com/callback")?,
+    ///     MicrosoftIssuer::Common,
+    /// )?;
+    ///
+    /// let session = client.generate_authorization_session_with_state(
+    ///     HashSet::from([OAuthScope::open_id()]),
+    ///     CsrfState::new_random(),
+    /// );
+    ///
+    /// let authorize_url = session.authorize_url.inner();
+    /// ```
+    pub fn generate_authorization_session_with_state(
+        &self,
+        scopes: HashSet<OAuthScope>,
+        state: CsrfState,
+    ) -> AuthorizationCodeSession {
+        self.inner
+            .generate_session_with_state(scopes, state)
+    }
 }
@@ -4,6 +4,7 @@ use crate::oauth::AuthorizationCodeClient;
 use crate::oauth::AuthorizationCodeSession;
 use crate::oauth::AuthorizeEndpoint;
 use crate::oauth::ClientId;
+use crate::oauth::CsrfState;
 use crate::oauth::OAuthResult;
 use crate::oauth::OAuthScope;
 use crate::oauth::PkceOption;
@@ -19,6 +20,8 @@ use crate::oauth::TokenEndpoint;
 ///
 /// ## WARNING
 /// Twitter OAuth 2.0 Access Token may not be supported by the Firebase Auth.
+/// The Firebase Auth officially supports the OAuth 1.0a credentials of the Twitter,
+/// please use [`crate::IdpPostBody::twitter`] with the access token and the token secret in that case.
 ///
 /// ## Recommended use cases
 /// - Confidential clients (Web-Server apps) and public clients (Web-Client, Mobile and Desktop apps) with PKCE.
@@ -53,7 +56,7 @@ use crate::oauth::TokenEndpoint;
 ///     CsrfState::new(state),
 /// )?;
 ///
-/// let access_token = token.access_token.inner();
+/// let access_token = token.access_token().expose_secret();
 /// ```
 pub struct TwitterAuthorizationCodeClient {
     inner: AuthorizationCodeClient,
@@ -126,4 +129,43 @@ impl TwitterAuthorizationCodeClient {
         self.inner
             .generate_session(scopes)
     }
+
+    /// Generates a new authorization session with the specified CSRF state.
+    ///
+    /// This is useful to embed application data into the state
+    /// or to store the state on the server side for multi-instance deployments.
+    ///
+    /// ## Arguments
+    /// - `scopes` - The scopes to request authorization defined at [here](https://developer.twitter.com/en/docs/authentication/oauth-2-0/authorization-code).
+    /// - `state` - The CSRF state of the authorization request, it must be unguessable.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::oauth::TwitterAuthorizationCodeClient;
+    /// use fars::oauth::ClientId;
+    /// use fars::oauth::RedirectUrl;
+    /// use std::collections::HashSet;
+    /// use fars::oauth::OAuthScope;
+    /// use fars::oauth::CsrfState;
+    ///
+    /// let client = TwitterAuthorizationCodeClient::new(
+    ///     ClientId::new("client-id"),
+    ///     RedirectUrl::new("https://my.app.com/callback")?,
+    /// )?;
+    ///
+    /// let session = client.generate_authorization_session_with_state(
+    ///     HashSet::from([OAuthScope::open_id()]),
+    ///     CsrfState::new_random(),
+    /// );
+    ///
+    /// let authorize_url = session.authorize_url.inner();
+    /// ```
+    pub fn generate_authorization_session_with_state(
+        &self,
+        scopes: HashSet<OAuthScope>,
+        state: CsrfState,
+    ) -> AuthorizationCodeSession {
+        self.inner
+            .generate_session_with_state(scopes, state)
+    }
 }
@@ -1,16 +1,29 @@
 use std::collections::HashMap;
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use oauth2::basic::BasicTokenResponse;
+use oauth2::TokenResponse;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::oauth::AccessToken;
+use crate::oauth::OAuthScope;
 use crate::oauth::RefreshToken;
 use crate::IdpPostBody;
 use crate::ProviderId;
 
 /// The OAuth 2.0 token set.
 ///
+/// This can be serialized and deserialized by the [serde](https://serde.rs/)
+/// to persist the provider grant, e.g. to call the provider APIs later on a backend.
+/// The expiration time is serialized as seconds with the absolute expiration time in UNIX seconds
+/// recorded when the token has been received, and the remaining expiration time is computed from the absolute one at deserialization.
+///
 /// ## NOTE
 /// This is only available when the feature "oauth" is enabled.
 ///
+/// ## WARNING
+/// The serialized token set contains the raw access and refresh tokens, so please store it securely.
+///
 /// ## Example
 /// ```
 /// use std::collections::HashSet;
@@ -50,6 +63,7 @@ use crate::ProviderId;
 ///     CsrfState::new(state),
 /// )?;
 /// ```
+#[derive(Debug)]
 pub struct OAuthToken {
     /// The access token.
     pub(crate) access_token: AccessToken,
@@ -57,9 +71,61 @@ pub struct OAuthToken {
     pub(crate) refresh_token: Option<RefreshToken>,
     /// The expiration time.
     pub(crate) expires_in: Option<Duration>,
+    /// The absolute expiration time recorded when the token has been received.
+    pub(crate) expires_at: Option<SystemTime>,
+    /// The scopes granted by the user.
+    pub(crate) scopes: Vec<OAuthScope>,
+    /// The token type, e.g. "bearer".
+    pub(crate) token_type: String,
 }
 
 impl OAuthToken {
+    /// Creates a new token set received now, recording the absolute expiration time.
+    pub(crate) fn new(
+        access_token: AccessToken,
+        refresh_token: Option<RefreshToken>,
+        expires_in: Option<Duration>,
+        scopes: Vec<OAuthScope>,
+        token_type: String,
+    ) -> Self {
+        Self {
+            access_token,
+            refresh_token,
+            expires_in,
+            expires_at: expires_in.and_then(expires_at_from_now),
+            scopes,
+            token_type,
+        }
+    }
+
+    /// Creates a new token set from a token response of the `oauth2` crate.
+    pub(crate) fn from_response(token_response: &BasicTokenResponse) -> Self {
+        Self::new(
+            AccessToken::new(
+                token_response
+                    .access_token()
+                    .secret(),
+            ),
+            token_response
+                .refresh_token()
+                .map(|token| RefreshToken::new(token.secret())),
+            token_response.expires_in(),
+            token_response
+                .scopes()
+                .map(|scopes| {
+                    scopes
+                        .iter()
+                        .map(|scope| OAuthScope::new(scope.to_string()))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            token_response
+                .token_type()
+                .as_ref()
+                .to_string(),
+        )
+    }
+
     /// Returns the access token.
     pub fn access_token(&self) -> &AccessToken {
         &self.access_token
@@ -71,12 +137,42 @@ impl OAuthToken {
     }
 
     /// Returns the expiration time.
+    ///
+    /// For a deserialized token set, this is the remaining time at the deserialization.
     pub fn expires_in(&self) -> Option<Duration> {
         self.expires_in
     }
 
+    /// Returns the absolute expiration time recorded when the token has been received.
+    ///
+    /// ## NOTE
+    /// This is `None` on the `wasm32` targets where the system time is not available.
+    pub fn expires_at(&self) -> Option<SystemTime> {
+        self.expires_at
+    }
+
+    /// Returns the scopes granted by the user.
+    ///
+    /// The user may deselect some of the requested scopes on the consent screen,
+    /// so please check this to handle a partial consent.
+    ///
+    /// ## NOTE
+    /// This is empty when the token response does not include the `scope`,
+    /// which means that the granted scopes are identical to the requested ones by [RFC 6749](https://datatracker.ietf.org/doc/html/rfc6749#section-5.1).
+    pub fn scopes(&self) -> &[OAuthScope] {
+        &self.scopes
+    }
+
+    /// Returns the token type, e.g. "bearer".
+    pub fn token_type(&self) -> &str {
+        &self.token_type
+    }
+
     /// Creates a new post body with access token and provider ID to sign in.
     ///
+    /// For the Facebook Limited Login that returns an ID token instead of an access token,
+    /// use [`crate::IdpPostBody::facebook_limited_login`] instead.
+    ///
     /// ## Arguments
     /// - `provider_id` - The provider ID.
     ///
@@ -133,9 +229,194 @@ impl OAuthToken {
             HashMap::from([(
                 "access_token",
                 self.access_token
-                    .inner()
+                    .expose_secret()
                     .to_owned(),
             )]),
         )
     }
 }
+
+/// The serialized representation of [`OAuthToken`].
+#[derive(Serialize, Deserialize)]
+struct OAuthTokenRepresentation {
+    access_token: AccessToken,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    refresh_token: Option<RefreshToken>,
+    /// The expiration time in seconds.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    expires_in: Option<u64>,
+    /// The absolute expiration time in UNIX seconds.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    expires_at: Option<u64>,
+    #[serde(default)]
+    scopes: Vec<OAuthScope>,
+    token_type: String,
+}
+
+impl Serialize for OAuthToken {
+    fn serialize<S>(
+        &self,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        OAuthTokenRepresentation {
+            access_token: self.access_token.clone(),
+            refresh_token: self.refresh_token.clone(),
+            expires_in: self
+                .expires_in
+                .map(|expires_in| expires_in.as_secs()),
+            expires_at: self
+                .expires_at
+                .and_then(|expires_at| {
+                    expires_at
+                        .duration_since(UNIX_EPOCH)
+                        .ok()
+                })
+                .map(|expires_at| expires_at.as_secs()),
+            scopes: self.scopes.clone(),
+            token_type: self.token_type.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for OAuthToken {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let representation =
+            OAuthTokenRepresentation::deserialize(deserializer)?;
+
+        // Prefer the remaining time computed from the absolute expiration time.
+        let expires_in = match representation.expires_at {
+            | Some(expires_at) => {
+                remaining_until(expires_at).or(representation
+                    .expires_in
+                    .map(Duration::from_secs))
+            },
+            | None => representation
+                .expires_in
+                .map(Duration::from_secs),
+        };
+
+        Ok(Self {
+            access_token: representation.access_token,
+            refresh_token: representation.refresh_token,
+            expires_in,
+            expires_at: representation
+                .expires_at
+                .map(|expires_at| UNIX_EPOCH + Duration::from_secs(expires_at)),
+            scopes: representation.scopes,
+            token_type: representation.token_type,
+        })
+    }
+}
+
+/// Computes the absolute expiration time from now.
+///
+/// ## NOTE
+/// This returns `None` on the `wasm32` targets where the system time is not available.
+fn expires_at_from_now(expires_in: Duration) -> Option<SystemTime> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        SystemTime::now().checked_add(expires_in)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = expires_in;
+        None
+    }
+}
+
+/// Computes the remaining time until the absolute expiration time in UNIX seconds.
+///
+/// ## NOTE
+/// This returns `None` on the `wasm32` targets where the system time is not available.
+fn remaining_until(expires_at: u64) -> Option<Duration> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .ok()
+            .map(|now| {
+                Duration::from_secs(expires_at.saturating_sub(now.as_secs()))
+            })
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        let _ = expires_at;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_records_expiration_when_received() {
+        let before = SystemTime::now();
+        let token = OAuthToken::new(
+            AccessToken::new("access-token"),
+            None,
+            Some(Duration::from_secs(3600)),
+            Vec::new(),
+            "bearer".to_string(),
+        );
+        let after = SystemTime::now();
+
+        let expires_at = token.expires_at().unwrap();
+        assert!(expires_at >= before + Duration::from_secs(3600));
+        assert!(expires_at <= after + Duration::from_secs(3600));
+    }
+
+    #[test]
+    fn serializes_recorded_expiration() {
+        let expires_at = UNIX_EPOCH + Duration::from_secs(1_000_000);
+        let mut token = OAuthToken::new(
+            AccessToken::new("access-token"),
+            None,
+            Some(Duration::from_secs(3600)),
+            Vec::new(),
+            "bearer".to_string(),
+        );
+        token.expires_at = Some(expires_at);
+
+        let json = serde_json::to_value(&token).unwrap();
+        assert_eq!(json["expires_in"], 3600);
+        assert_eq!(json["expires_at"], 1_000_000);
+
+        // The token saved late has already expired after reload.
+        let token: OAuthToken = serde_json::from_value(json).unwrap();
+        assert_eq!(token.expires_in(), Some(Duration::ZERO));
+        assert_eq!(token.expires_at(), Some(expires_at));
+    }
+
+    #[test]
+    fn deserializes_without_absolute_expiration() {
+        let token: OAuthToken = serde_json::from_value(serde_json::json!({
+            "access_token": "access-token",
+            "expires_in": 3600,
+            "token_type": "bearer",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            token.expires_in(),
+            Some(Duration::from_secs(3600))
+        );
+        assert_eq!(token.expires_at(), None);
+    }
+}
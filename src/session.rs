@@ -16,20 +16,33 @@
 //!
 //! Therefore you have to **update** session every time you use APIs through a session by returned new session.
 //!
+//! To get a consistent view of the tokens, take a snapshot by [`crate::Session::tokens`] from the returned new session.
+//!
 //! ## Supported APIs
 //! Supported APIs are as follows:
 //!
 //! - [Change email](`crate::Session::change_email`)
+//! - [Verify before update email](`crate::Session::verify_before_update_email`)
 //! - [Change password](`crate::Session::change_password`)
 //! - [Update profile](`crate::Session::update_profile`)
+//! - [Update profile with new tokens](`crate::Session::update_profile_with_new_tokens`)
 //! - [Delete profile](`crate::Session::delete_profile`)
 //! - [Get user data](`crate::Session::get_user_data`)
+//! - [Get account info](`crate::Session::account_info`)
+//! - [Get linked providers](`crate::Session::linked_providers`)
+//! - [Check anonymous user](`crate::Session::is_anonymous`)
 //! - [Link with email and password](`crate::Session::link_with_email_password`)
 //! - [Link with OAuth credential](`crate::Session::link_with_oauth_credential`)
 //! - [Unlink provider](`crate::Session::unlink_provider`)
 //! - [Send email verification](`crate::Session::send_email_verification`)
+//! - [Send email verification if needed](`crate::Session::send_email_verification_if_needed`)
+//! - [Send email verification and return the OOB code](`crate::Session::send_email_verification_returning_oob_code`) (emulator only)
 //! - [Delete account](`crate::Session::delete_account`)
+//! - [Delete account with reauthentication](`crate::Session::delete_account_reauth`)
 //! - [Refresh token](`crate::Session::refresh_token`)
+//! - [Refresh token and get claims](`crate::Session::refresh_and_get_claims`) (optional feature: `verify`)
+//!
+//! See also [`crate::CachedSession`] to cache the user data with a TTL.
 //!
 //! ## Examples
 //! An example to get user data through a session with [tokio](https://github.com/tokio-rs/tokio) and [anyhow](https://github.com/dtolnay/anyhow) is as follows:
@@ -66,8 +79,11 @@
 use std::collections::HashSet;
 
 use crate::api;
+use crate::AccountInfo;
+use crate::ActionCodeSettings;
 use crate::ApiKey;
 use crate::Client;
+use crate::Config;
 use crate::DeleteAttribute;
 use crate::DisplayName;
 use crate::Email;
@@ -77,11 +93,16 @@ use crate::IdToken;
 use crate::IdpPostBody;
 use crate::LanguageCode;
 use crate::OAuthRequestUri;
+use crate::OobCode;
 use crate::Password;
 use crate::PhotoUrl;
+use crate::ProjectId;
 use crate::ProviderId;
 use crate::RefreshToken;
 use crate::Result;
+use crate::SentOobCode;
+use crate::Tokens;
+use crate::Uid;
 use crate::UserData;
 
 /// Authentication session for a user of the Firebase Auth.
@@ -90,6 +111,9 @@ use crate::UserData;
 ///
 /// See also [`crate::config`].
 ///
+/// ## Thread safety
+/// A session is `Send + Sync + 'static`, so it can be shared between tasks, e.g. by `Arc<Mutex<Session>>`.
+///
 /// ## Example
 /// ```
 /// use fars::Config;
@@ -118,6 +142,311 @@ pub struct Session {
     pub expires_in: ExpiresIn,
     /// Firebase Auth refresh token.
     pub refresh_token: RefreshToken,
+    /// The UID of the signed in user.
+    pub(crate) local_id: Uid,
+    /// Whether the user has been newly created by the sign in.
+    pub(crate) first_sign_in: bool,
+    /// Whether the session has been started by signing in anonymously and not linked yet.
+    pub(crate) anonymous: bool,
+    /// The last-known display name updated by this session.
+    pub(crate) display_name: Option<String>,
+    /// The last-known photo URL updated by this session.
+    pub(crate) photo_url: Option<String>,
+    /// The expected project ID of the config that has created this session,
+    /// carried over to the sessions signed in again through this session.
+    pub(crate) expected_project_id: Option<ProjectId>,
+}
+
+impl Session {
+    /// Returns whether the user has been newly created by the sign in that started this session.
+    ///
+    /// This is useful to trigger onboarding for a new user.
+    ///
+    /// ## NOTE
+    /// - Signing up with email and password and signing in anonymously always create a new user.
+    /// - Signing in with email and password is derived from the `registered` field of the response.
+    /// - Signing in with OAuth credential is derived from the `isNewUser` field of the response.
+    /// - Exchanging a refresh token never creates a new user.
+    ///
+    /// The value is kept on new sessions returned from the APIs through this session.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// if session.first_sign_in() {
+    ///     // Start onboarding.
+    /// }
+    /// ```
+    pub fn first_sign_in(&self) -> bool {
+        self.first_sign_in
+    }
+
+    /// Returns whether the session has been started by signing in anonymously and has not been linked with other credentials yet.
+    ///
+    /// This is a synchronous flag tracked locally without any API call,
+    /// unlike [`crate::Session::is_anonymous`] that fetches the user data to check the account on the Firebase Auth.
+    ///
+    /// ## NOTE
+    /// - Signing in anonymously starts an anonymous session.
+    /// - Linking with email and password or OAuth credential marks the session as not anonymous.
+    /// - Exchanging a refresh token by [`crate::Config::exchange_refresh_token`] (and [`crate::Config::sign_in_anonymously_or_restore`])
+    ///   and [`crate::Config::session_from_tokens`] are anonymous if the ID token has been issued for an anonymous user.
+    /// - Sessions of other sign in methods are not anonymous.
+    ///
+    /// The value is kept on new sessions returned from the APIs through this session.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let session = config.sign_in_anonymously().await?;
+    ///
+    /// assert!(session.started_anonymously());
+    /// ```
+    pub fn started_anonymously(&self) -> bool {
+        self.anonymous
+    }
+
+    /// Returns the last-known display name updated by [`crate::Session::update_profile`] on this session,
+    /// e.g. to show the edited profile without fetching the user data again.
+    ///
+    /// ## NOTE
+    /// This is `None` until the profile is updated on this session, and does not reflect changes by other sessions.
+    /// Use [`crate::Session::get_user_data`] to get the latest profile.
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    /// Returns the last-known photo URL updated by [`crate::Session::update_profile`] on this session,
+    /// e.g. to show the edited profile without fetching the user data again.
+    ///
+    /// ## NOTE
+    /// This is `None` until the profile is updated on this session, and does not reflect changes by other sessions.
+    /// Use [`crate::Session::get_user_data`] to get the latest profile.
+    pub fn photo_url(&self) -> Option<&str> {
+        self.photo_url.as_deref()
+    }
+
+    /// Returns the UID of the user of this session.
+    ///
+    /// This is useful to key local states by the UID, e.g. for an anonymous user, without calling [`crate::Session::get_user_data`].
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let session = config.sign_in_anonymously().await?;
+    ///
+    /// let uid = session.local_id();
+    /// ```
+    pub fn local_id(&self) -> &Uid {
+        &self.local_id
+    }
+
+    /// Returns the project ID of the Firebase project that issued the ID token of this session,
+    /// e.g. to build a [`crate::verification::VerificationConfig`] without configuring the project ID separately.
+    ///
+    /// This is read from the audience (`aud`) claim of the ID token, which is the project ID expected by the verification.
+    ///
+    /// ## WARNING
+    /// This does NOT verify the ID token, so never trust it for authorization on a server.
+    ///
+    /// ## NOTE
+    /// The `project_id` of the refresh token response is the project number, not the project ID,
+    /// so it is not used here.
+    ///
+    /// ## Returns
+    /// The project ID, or `None` if the ID token is malformed.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let session = config.sign_in_anonymously().await?;
+    ///
+    /// let project_id = session.project_id();
+    /// ```
+    pub fn project_id(&self) -> Option<ProjectId> {
+        /// The audience claim of an ID token.
+        #[derive(serde::Deserialize)]
+        struct Audience {
+            aud: String,
+        }
+
+        self.id_token
+            .decode_unverified_claims::<Audience>()
+            .map(|claims| ProjectId::new(claims.aud))
+    }
+
+    /// Returns whether the email of the user has been verified by the `email_verified` claim of the ID token,
+    /// e.g. to show a "verify your email" banner without calling [`crate::Session::get_user_data`].
+    ///
+    /// ## WARNING
+    /// This is only a hint from the ID token that is NOT verified,
+    /// so never trust it for authorization on a server.
+    /// Use [`crate::verification::VerificationConfig::verify_id_token`] for authoritative checks.
+    ///
+    /// ## NOTE
+    /// The claim is as of when the ID token has been issued,
+    /// so it does not reflect the verification after that until the ID token is refreshed.
+    ///
+    /// ## Returns
+    /// Whether the email has been verified, or `None` if the ID token is malformed or has no `email_verified` claim.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// if session.email_verified_hint() != Some(true) {
+    ///     // Show a "verify your email" banner.
+    /// }
+    /// ```
+    pub fn email_verified_hint(&self) -> Option<bool> {
+        /// The email verified claim of an ID token.
+        #[derive(serde::Deserialize)]
+        struct EmailVerified {
+            email_verified: Option<bool>,
+        }
+
+        self.id_token
+            .decode_unverified_claims::<EmailVerified>()
+            .and_then(|claims| claims.email_verified)
+    }
+
+    /// Returns whether the ID token has been issued for an anonymous user by the `firebase.sign_in_provider` claim.
+    ///
+    /// ## NOTE
+    /// This is NOT verified, so use it only as a hint, e.g. to restore the anonymity of a session.
+    pub(crate) fn signed_in_anonymously_hint(&self) -> bool {
+        /// The Firebase claim of an ID token.
+        #[derive(serde::Deserialize)]
+        struct Firebase {
+            sign_in_provider: Option<String>,
+        }
+
+        /// The claims with the sign in provider of an ID token.
+        #[derive(serde::Deserialize)]
+        struct SignInProvider {
+            firebase: Option<Firebase>,
+        }
+
+        self.id_token
+            .decode_unverified_claims::<SignInProvider>()
+            .and_then(|claims| claims.firebase)
+            .and_then(|firebase| firebase.sign_in_provider)
+            .is_some_and(|provider| provider == "anonymous")
+    }
+
+    /// Returns a snapshot of the current tokens of this session.
+    ///
+    /// ## NOTE
+    /// APIs through a session consume the session and return a new session that may have new tokens,
+    /// so take a snapshot from the returned session after calling APIs.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// let new_session = session.change_password(
+    ///     Password::new("new-password"),
+    /// ).await?;
+    ///
+    /// // Use tokens of the new session.
+    /// let tokens = new_session.tokens();
+    /// ```
+    pub fn tokens(&self) -> Tokens {
+        Tokens {
+            id_token: self.id_token.clone(),
+            expires_in: self.expires_in,
+            refresh_token: self.refresh_token.clone(),
+        }
+    }
+
+    /// Replaces the HTTP client of the session.
+    ///
+    /// Sessions returned by APIs through this session, e.g. by refreshing tokens, keep using the replaced client,
+    /// so sharing a single tuned client across independently created sessions improves connection pooling.
+    ///
+    /// ## Arguments
+    /// - `client` - A HTTP client to replace.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Client;
+    /// use fars::RefreshToken;
+    ///
+    /// let client = Client::new();
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let session = config.exchange_refresh_token(
+    ///     RefreshToken::new("refresh-token"),
+    /// ).await?
+    /// .with_client(client.clone());
+    /// ```
+    pub fn with_client(
+        mut self,
+        client: Client,
+    ) -> Self {
+        self.client = client;
+        self
+    }
 }
 
 // Defines macros for calling APIs with refreshing tokens.
@@ -134,7 +463,7 @@ macro_rules! call_refreshing_tokens_return_session_and_value {
                     Ok(value) => return Ok((session, value)),
                     Err(error) => match error {
                         // NOTE: Retry for invalid ID token error.
-                        Error::InvalidIdToken if attempts < $retry_count => {
+                        Error::InvalidIdToken { .. } if attempts < $retry_count => {
                             match session.refresh_token().await {
                                 Ok(new_session) => {
                                     session = new_session;
@@ -168,7 +497,7 @@ macro_rules! call_refreshing_tokens_without_value_return_session {
                     Ok(_) => return Ok(session),
                     Err(error) => match error {
                         // NOTE: Retry for invalid ID token error.
-                        Error::InvalidIdToken if attempts < $retry_count => {
+                        Error::InvalidIdToken { .. } if attempts < $retry_count => {
                             match session.refresh_token().await {
                                 Ok(new_session) => {
                                     session = new_session;
@@ -191,7 +520,6 @@ macro_rules! call_refreshing_tokens_without_value_return_session {
 }
 
 /// Calls an API with refreshing tokens then returns new session.
-#[allow(unused_macros)]
 macro_rules! call_refreshing_tokens_return_session {
     // Has arguments and returns new session.
     ($session:expr, $api_call:expr, $retry_count:expr, $($api_call_args:expr),*) => {{
@@ -203,7 +531,7 @@ macro_rules! call_refreshing_tokens_return_session {
                     Ok(new_session) => return Ok(new_session),
                     Err(error) => match error {
                         // NOTE: Retry for invalid ID token error.
-                        Error::InvalidIdToken if attempts < $retry_count => {
+                        Error::InvalidIdToken { .. } if attempts < $retry_count => {
                             match session.refresh_token().await {
                                 Ok(new_session) => {
                                     session = new_session;
@@ -237,7 +565,7 @@ macro_rules! call_refreshing_tokens_return_nothing {
                     Ok(_) => return Ok(()),
                     Err(error) => match error {
                         // NOTE: Retry for invalid ID token error.
-                        Error::InvalidIdToken if attempts < $retry_count => {
+                        Error::InvalidIdToken { .. } if attempts < $retry_count => {
                             match session.refresh_token().await {
                                 Ok(new_session) => {
                                     session = new_session;
@@ -265,12 +593,20 @@ impl Session {
     ///
     /// Automatically refreshes tokens if needed.
     ///
+    /// ## NOTE
+    /// This fails with `Error::NotSupportedForAnonymousUser` without any request for an anonymous session,
+    /// so please link the user with email and password by [`crate::Session::link_with_email_password`] first.
+    ///
+    /// The new email is unverified, i.e. `email_verified` of the user becomes `false`,
+    /// so please fetch the user data again by [`crate::Session::get_user_data`] to show the latest verification status.
+    /// [`crate::CachedSession::change_email`] invalidates the cached user data to fetch it again.
+    ///
     /// ## Arguments
     /// - `new_email` - The new email address of the user.
     /// - `locale` - The optional language code corresponding to the user's locale.
     ///
     /// ## Returns
-    /// New session to replace the consumed session.
+    /// New session that has new tokens to replace the consumed session.
     ///
     /// ## Errors
     /// - `Error::InvalidHeaderValue` - Invalid header value.
@@ -279,6 +615,7 @@ impl Session {
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::NotSupportedForAnonymousUser` - The session is anonymous.
     /// - `Error::ApiError` - API error on the Firebase Auth.
     ///
     /// ## Example
@@ -306,7 +643,13 @@ impl Session {
         new_email: Email,
         locale: Option<LanguageCode>,
     ) -> Result<Session> {
-        call_refreshing_tokens_without_value_return_session!(
+        if self.anonymous {
+            return Err(Error::NotSupportedForAnonymousUser {
+                operation: "change email",
+            });
+        }
+
+        call_refreshing_tokens_return_session!(
             self,
             Session::change_email_internal,
             1,
@@ -316,15 +659,92 @@ impl Session {
         .await
     }
 
+    /// Sends a verification email to the new email before updating the email for the user.
+    ///
+    /// The email is updated after the user clicks the link in the verification email,
+    /// so this is required for projects that enable the email enumeration protection instead of [`crate::Session::change_email`].
+    ///
+    /// Automatically refreshes tokens if needed.
+    ///
+    /// ## NOTE
+    /// This fails with `Error::NotSupportedForAnonymousUser` without any request for an anonymous session,
+    /// so please link the user with email and password by [`crate::Session::link_with_email_password`] first.
+    ///
+    /// ## Arguments
+    /// - `new_email` - The new email address of the user.
+    /// - `action_code_settings` - The optional settings of the action code, e.g. the continue URL.
+    /// - `locale` - The optional language code corresponding to the user's locale.
+    ///
+    /// ## Returns
+    /// New session to replace the consumed session, the email has not been updated yet.
+    ///
+    /// ## Errors
+    /// - `Error::InvalidHeaderValue` - Invalid header value.
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::NotSupportedForAnonymousUser` - The session is anonymous.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// let new_session = session.verify_before_update_email(
+    ///     Email::new("new-user@example"),
+    ///     None, // action code settings
+    ///     None, // locale
+    /// ).await?;
+    /// ```
+    pub async fn verify_before_update_email(
+        self,
+        new_email: Email,
+        action_code_settings: Option<ActionCodeSettings>,
+        locale: Option<LanguageCode>,
+    ) -> Result<Session> {
+        if self.anonymous {
+            return Err(Error::NotSupportedForAnonymousUser {
+                operation: "verify before update email",
+            });
+        }
+
+        call_refreshing_tokens_without_value_return_session!(
+            self,
+            Session::verify_before_update_email_internal,
+            1,
+            new_email.clone(),
+            action_code_settings.clone(),
+            locale
+        )
+        .await
+    }
+
     /// Changes the password for the user.
     ///
     /// Automatically refreshes tokens if needed.
     ///
+    /// ## NOTE
+    /// This fails with `Error::NotSupportedForAnonymousUser` without any request for an anonymous session,
+    /// so please link the user with email and password by [`crate::Session::link_with_email_password`] first.
+    ///
     /// ## Arguments
     /// - `new_password` - The new password of the user.
     ///
     /// ## Returns
-    /// New session to replace the consumed session.
+    /// New session that has new tokens to replace the consumed session.
     ///
     /// ## Errors
     /// - `Error::HttpRequestError` - Failed to send a request.
@@ -332,8 +752,8 @@ impl Session {
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::NotSupportedForAnonymousUser` - The session is anonymous.
     /// - `Error::ApiError` - API error on the Firebase Auth.
-    /// - `Error::ParseExpriesInFailed` - Failed to parse the expires in value.
     ///
     /// ## Example
     /// ```
@@ -358,7 +778,13 @@ impl Session {
         self,
         new_password: Password,
     ) -> Result<Session> {
-        call_refreshing_tokens_without_value_return_session!(
+        if self.anonymous {
+            return Err(Error::NotSupportedForAnonymousUser {
+                operation: "change password",
+            });
+        }
+
+        call_refreshing_tokens_return_session!(
             self,
             Session::change_password_internal,
             1,
@@ -413,12 +839,73 @@ impl Session {
         display_name: Option<DisplayName>,
         photo_url: Option<PhotoUrl>,
     ) -> Result<Session> {
-        call_refreshing_tokens_without_value_return_session!(
+        call_refreshing_tokens_return_session!(
+            self,
+            Session::update_profile_internal,
+            1,
+            display_name.clone(),
+            photo_url.clone(),
+            false
+        )
+        .await
+    }
+
+    /// Updates the user profile information and applies new tokens issued by the API.
+    ///
+    /// Automatically refreshes tokens if needed.
+    ///
+    /// This requests `returnSecureToken` to keep the session current after the profile change
+    /// instead of relying on the next refresh, e.g. to reflect the new display name in the ID token claims.
+    ///
+    /// ## Arguments
+    /// - `display_name` - (Optional) The display name for the account.
+    /// - `photo_url` - (Optional) The photo url of the account.
+    ///
+    /// ## Returns
+    /// New session with new tokens to replace the consumed session.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    /// use fars::DisplayName;
+    /// use fars::PhotoUrl;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// let new_session = session.update_profile_with_new_tokens(
+    ///     Some(DisplayName::new("new-display-name")),
+    ///     None,
+    /// ).await?;
+    /// ```
+    pub async fn update_profile_with_new_tokens(
+        self,
+        display_name: Option<DisplayName>,
+        photo_url: Option<PhotoUrl>,
+    ) -> Result<Session> {
+        call_refreshing_tokens_return_session!(
             self,
             Session::update_profile_internal,
             1,
             display_name.clone(),
-            photo_url.clone()
+            photo_url.clone(),
+            true
         )
         .await
     }
@@ -434,6 +921,7 @@ impl Session {
     /// New session to replace the consumed session.
     ///
     /// ## Errors
+    /// - `Error::NoDeleteAttribute` - No attribute to delete is specified.
     /// - `Error::HttpRequestError` - Failed to send a request.
     /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
@@ -469,7 +957,12 @@ impl Session {
         self,
         delete_attribute: HashSet<DeleteAttribute>,
     ) -> Result<Session> {
-        call_refreshing_tokens_without_value_return_session!(
+        // Reject the no-op request without any attribute.
+        if delete_attribute.is_empty() {
+            return Err(Error::NoDeleteAttribute);
+        }
+
+        call_refreshing_tokens_return_session!(
             self,
             Session::delete_profile_internal,
             1,
@@ -522,6 +1015,141 @@ impl Session {
         .await
     }
 
+    /// Gets the identity providers linked with the user.
+    ///
+    /// This is a convenience method over [`crate::Session::get_user_data`] that maps the provider user information to typed provider IDs.
+    ///
+    /// Automatically refreshes tokens if needed.
+    ///
+    /// ## Returns
+    /// 1. New session to replace the consumed session.
+    /// 2. The linked provider IDs.
+    ///
+    /// ## Errors
+    /// - `Error::InvalidHeaderValue` - Invalid header value.
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    /// - `Error::NotFoundAnyUserData` - Not found any user data.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// let (new_session, provider_ids) = session.linked_providers().await?;
+    /// ```
+    pub async fn linked_providers(self) -> Result<(Session, Vec<ProviderId>)> {
+        let (session, user_data) = self.get_user_data().await?;
+
+        let provider_ids = user_data
+            .provider_user_info
+            .unwrap_or_default()
+            .into_iter()
+            .map(|info| info.typed_provider_id())
+            .collect();
+
+        Ok((session, provider_ids))
+    }
+
+    /// Gets the account information that bundles the user data, the anonymity, the linked providers and the parsed timestamps.
+    ///
+    /// This is a convenience method over [`crate::Session::get_user_data`], e.g. for a settings screen.
+    ///
+    /// Automatically refreshes tokens if needed.
+    ///
+    /// ## Returns
+    /// 1. New session to replace the consumed session.
+    /// 2. The account information.
+    ///
+    /// ## Errors
+    /// - `Error::InvalidHeaderValue` - Invalid header value.
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    /// - `Error::NotFoundAnyUserData` - Not found any user data.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// let (new_session, account_info) = session.account_info().await?;
+    /// ```
+    pub async fn account_info(self) -> Result<(Session, AccountInfo)> {
+        let (session, user_data) = self.get_user_data().await?;
+
+        Ok((session, user_data.into()))
+    }
+
+    /// Checks whether the user is an anonymous user.
+    ///
+    /// This is a convenience method over [`crate::Session::get_user_data`] and [`crate::UserData::is_anonymous`],
+    /// e.g. to show an "upgrade your account" UI.
+    ///
+    /// This sends a request to fetch the user data, unlike [`crate::Session::started_anonymously`]
+    /// that returns the flag tracked locally on this session without any API call.
+    ///
+    /// Automatically refreshes tokens if needed.
+    ///
+    /// ## Returns
+    /// 1. New session to replace the consumed session.
+    /// 2. Whether the user is an anonymous user.
+    ///
+    /// ## Errors
+    /// - `Error::InvalidHeaderValue` - Invalid header value.
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    /// - `Error::NotFoundAnyUserData` - Not found any user data.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    /// let session = config.sign_in_anonymously().await?;
+    ///
+    /// let (new_session, is_anonymous) = session.is_anonymous().await?;
+    /// ```
+    pub async fn is_anonymous(self) -> Result<(Session, bool)> {
+        let (session, user_data) = self.get_user_data().await?;
+
+        Ok((session, user_data.is_anonymous()))
+    }
+
     /// Links the user with the given email and password.
     ///
     /// Automatically refreshes tokens if needed.
@@ -540,7 +1168,6 @@ impl Session {
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::InvalidIdToken` - Invalid ID token.
     /// - `Error::ApiError` - API error on the Firebase Auth.
-    /// - `Error::ParseExpriesInFailed` - Failed to parse the expires in value.
     ///
     /// ## Example
     /// ```
@@ -576,24 +1203,139 @@ impl Session {
         self,
         email: Email,
         password: Password,
+    ) -> Result<Session> {
+        call_refreshing_tokens_return_session!(
+            self,
+            Session::link_with_email_password_internal,
+            1,
+            email.clone(),
+            password.clone()
+        )
+        .await
+    }
+
+    /// Links the user with the given OAuth credential.
+    ///
+    /// Automatically refreshes tokens if needed.
+    ///
+    /// ## Arguments
+    /// - `request_uri` - The URI to which the IDP redirects the user back.
+    /// - `post_body` - The POST body passed to the IDP containing the OAuth credential and provider ID.
+    ///
+    /// ## Returns
+    /// New session to replace the consumed session.
+    ///
+    /// ## Errors
+    /// - `Error::InvalidHeaderValue` - Invalid header value.
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    /// use fars::OAuthRequestUri;
+    /// use fars::IdpPostBody;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// let new_session = session.link_with_oauth_credential(
+    ///     OAuthRequestUri::new("https://your-app.com/redirect/path/auth/handler"),
+    ///     IdpPostBody::new(
+    ///         ProviderId::Google,
+    ///         HashMap::from([(
+    ///             "access_token",
+    ///             "google-access-token".to_string(),
+    ///         )]),
+    ///     )?,
+    /// ).await?;
+    /// ```
+    pub async fn link_with_oauth_credential(
+        self,
+        request_uri: OAuthRequestUri,
+        post_body: IdpPostBody,
+    ) -> Result<Session> {
+        call_refreshing_tokens_return_session!(
+            self,
+            Session::link_with_oauth_credential_internal,
+            1,
+            request_uri.clone(),
+            post_body.clone()
+        )
+        .await
+    }
+
+    /// Unlinks the user with the given provider.
+    ///
+    /// Automatically refreshes tokens if needed.
+    ///
+    /// ## Arguments
+    /// - `delete_provider` - The provider IDs to unlink, e.g. `ProviderId::Password` to unlink the email and password credential and `ProviderId::Phone` to unlink the phone number.
+    ///
+    /// ## Returns
+    /// New session to replace the consumed session.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::collections::HashSet;
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    /// use fars::ProviderId;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// let new_session = session.unlink_provider(
+    ///     HashSet::from([ProviderId::Google]),
+    /// ).await?;
+    /// ```
+    pub async fn unlink_provider(
+        self,
+        delete_provider: HashSet<ProviderId>,
     ) -> Result<Session> {
         call_refreshing_tokens_without_value_return_session!(
             self,
-            Session::link_with_email_password_internal,
+            Session::unlink_provider_internal,
             1,
-            email.clone(),
-            password.clone()
+            delete_provider.clone()
         )
         .await
     }
 
-    /// Links the user with the given OAuth credential.
+    /// Sends an email verification to the user.
     ///
     /// Automatically refreshes tokens if needed.
     ///
     /// ## Arguments
-    /// - `request_uri` - The URI to which the IDP redirects the user back.
-    /// - `post_body` - The POST body passed to the IDP containing the OAuth credential and provider ID.
+    /// - `action_code_settings` - The optional settings of the action code, e.g. the continue URL.
+    /// - `locale` - The optional language code corresponding to the user's locale.
     ///
     /// ## Returns
     /// New session to replace the consumed session.
@@ -606,7 +1348,6 @@ impl Session {
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::InvalidIdToken` - Invalid ID token.
     /// - `Error::ApiError` - API error on the Firebase Auth.
-    /// - `Error::ParseExpriesInFailed` - Failed to parse the expires in value.
     ///
     /// ## Example
     /// ```
@@ -614,8 +1355,6 @@ impl Session {
     /// use fars::ApiKey;
     /// use fars::Email;
     /// use fars::Password;
-    /// use fars::OAuthRequestUri;
-    /// use fars::IdpPostBody;
     ///
     /// let config = Config::new(
     ///     ApiKey::new("your-firebase-project-api-key"),
@@ -625,58 +1364,61 @@ impl Session {
     ///     Password::new("password"),
     /// ).await?;
     ///
-    /// let new_session = session.link_with_oauth_credential(
-    ///     OAuthRequestUri::new("https://your-app.com/redirect/path/auth/handler"),
-    ///     IdpPostBody::new(
-    ///         ProviderId::Google,
-    ///         HashMap::from([(
-    ///             "access_token",
-    ///             "google-access-token".to_string(),
-    ///         )]),
-    ///     )?,
+    /// let new_session = session.send_email_verification(
+    ///     None, // action code settings
+    ///     None, // locale
     /// ).await?;
     /// ```
-    pub async fn link_with_oauth_credential(
+    pub async fn send_email_verification(
         self,
-        request_uri: OAuthRequestUri,
-        post_body: IdpPostBody,
+        action_code_settings: Option<ActionCodeSettings>,
+        locale: Option<LanguageCode>,
     ) -> Result<Session> {
         call_refreshing_tokens_without_value_return_session!(
             self,
-            Session::link_with_oauth_credential_internal,
+            Session::send_email_verification_internal,
             1,
-            request_uri.clone(),
-            post_body.clone()
+            action_code_settings.clone(),
+            locale
         )
         .await
     }
 
-    /// Unlinks the user with the given provider.
+    /// Sends an email verification to the user only if the email has not been verified yet,
+    /// e.g. to avoid pointless sends and the rate limiting of `TOO_MANY_ATTEMPTS_TRY_LATER`.
+    ///
+    /// This is a convenience method over [`crate::Session::get_user_data`] and [`crate::Session::send_email_verification`].
     ///
     /// Automatically refreshes tokens if needed.
     ///
+    /// ## NOTE
+    /// This sends one more request to get the user data for the verification state.
+    /// Use [`crate::CachedSession::send_email_verification_if_needed`] to check it by the cached user data.
+    ///
     /// ## Arguments
-    /// - `delete_provider` - The provider IDs to unlink.
+    /// - `action_code_settings` - The optional settings of the action code, e.g. the continue URL.
+    /// - `locale` - The optional language code corresponding to the user's locale.
     ///
     /// ## Returns
-    /// New session to replace the consumed session.
+    /// 1. New session to replace the consumed session.
+    /// 2. Whether the email verification has been sent, `false` if the email has already been verified.
     ///
     /// ## Errors
+    /// - `Error::InvalidHeaderValue` - Invalid header value.
     /// - `Error::HttpRequestError` - Failed to send a request.
     /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::InvalidIdToken` - Invalid ID token.
     /// - `Error::ApiError` - API error on the Firebase Auth.
+    /// - `Error::NotFoundAnyUserData` - Not found any user data.
     ///
     /// ## Example
     /// ```
-    /// use std::collections::HashSet;
     /// use fars::Config;
     /// use fars::ApiKey;
     /// use fars::Email;
     /// use fars::Password;
-    /// use fars::ProviderId;
     ///
     /// let config = Config::new(
     ///     ApiKey::new("your-firebase-project-api-key"),
@@ -686,32 +1428,48 @@ impl Session {
     ///     Password::new("password"),
     /// ).await?;
     ///
-    /// let new_session = session.unlink_provider(
-    ///     HashSet::from([ProviderId::Google]),
+    /// let (new_session, sent) = session.send_email_verification_if_needed(
+    ///     None, // action code settings
+    ///     None, // locale
     /// ).await?;
     /// ```
-    pub async fn unlink_provider(
+    pub async fn send_email_verification_if_needed(
         self,
-        delete_provider: HashSet<ProviderId>,
-    ) -> Result<Session> {
-        call_refreshing_tokens_without_value_return_session!(
-            self,
-            Session::unlink_provider_internal,
-            1,
-            delete_provider.clone()
-        )
-        .await
+        action_code_settings: Option<ActionCodeSettings>,
+        locale: Option<LanguageCode>,
+    ) -> Result<(Session, bool)> {
+        let (session, user_data) = self.get_user_data().await?;
+
+        // Skip sending if the email has already been verified.
+        if user_data
+            .email_verified
+            .unwrap_or(false)
+        {
+            return Ok((session, false));
+        }
+
+        let session = session
+            .send_email_verification(action_code_settings, locale)
+            .await?;
+
+        Ok((session, true))
     }
 
-    /// Sends an email verification to the user.
+    /// Sends an email verification to the user and returns the sent OOB code and link.
     ///
     /// Automatically refreshes tokens if needed.
     ///
     /// ## Arguments
+    /// - `action_code_settings` - The optional settings of the action code, e.g. the continue URL.
     /// - `locale` - The optional language code corresponding to the user's locale.
     ///
     /// ## Returns
-    /// New session to replace the consumed session.
+    /// New session to replace the consumed session and the OOB code and link sent by the email.
+    ///
+    /// ## NOTE
+    /// The OOB code and link are only returned by the [Firebase Local Emulator Suite](https://firebase.google.com/docs/emulator-suite),
+    /// so this is realistically useful only for integration tests against the emulator.
+    /// Production responses do not include them and both fields of [`SentOobCode`] are `None`.
     ///
     /// ## Errors
     /// - `Error::InvalidHeaderValue` - Invalid header value.
@@ -737,18 +1495,25 @@ impl Session {
     ///     Password::new("password"),
     /// ).await?;
     ///
-    /// let new_session = session.send_email_verification(
+    /// let (new_session, sent) = session.send_email_verification_returning_oob_code(
+    ///     None, // action code settings
     ///     None, // locale
     /// ).await?;
+    ///
+    /// if let Some(oob_code) = sent.oob_code {
+    ///     config.apply_action_code(oob_code).await?;
+    /// }
     /// ```
-    pub async fn send_email_verification(
+    pub async fn send_email_verification_returning_oob_code(
         self,
+        action_code_settings: Option<ActionCodeSettings>,
         locale: Option<LanguageCode>,
-    ) -> Result<Session> {
-        call_refreshing_tokens_without_value_return_session!(
+    ) -> Result<(Session, SentOobCode)> {
+        call_refreshing_tokens_return_session_and_value!(
             self,
             Session::send_email_verification_internal,
             1,
+            action_code_settings.clone(),
             locale
         )
         .await
@@ -758,12 +1523,18 @@ impl Session {
     ///
     /// Automatically refreshes tokens if needed.
     ///
+    /// ## NOTE
+    /// The Firebase Auth requires a recent sign-in to delete the account,
+    /// so this fails with `Error::ReauthenticationRequired` if the user has signed in a long time ago.
+    /// Please sign in again, e.g. by [`Session::delete_account_reauth`], then delete the account.
+    ///
     /// ## Errors
     /// - `Error::HttpRequestError` - Failed to send a request.
     /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
     /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::ReauthenticationRequired` - The user must sign in again to delete the account.
     /// - `Error::ApiError` - API error on the Firebase Auth.
     ///
     /// ## Example
@@ -790,6 +1561,118 @@ impl Session {
             1,
         )
         .await
+        .map_err(Error::map_reauthentication_required)
+    }
+
+    /// Signs in again with the password of the user, then deletes the user account.
+    ///
+    /// This avoids `Error::ReauthenticationRequired` of [`Session::delete_account`]
+    /// for the user who has signed in a long time ago.
+    ///
+    /// ## NOTE
+    /// This is only available for the user who has an email and password.
+    /// For the user who has signed in with other providers, e.g. OAuth, please sign in again with the provider
+    /// then call [`Session::delete_account`].
+    ///
+    /// ## Arguments
+    /// - `password` - The password of the user.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::NotFoundAnyUserData` - Not found any user data in a response.
+    /// - `Error::NotSupportedForAnonymousUser` - The user is an anonymous user.
+    /// - `Error::PasswordProviderNotLinked` - The user has no email and password to sign in again with.
+    /// - `Error::InvalidCredentials` - The password is wrong.
+    /// - `Error::MfaRequired` - The multi-factor authentication is required to sign in again.
+    /// - `Error::AccountDisabled` - The user account has been disabled.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// session.delete_account_reauth(
+    ///     Password::new("password"),
+    /// ).await?;
+    /// ```
+    pub async fn delete_account_reauth(
+        self,
+        password: Password,
+    ) -> Result<()> {
+        let session = self
+            .reauthenticate_with_password(password)
+            .await?;
+
+        // Delete the account with the fresh tokens.
+        session
+            .delete_account_internal()
+            .await
+            .map_err(Error::map_reauthentication_required)
+    }
+
+    /// Signs in again with the password of the user by [`crate::Config::sign_in_with_email_password`].
+    ///
+    /// ## Arguments
+    /// - `password` - The password of the user.
+    ///
+    /// ## Returns
+    /// New session with the fresh tokens, carrying over the expected project ID and the last-known profile of this session.
+    async fn reauthenticate_with_password(
+        self,
+        password: Password,
+    ) -> Result<Session> {
+        if self.anonymous {
+            return Err(Error::NotSupportedForAnonymousUser {
+                operation: "reauthenticate with password",
+            });
+        }
+
+        // Get the email of the user.
+        let (session, user_data) = self.get_user_data().await?;
+        let has_password = user_data
+            .provider_user_info
+            .unwrap_or_default()
+            .iter()
+            .any(|info| info.typed_provider_id() == ProviderId::Password);
+        let email = match user_data.email {
+            | Some(email) if has_password => email,
+            | _ => return Err(Error::PasswordProviderNotLinked),
+        };
+
+        // Sign in again with the password on the same config.
+        let mut config = Config::new(session.api_key.clone())
+            .with_client(session.client.clone());
+        if let Some(project_id) = session
+            .expected_project_id
+            .clone()
+        {
+            config = config.with_expected_project_id(project_id);
+        }
+        let signed_in = config
+            .sign_in_with_email_password(Email::new(email), password)
+            .await?;
+
+        // Carry over the last-known profile of this session.
+        Ok(Session {
+            display_name: session.display_name,
+            photo_url: session.photo_url,
+            ..signed_in
+        })
     }
 
     /// Refreshes the ID token.
@@ -804,8 +1687,8 @@ impl Session {
     /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
     /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
     /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::AccountDisabled` - The user account has been disabled.
     /// - `Error::ApiError` - API error on the Firebase Auth.
-    /// - `Error::ParseExpriesInFailed` - Failed to parse the expires in value.
     ///
     /// ## Example
     /// ```
@@ -831,7 +1714,7 @@ impl Session {
         // Create request payload.
         let request_payload = api::ExchangeRefreshTokenRequestBodyPayload::new(
             self.refresh_token
-                .inner()
+                .expose_secret()
                 .to_string(),
         );
 
@@ -848,10 +1731,119 @@ impl Session {
             client: self.client.clone(),
             api_key: self.api_key.clone(),
             id_token: IdToken::new(response_payload.id_token),
-            expires_in: ExpiresIn::parse(response_payload.expires_in)?,
+            expires_in: response_payload.expires_in,
             refresh_token: RefreshToken::new(response_payload.refresh_token),
+            local_id: self.local_id.clone(),
+            first_sign_in: self.first_sign_in,
+            anonymous: self.anonymous,
+            display_name: self.display_name.clone(),
+            photo_url: self.photo_url.clone(),
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
         })
     }
+
+    /// Refreshes the ID token and returns the payload claims of the new ID token,
+    /// e.g. to pick up custom claims that have just been set by the Admin SDK.
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "verify" is enabled.
+    ///
+    /// ## WARNING
+    /// The claims are decoded WITHOUT any cryptographic verification of the ID token.
+    /// They are only suitable for client-side decisions, e.g. updating UI for new roles.
+    /// Servers must verify the ID token by [`crate::verification::VerificationConfig::verify_id_token`].
+    ///
+    /// ## Returns
+    /// New session to replace the consumed session and the payload claims of the new ID token.
+    ///
+    /// ## Errors
+    /// - `Error::InvalidHeaderValue` - Invalid header value.
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    /// - `Error::DecodeIdTokenFailed` - Failed to decode the new ID token.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::Email;
+    /// use fars::Password;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    /// let session = config.sign_in_with_email_password(
+    ///     Email::new("user@example"),
+    ///     Password::new("password"),
+    /// ).await?;
+    ///
+    /// let (new_session, claims) = session.refresh_and_get_claims().await?;
+    ///
+    /// let is_admin = claims
+    ///     .other_claims
+    ///     .get("admin")
+    ///     .and_then(|admin| admin.as_bool())
+    ///     .unwrap_or(false);
+    /// ```
+    #[cfg(feature = "verify")]
+    pub async fn refresh_and_get_claims(
+        self
+    ) -> Result<(
+        Self,
+        crate::verification::IdTokenPayloadClaims,
+    )> {
+        let session = self.refresh_token().await?;
+
+        let claims =
+            crate::verification::IdTokenPayloadClaims::decode_unverified(
+                &session.id_token,
+            )
+            .map_err(|error| Error::DecodeIdTokenFailed {
+                error,
+            })?;
+
+        Ok((session, claims))
+    }
+
+    /// Wraps this session of an ephemeral user to delete the account on drop,
+    /// e.g. in short-lived tools and test harnesses.
+    ///
+    /// See also [`crate::DeleteOnDrop`].
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "tokio" is enabled on native (non-`wasm32`) targets.
+    ///
+    /// ## WARNING
+    /// This **deletes the account** of the user on drop.
+    /// The account of a non-anonymous user is kept
+    /// unless [`crate::DeleteOnDrop::allow_non_anonymous`] is called.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let ephemeral = config
+    ///     .sign_in_anonymously()
+    ///     .await?
+    ///     .delete_on_drop();
+    /// ```
+    #[cfg(all(
+        feature = "tokio",
+        not(target_arch = "wasm32")
+    ))]
+    pub fn delete_on_drop(self) -> crate::DeleteOnDrop {
+        crate::DeleteOnDrop::new(self)
+    }
 }
 
 // Implements internal API callings for an `Session`.
@@ -860,18 +1852,51 @@ impl Session {
         &self,
         new_email: Email,
         locale: Option<LanguageCode>,
-    ) -> Result<()> {
+    ) -> Result<Self> {
         // Create request payload.
         let request_payload = api::ChangeEmailRequestBodyPayload::new(
             self.id_token
-                .inner()
+                .expose_secret()
                 .to_string(),
             new_email.inner().to_string(),
-            false,
+            true,
         );
 
         // Send request.
-        api::change_email(
+        let response_payload = api::change_email(
+            &self.client,
+            &self.api_key,
+            request_payload,
+            locale,
+        )
+        .await?;
+
+        // Update tokens.
+        Ok(self.update_tokens(
+            response_payload.id_token,
+            response_payload.expires_in,
+            response_payload.refresh_token,
+        ))
+    }
+
+    async fn verify_before_update_email_internal(
+        &self,
+        new_email: Email,
+        action_code_settings: Option<ActionCodeSettings>,
+        locale: Option<LanguageCode>,
+    ) -> Result<()> {
+        // Create request payload.
+        let request_payload =
+            api::VerifyBeforeUpdateEmailRequestBodyPayload::new(
+                self.id_token
+                    .expose_secret()
+                    .to_string(),
+                new_email.inner().to_string(),
+                action_code_settings,
+            );
+
+        // Send request.
+        api::verify_before_update_email(
             &self.client,
             &self.api_key,
             request_payload,
@@ -885,38 +1910,44 @@ impl Session {
     async fn change_password_internal(
         &self,
         new_password: Password,
-    ) -> Result<()> {
+    ) -> Result<Self> {
         // Create request payload.
         let request_payload = api::ChangePasswordRequestBodyPayload::new(
             self.id_token
-                .inner()
+                .expose_secret()
                 .to_string(),
             new_password
                 .inner()
                 .to_string(),
-            false,
+            true,
         );
 
         // Send request.
-        api::change_password(
+        let response_payload = api::change_password(
             &self.client,
             &self.api_key,
             request_payload,
         )
         .await?;
 
-        Ok(())
+        // Update tokens.
+        Ok(self.update_tokens(
+            response_payload.id_token,
+            response_payload.expires_in,
+            response_payload.refresh_token,
+        ))
     }
 
     async fn update_profile_internal(
         &self,
         display_name: Option<DisplayName>,
         photo_url: Option<PhotoUrl>,
-    ) -> Result<()> {
+        return_secure_token: bool,
+    ) -> Result<Self> {
         // Create request payload.
         let request_payload = api::UpdateProfileRequestBodyPayload::new(
             self.id_token
-                .inner()
+                .expose_secret()
                 .to_string(),
             display_name.map(|display_name| {
                 display_name
@@ -925,32 +1956,41 @@ impl Session {
             }),
             photo_url.map(|photo_url| photo_url.inner().to_string()),
             None,
-            false,
+            return_secure_token,
         );
 
         // Send request.
-        api::update_profile(
+        let response_payload = api::update_profile(
             &self.client,
             &self.api_key,
             request_payload,
         )
         .await?;
 
-        Ok(())
+        // Update tokens and the last-known profile.
+        Ok(Self {
+            display_name: response_payload.display_name,
+            photo_url: response_payload.photo_url,
+            ..self.update_tokens(
+                response_payload.id_token,
+                response_payload.expires_in,
+                response_payload.refresh_token,
+            )
+        })
     }
 
     async fn delete_profile_internal(
         &self,
         delete_attribute: HashSet<DeleteAttribute>,
-    ) -> Result<()> {
+    ) -> Result<Self> {
         // Create request payload.
         let request_payload = api::UpdateProfileRequestBodyPayload::new(
             self.id_token
-                .inner()
+                .expose_secret()
                 .to_string(),
             None,
             None,
-            Some(delete_attribute),
+            Some(delete_attribute.clone()),
             false,
         );
 
@@ -962,14 +2002,23 @@ impl Session {
         )
         .await?;
 
-        Ok(())
+        // Forget the deleted attributes of the last-known profile.
+        let mut session = self.clone();
+        if delete_attribute.contains(&DeleteAttribute::DisplayName) {
+            session.display_name = None;
+        }
+        if delete_attribute.contains(&DeleteAttribute::PhotoUrl) {
+            session.photo_url = None;
+        }
+
+        Ok(session)
     }
 
     async fn get_user_data_internal(&self) -> Result<UserData> {
         // Create request payload.
         let request_payload = api::GetUserDataRequestBodyPayload::new(
             self.id_token
-                .inner()
+                .expose_secret()
                 .to_string(),
         );
 
@@ -988,7 +2037,7 @@ impl Session {
             .ok_or(Error::NotFoundAnyUserData)?;
 
         Ok(UserData {
-            local_id: user.local_id.clone(),
+            local_id: Uid::new(user.local_id.clone()),
             email: user.email.clone(),
             email_verified: user.email_verified,
             display_name: user.display_name.clone(),
@@ -1004,6 +2053,8 @@ impl Session {
             created_at: user.created_at.clone(),
             last_refresh_at: user.last_refresh_at.clone(),
             custom_auth: user.custom_auth,
+            tenant_id: user.tenant_id.clone(),
+            custom_attributes: user.custom_attributes.clone(),
         })
     }
 
@@ -1015,7 +2066,7 @@ impl Session {
         // Create request payload.
         let request_payload = api::LinkWithEmailPasswordRequestBodyPayload::new(
             self.id_token
-                .inner()
+                .expose_secret()
                 .to_string(),
             email.inner().to_string(),
             password.inner().to_string(),
@@ -1034,8 +2085,16 @@ impl Session {
             client: self.client.clone(),
             api_key: self.api_key.clone(),
             id_token: IdToken::new(response_payload.id_token),
-            expires_in: ExpiresIn::parse(response_payload.expires_in)?,
+            expires_in: response_payload.expires_in,
             refresh_token: RefreshToken::new(response_payload.refresh_token),
+            local_id: self.local_id.clone(),
+            first_sign_in: self.first_sign_in,
+            anonymous: false,
+            display_name: self.display_name.clone(),
+            photo_url: self.photo_url.clone(),
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
         })
     }
 
@@ -1048,7 +2107,7 @@ impl Session {
         let request_payload =
             api::LinkWithOAuthCredentialRequestBodyPayload::new(
                 self.id_token
-                    .inner()
+                    .expose_secret()
                     .to_string(),
                 request_uri
                     .inner()
@@ -1070,8 +2129,16 @@ impl Session {
             client: self.client.clone(),
             api_key: self.api_key.clone(),
             id_token: IdToken::new(response_payload.id_token),
-            expires_in: ExpiresIn::parse(response_payload.expires_in)?,
+            expires_in: response_payload.expires_in,
             refresh_token: RefreshToken::new(response_payload.refresh_token),
+            local_id: self.local_id.clone(),
+            first_sign_in: self.first_sign_in,
+            anonymous: false,
+            display_name: self.display_name.clone(),
+            photo_url: self.photo_url.clone(),
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
         })
     }
 
@@ -1082,7 +2149,7 @@ impl Session {
         // Create request payload.
         let request_payload = api::UnlinkProviderRequestBodyPayload::new(
             self.id_token
-                .inner()
+                .expose_secret()
                 .to_string(),
             delete_provider,
         );
@@ -1100,17 +2167,19 @@ impl Session {
 
     async fn send_email_verification_internal(
         &self,
+        action_code_settings: Option<ActionCodeSettings>,
         locale: Option<LanguageCode>,
-    ) -> Result<()> {
+    ) -> Result<SentOobCode> {
         // Create request payload.
         let request_payload = api::SendEmailVerificationRequestBodyPayload::new(
             self.id_token
-                .inner()
+                .expose_secret()
                 .to_string(),
+            action_code_settings,
         );
 
         // Send request.
-        api::send_email_verification(
+        let response_payload = api::send_email_verification(
             &self.client,
             &self.api_key,
             request_payload,
@@ -1118,14 +2187,19 @@ impl Session {
         )
         .await?;
 
-        Ok(())
+        Ok(SentOobCode {
+            oob_code: response_payload
+                .oob_code
+                .map(OobCode::new),
+            oob_link: response_payload.oob_link,
+        })
     }
 
     async fn delete_account_internal(&self) -> Result<()> {
         // Create request payload.
         let request_payload = api::DeleteAccountRequestBodyPayload::new(
             self.id_token
-                .inner()
+                .expose_secret()
                 .to_string(),
         );
 
@@ -1139,4 +2213,109 @@ impl Session {
 
         Ok(())
     }
+
+    /// Creates a new session with tokens in a response, keeping current tokens that are not in the response.
+    fn update_tokens(
+        &self,
+        id_token: Option<String>,
+        expires_in: Option<ExpiresIn>,
+        refresh_token: Option<String>,
+    ) -> Self {
+        Self {
+            client: self.client.clone(),
+            api_key: self.api_key.clone(),
+            id_token: id_token
+                .map(IdToken::new)
+                .unwrap_or_else(|| self.id_token.clone()),
+            expires_in: expires_in.unwrap_or(self.expires_in),
+            refresh_token: refresh_token
+                .map(RefreshToken::new)
+                .unwrap_or_else(|| self.refresh_token.clone()),
+            local_id: self.local_id.clone(),
+            first_sign_in: self.first_sign_in,
+            anonymous: self.anonymous,
+            display_name: self.display_name.clone(),
+            photo_url: self.photo_url.clone(),
+            expected_project_id: self
+                .expected_project_id
+                .clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+
+    use super::*;
+
+    /// Creates a session with an unsigned ID token of the claims.
+    fn session_with_claims(claims: serde_json::Value) -> Session {
+        let encode = |value: serde_json::Value| {
+            base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .encode(value.to_string())
+        };
+        let id_token = format!(
+            "{}.{}.",
+            encode(serde_json::json!({ "alg": "none", "typ": "JWT" })),
+            encode(claims),
+        );
+
+        Session {
+            client: Client::new(),
+            api_key: ApiKey::new("api-key"),
+            id_token: IdToken::new(id_token),
+            expires_in: ExpiresIn::parse("3600".to_string()).unwrap(),
+            refresh_token: RefreshToken::new("refresh-token"),
+            local_id: Uid::new("uid"),
+            first_sign_in: false,
+            anonymous: false,
+            display_name: None,
+            photo_url: None,
+            expected_project_id: None,
+        }
+    }
+
+    #[test]
+    fn signed_in_anonymously_hint_by_sign_in_provider() {
+        let anonymous = session_with_claims(serde_json::json!({
+            "firebase": { "sign_in_provider": "anonymous" },
+        }));
+        let password = session_with_claims(serde_json::json!({
+            "firebase": { "sign_in_provider": "password" },
+        }));
+        let missing = session_with_claims(serde_json::json!({}));
+
+        assert!(anonymous.signed_in_anonymously_hint());
+        assert!(!password.signed_in_anonymously_hint());
+        assert!(!missing.signed_in_anonymously_hint());
+    }
+
+    #[test]
+    fn session_from_tokens_restores_anonymity() {
+        let config = Config::new(ApiKey::new("api-key"));
+        let from_tokens = |claims: serde_json::Value| {
+            config
+                .session_from_tokens(
+                    session_with_claims(claims).id_token,
+                    RefreshToken::new("refresh-token"),
+                    "3600".to_string(),
+                    Uid::new("uid"),
+                )
+                .unwrap()
+        };
+
+        assert!(
+            from_tokens(serde_json::json!({
+                "firebase": { "sign_in_provider": "anonymous" },
+            }))
+            .anonymous
+        );
+        assert!(
+            !from_tokens(serde_json::json!({
+                "firebase": { "sign_in_provider": "password" },
+            }))
+            .anonymous
+        );
+    }
 }
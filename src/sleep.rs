@@ -0,0 +1,58 @@
+//! Sleep functions to wait for a while, e.g. the backoff of [`crate::RetryPolicy`]
+//! and the polling of the Device Code grant type of the OAuth 2.0.
+//!
+//! This crate does not depend on any async runtime,
+//! so the features that wait for a while take a sleep function of your runtime as `Fn(Duration) -> impl Future<Output = ()>`:
+//!
+//! - [`crate::RetryPolicy::new`]
+//! - `crate::oauth::DeviceCodeSession::poll_exchange_token` (optional feature: `oauth`)
+//! - `crate::oauth::FacebookDeviceCodeSession::poll_exchange_token` (optional feature: `oauth`)
+//!
+//! The sleep function of [tokio](https://github.com/tokio-rs/tokio) is provided by `tokio_sleep` with the optional feature `tokio`.
+//!
+//! ## Examples
+//! ```
+//! use std::time::Duration;
+//! use fars::RetryPolicy;
+//!
+//! // tokio
+//! let retry_policy = RetryPolicy::new(tokio::time::sleep);
+//!
+//! // async-std
+//! let retry_policy = RetryPolicy::new(async_std::task::sleep);
+//!
+//! // smol
+//! let retry_policy = RetryPolicy::new(|duration: Duration| async move {
+//!     smol::Timer::after(duration).await;
+//! });
+//! ```
+
+#[cfg(all(
+    feature = "tokio",
+    not(target_arch = "wasm32")
+))]
+use std::time::Duration;
+
+/// Sleeps for the duration by [tokio](https://github.com/tokio-rs/tokio).
+///
+/// ## NOTE
+/// This is only available when the feature "tokio" is enabled on native targets,
+/// and requires a tokio runtime with the time driver enabled.
+///
+/// ## Arguments
+/// - `duration` - The duration to sleep.
+///
+/// ## Example
+/// ```
+/// use fars::sleep::tokio_sleep;
+/// use fars::RetryPolicy;
+///
+/// let retry_policy = RetryPolicy::new(tokio_sleep);
+/// ```
+#[cfg(all(
+    feature = "tokio",
+    not(target_arch = "wasm32")
+))]
+pub async fn tokio_sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
@@ -0,0 +1,43 @@
+//! Helpers for integration tests against the Firebase Auth.
+//!
+//! ## NOTE
+//! This feature is only available when the feature `testing` is enabled on native (non-`wasm32`) targets.
+//!
+//! Helpers in this module call the Firebase Auth REST API,
+//! so they require a network connection and a real (or emulated) Firebase project
+//! that enables the email / password sign-in provider.
+//!
+//! ## Examples
+//! An example of an integration test with an ephemeral user with [tokio](https://github.com/tokio-rs/tokio) and [anyhow](https://github.com/dtolnay/anyhow) is as follows:
+//!
+//! ```rust
+//! use fars::Config;
+//! use fars::ApiKey;
+//! use fars::testing::TestUser;
+//!
+//! #[tokio::test]
+//! async fn get_user_data() -> anyhow::Result<()> {
+//!     // Create a config.
+//!     let config = Config::new(
+//!         ApiKey::new("your-firebase-project-api-key"),
+//!     );
+//!
+//!     // Sign up an ephemeral user.
+//!     let user = TestUser::new(&config).await?;
+//!
+//!     // Do something with the session of the user.
+//!     let (_session, user_data) = user
+//!         .session()
+//!         .clone()
+//!         .get_user_data()
+//!         .await?;
+//!     assert_eq!(user_data.email.as_deref(), Some(user.email_str()));
+//!
+//!     // The user is deleted when `user` is dropped.
+//!     Ok(())
+//! }
+//! ```
+
+mod test_user;
+
+pub use test_user::TestUser;
@@ -0,0 +1,162 @@
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use crate::Config;
+use crate::DeleteOnDrop;
+use crate::Email;
+use crate::Password;
+use crate::Result;
+use crate::Session;
+
+/// A counter to avoid collisions of generated emails in the same process.
+static USER_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// An ephemeral user for integration tests.
+///
+/// Signs up a user with a random email and password on construction
+/// and deletes the account on drop by [`DeleteOnDrop`].
+///
+/// ## NOTE
+/// This is only available when the feature "testing" is enabled.
+///
+/// Deleting the account on drop is best-effort as described in [`DeleteOnDrop`].
+/// Call [`TestUser::delete`] to delete the account deterministically.
+///
+/// ## Example
+/// ```
+/// use fars::Config;
+/// use fars::ApiKey;
+/// use fars::testing::TestUser;
+///
+/// let config = Config::new(
+///     ApiKey::new("your-firebase-project-api-key"),
+/// );
+///
+/// let user = TestUser::new(&config).await?;
+///
+/// let session = user.session().clone();
+/// ```
+#[derive(Debug)]
+pub struct TestUser {
+    /// The email of the user.
+    email: Email,
+    /// The password of the user.
+    password: Password,
+    /// The session of the user to delete on drop.
+    session: DeleteOnDrop,
+}
+
+impl TestUser {
+    /// Signs up a new ephemeral user with a random email and password.
+    ///
+    /// ## Arguments
+    /// - `config` - The config of the Firebase project to sign up the user.
+    ///
+    /// ## Returns
+    /// The ephemeral user.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::testing::TestUser;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let user = TestUser::new(&config).await?;
+    /// ```
+    pub async fn new(config: &Config) -> Result<Self> {
+        let seed = random_seed();
+        let email = Email::new(format!(
+            "fars-test-{:x}@example.com",
+            seed
+        ));
+        let password = Password::new(format!("fars-test-password-{:x}", seed));
+
+        // Sign up the user.
+        let session = config
+            .sign_up_with_email_password(email.clone(), password.clone())
+            .await?;
+
+        Ok(Self {
+            email,
+            password,
+            session: session
+                .delete_on_drop()
+                .allow_non_anonymous(),
+        })
+    }
+
+    /// Returns the email of the user.
+    pub fn email(&self) -> &Email {
+        &self.email
+    }
+
+    /// Returns the email of the user as a string.
+    pub fn email_str(&self) -> &str {
+        self.email.inner()
+    }
+
+    /// Returns the password of the user.
+    pub fn password(&self) -> &Password {
+        &self.password
+    }
+
+    /// Returns the session of the user.
+    pub fn session(&self) -> &Session {
+        self.session.session()
+    }
+
+    /// Deletes the user immediately instead of on drop.
+    ///
+    /// ## Errors
+    /// - `Error::HttpRequestError` - Failed to send a request.
+    /// - `Error::ReadResponseTextFailed` - Failed to read the response body as text.
+    /// - `Error::DeserializeResponseJsonFailed` - Failed to deserialize the response body as JSON.
+    /// - `Error::DeserializeErrorResponseJsonFailed` - Failed to deserialize the error response body as JSON.
+    /// - `Error::InvalidIdToken` - Invalid ID token.
+    /// - `Error::ApiError` - API error on the Firebase Auth.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::Config;
+    /// use fars::ApiKey;
+    /// use fars::testing::TestUser;
+    ///
+    /// let config = Config::new(
+    ///     ApiKey::new("your-firebase-project-api-key"),
+    /// );
+    ///
+    /// let user = TestUser::new(&config).await?;
+    ///
+    /// user.delete().await?;
+    /// ```
+    pub async fn delete(self) -> Result<()> {
+        self.session.delete().await
+    }
+}
+
+/// Generates a seed to make a unique email and password.
+fn random_seed() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or_default();
+    let count = USER_COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    // Mix the process ID and the counter into the timestamp.
+    nanos
+        ^ ((std::process::id() as u64) << 32)
+        ^ count.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
@@ -5,6 +5,24 @@
 //! ## NOTE
 //! This feature is only available when the feature `verify` is enabled.
 //!
+//! ## Key sources
+//! The public keys are fetched from the Google API by [`crate::verification::GoogleKeySource`] by default.
+//! Implement [`crate::verification::KeySource`] and set it by [`crate::verification::VerificationConfig::with_key_source`]
+//! to get the public keys from other sources, e.g. a local mirror of the JWKS or fixed keys for tests.
+//!
+//! The fetched public keys are cached by the configuration until the max age of the key set,
+//! e.g. by the `max-age` of the `Cache-Control` header of the Google API,
+//! and shared by the cloned configurations. The key sets without the max age are not cached.
+//!
+//! ## Issuers and audiences
+//! The issuer (`iss`) and the audience (`aud`) of the ID token are expected to be `https://securetoken.google.com/{project_id}` and the project ID by default.
+//! Set them by [`crate::verification::VerificationConfig::with_issuers`] and [`crate::verification::VerificationConfig::with_audiences`]
+//! to verify the tokens issued on a custom domain or an Identity Platform tenant.
+//!
+//! ## Firebase Auth Emulator
+//! The Firebase Auth Emulator issues unsigned (`alg: none`) ID tokens, which are rejected by default.
+//! Enable the feature `emulator` and opt in by [`crate::emulator::EmulatorConfig::verification_config`] to verify them in test suites.
+//!
 //! ## Examples
 //! An example of ID token verification with [tokio](https://github.com/tokio-rs/tokio) and [anyhow](https://github.com/dtolnay/anyhow) is as follows:
 //!
@@ -38,12 +56,24 @@
 //! }
 //! ```
 
+mod authenticated_user;
+mod firebase_claims;
 mod id_token_payload_claim;
+mod key_source;
+mod public_key_set;
 mod verification_config;
 mod verification_error;
+mod verification_metadata;
 mod verification_result;
 
+pub use authenticated_user::AuthenticatedUser;
+pub use firebase_claims::FirebaseClaims;
 pub use id_token_payload_claim::IdTokenPayloadClaims;
+pub use key_source::GoogleKeySource;
+pub use key_source::KeySource;
+pub use key_source::KeySourceFuture;
+pub use public_key_set::PublicKeySet;
 pub use verification_config::VerificationConfig;
 pub use verification_error::VerificationError;
+pub use verification_metadata::VerificationMetadata;
 pub use verification_result::VerificationResult;
@@ -0,0 +1,39 @@
+use crate::verification::IdTokenPayloadClaims;
+use crate::Uid;
+
+/// An authenticated user of a verified ID token, e.g. to put into the request extensions of a web middleware.
+///
+/// See also [`crate::verification::VerificationConfig::authenticate`].
+///
+/// ## NOTE
+/// This is only available when the feature "verify" is enabled.
+#[derive(Debug)]
+pub struct AuthenticatedUser {
+    /// The uid of the user, i.e. the subject (`sub`) of the ID token.
+    pub uid: Uid,
+    /// The email of the user if any.
+    pub email: Option<String>,
+    /// Whether the email of the user has been verified, `false` if the ID token omits it.
+    pub email_verified: bool,
+    /// All claims of the verified ID token, e.g. to check custom claims set by the Admin SDK.
+    pub claims: IdTokenPayloadClaims,
+}
+
+impl From<IdTokenPayloadClaims> for AuthenticatedUser {
+    fn from(claims: IdTokenPayloadClaims) -> Self {
+        Self {
+            uid: claims.sub.clone(),
+            email: claims
+                .other_claims
+                .get("email")
+                .and_then(serde_json::Value::as_str)
+                .map(str::to_string),
+            email_verified: claims
+                .other_claims
+                .get("email_verified")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false),
+            claims,
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::ProviderId;
+
+/// The `firebase` claim of an ID token for the Firebase Auth,
+/// which describes how the user has signed in.
+///
+/// See also [`crate::verification::IdTokenPayloadClaims::firebase`].
+///
+/// ## NOTE
+/// This is only available when the feature "verify" is enabled.
+///
+/// ## Example
+/// ```
+/// use fars::verification::VerificationConfig;
+/// use fars::ProjectId;
+/// use fars::ProviderId;
+/// use fars::IdToken;
+///
+/// let config = VerificationConfig::new(
+///     ProjectId::new("firebase-project-id"),
+/// );
+///
+/// let claims = config.verify_id_token(
+///     &IdToken::new("id-token"),
+/// ).await?;
+///
+/// let signed_in_with_google = claims
+///     .firebase
+///     .map(|firebase| firebase.typed_sign_in_provider() == ProviderId::Google)
+///     .unwrap_or(false);
+/// ```
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct FirebaseClaims {
+    /// The sign in provider used to obtain the ID token, e.g. `password`, `google.com`, `anonymous` and `custom`.
+    pub sign_in_provider: String,
+    /// The identities of the user by the provider ID, e.g. `{"email": ["user@example.com"]}`.
+    #[serde(default)]
+    pub identities: HashMap<String, Vec<String>>,
+    /// The tenant ID of the user on the Identity Platform if any.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tenant: Option<String>,
+    /// The second factor used to sign in if any, e.g. `phone`.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub sign_in_second_factor: Option<String>,
+    /// Other claims in the `firebase` claim.
+    #[serde(flatten)]
+    pub other_claims: HashMap<String, serde_json::Value>,
+}
+
+impl FirebaseClaims {
+    /// Returns the typed provider ID of the sign in provider.
+    ///
+    /// ## NOTE
+    /// Providers without a variant of [`ProviderId`], e.g. `custom` of a custom token,
+    /// are parsed as [`ProviderId::Custom`].
+    pub fn typed_sign_in_provider(&self) -> ProviderId {
+        ProviderId::parse(self.sign_in_provider.clone())
+    }
+}
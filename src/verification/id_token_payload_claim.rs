@@ -1,5 +1,12 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
+use crate::verification::FirebaseClaims;
+use crate::verification::VerificationError;
+use crate::IdToken;
+use crate::Uid;
+
 /// ID token payload claims for the Firebase Auth.
 ///
 /// See also [document](https://firebase.google.com/docs/auth/admin/verify-id-tokens#verify_id_tokens_using_a_third-party_jwt_library).
@@ -24,9 +31,58 @@ pub struct IdTokenPayloadClaims {
     pub iss: String,
     /// Subject.
     /// Must be a non-empty string and must be the uid of the user or device.
-    pub sub: String,
+    pub sub: Uid,
     /// Authentication time.
     /// Must be in the past.
     /// The time when the user authenticated.
     pub auth_time: u64,
+    /// The `firebase` claim that describes how the user has signed in, e.g. the sign in provider.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub firebase: Option<FirebaseClaims>,
+    /// Other claims in the payload,
+    /// e.g. `email` and custom claims set by the Admin SDK.
+    #[serde(flatten)]
+    pub other_claims: HashMap<String, serde_json::Value>,
+}
+
+impl IdTokenPayloadClaims {
+    /// Decodes the payload claims of an ID token without any verification.
+    ///
+    /// ## WARNING
+    /// This does NOT verify the signature, the expiration, the audience and the issuer of the ID token,
+    /// so never trust the decoded claims for authorization on a server.
+    /// Use [`crate::verification::VerificationConfig::verify_id_token`] instead.
+    ///
+    /// ## Arguments
+    /// - `id_token` - An ID token of the Firebase Auth.
+    ///
+    /// ## Returns
+    /// Decoded ID token payload claims.
+    ///
+    /// ## Errors
+    /// - `VerificationError::DecodeTokenFailed` - Failed to decode the ID token.
+    pub(crate) fn decode_unverified(
+        id_token: &IdToken
+    ) -> Result<Self, VerificationError> {
+        let mut validation =
+            jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::RS256);
+        validation.insecure_disable_signature_validation();
+        validation.validate_exp = false;
+        validation.validate_aud = false;
+        validation
+            .required_spec_claims
+            .clear();
+
+        let decoded = jsonwebtoken::decode::<Self>(
+            id_token.expose_secret(),
+            &jsonwebtoken::DecodingKey::from_secret(&[]),
+            &validation,
+        )
+        .map_err(VerificationError::DecodeTokenFailed)?;
+
+        Ok(decoded.claims)
+    }
 }
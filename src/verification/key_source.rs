@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use crate::verification::PublicKeySet;
+use crate::verification::VerificationError;
+use crate::Client;
+
+/// The future returned by [`KeySource::keys`].
+pub type KeySourceFuture<'a> = Pin<
+    Box<
+        dyn Future<Output = Result<PublicKeySet, VerificationError>>
+            + Send
+            + 'a,
+    >,
+>;
+
+/// A source of the public keys to verify ID tokens,
+/// e.g. the Google API, a local mirror of the JWKS and fixed keys for tests.
+///
+/// The default source is [`GoogleKeySource`].
+///
+/// See also [`crate::verification::VerificationConfig::with_key_source`].
+///
+/// ## NOTE
+/// This is only available when the feature "verify" is enabled.
+///
+/// ## Example
+/// ```
+/// use fars::verification::KeySource;
+/// use fars::verification::KeySourceFuture;
+/// use fars::verification::PublicKeySet;
+///
+/// #[derive(Debug)]
+/// struct FixedKeySource {
+///     keys: PublicKeySet,
+/// }
+///
+/// impl KeySource for FixedKeySource {
+///     fn keys(&self) -> KeySourceFuture<'_> {
+///         Box::pin(async move { Ok(self.keys.clone()) })
+///     }
+/// }
+/// ```
+pub trait KeySource: Debug + Send + Sync {
+    /// Gets the public keys to verify ID tokens.
+    ///
+    /// ## Errors
+    /// [`VerificationError`] if failed to get the public keys,
+    /// e.g. [`VerificationError::KeySourceFailed`] for custom sources.
+    fn keys(&self) -> KeySourceFuture<'_>;
+}
+
+/// The default [`KeySource`] that fetches the X.509 certificates from the [public keys list](https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com) of the Google API.
+///
+/// The transient failures, i.e. timeouts, transport errors and `429` or `5xx` status codes,
+/// are retried by the retry policy of the client if any. See also [`crate::Client::with_retry_policy`].
+///
+/// ## NOTE
+/// This is only available when the feature "verify" is enabled.
+#[derive(Clone, Debug)]
+pub struct GoogleKeySource {
+    /// A HTTP client.
+    client: Client,
+    /// Timeout to fetch the public keys.
+    timeout: Duration,
+}
+
+impl GoogleKeySource {
+    /// The URL of the public keys list.
+    const URL: &'static str = "https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com";
+
+    /// Creates a new key source of the Google API.
+    ///
+    /// ## Arguments
+    /// - `client` - A HTTP client.
+    /// - `timeout` - Timeout to fetch the public keys.
+    pub fn new(
+        client: Client,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            client,
+            timeout,
+        }
+    }
+}
+
+impl KeySource for GoogleKeySource {
+    fn keys(&self) -> KeySourceFuture<'_> {
+        Box::pin(async move {
+            let Some(retry_policy) = self.client.retry_policy() else {
+                return self.fetch_keys().await;
+            };
+
+            let mut retry = 0;
+            loop {
+                match self.fetch_keys().await {
+                    | Err(error)
+                        if is_transient(&error)
+                            && retry_policy
+                                .wait_transient(retry)
+                                .await =>
+                    {
+                        retry += 1;
+                    },
+                    | result => return result,
+                }
+            }
+        })
+    }
+}
+
+impl GoogleKeySource {
+    /// Fetches the public keys from the Google API once.
+    async fn fetch_keys(&self) -> Result<PublicKeySet, VerificationError> {
+        // Get public key list from the Google API.
+        let response = self
+            .client
+            .inner()
+            .get(Self::URL)
+            .timeout(self.timeout)
+            .send()
+            .await
+            .map_err(|error| match error.is_timeout() {
+                | true => VerificationError::KeyFetchTimeout(error),
+                | false => VerificationError::HttpRequestError(error),
+            })?;
+
+        // Verify status code of the response.
+        if response.status() != reqwest::StatusCode::OK {
+            return Err(
+                VerificationError::InvalidResponseStatusCode(response.status()),
+            );
+        }
+
+        // Get max age of the public keys from the cache control header.
+        let max_age = response
+            .headers()
+            .get(reqwest::header::CACHE_CONTROL)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_max_age);
+
+        // Deserialize the response JSON.
+        let certificates = response
+            .json::<HashMap<String, String>>()
+            .await
+            .map_err(|error| match error.is_timeout() {
+                | true => VerificationError::KeyFetchTimeout(error),
+                | false => {
+                    VerificationError::DeserializeResponseJsonFailed(error)
+                },
+            })?;
+
+        Ok(
+            PublicKeySet::from_x509_certificates(certificates)?
+                .with_max_age(max_age),
+        )
+    }
+}
+
+/// Checks whether the failure to fetch the public keys is transient and worth retrying.
+fn is_transient(error: &VerificationError) -> bool {
+    match error {
+        | VerificationError::KeyFetchTimeout(_)
+        | VerificationError::HttpRequestError(_) => true,
+        | VerificationError::InvalidResponseStatusCode(status_code) => {
+            status_code.is_server_error()
+                || *status_code == reqwest::StatusCode::TOO_MANY_REQUESTS
+        },
+        | _ => false,
+    }
+}
+
+/// Parses the `max-age` directive of a `Cache-Control` header value.
+///
+/// ## Arguments
+/// - `cache_control` - A value of the `Cache-Control` header, e.g. `public, max-age=19302, must-revalidate, no-transform`.
+///
+/// ## Returns
+/// The max age if the directive is specified.
+fn parse_max_age(cache_control: &str) -> Option<Duration> {
+    cache_control
+        .split(',')
+        .find_map(|directive| {
+            directive
+                .trim()
+                .strip_prefix("max-age=")
+        })
+        .and_then(|seconds| seconds.parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_transient_retries_server_errors_and_rate_limiting() {
+        for status_code in [
+            reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+            reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            reqwest::StatusCode::TOO_MANY_REQUESTS,
+        ] {
+            assert!(is_transient(
+                &VerificationError::InvalidResponseStatusCode(status_code)
+            ));
+        }
+
+        for status_code in [
+            reqwest::StatusCode::NOT_FOUND,
+            reqwest::StatusCode::FORBIDDEN,
+        ] {
+            assert!(!is_transient(
+                &VerificationError::InvalidResponseStatusCode(status_code)
+            ));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::verification::VerificationError;
+
+/// A set of the public keys to verify ID tokens, provided by a [`crate::verification::KeySource`].
+///
+/// ## NOTE
+/// This is only available when the feature "verify" is enabled.
+///
+/// ## Example
+/// ```
+/// use std::collections::HashMap;
+/// use fars::verification::PublicKeySet;
+///
+/// let keys = PublicKeySet::from_x509_certificates(HashMap::from([(
+///     "kid".to_string(),
+///     "-----BEGIN CERTIFICATE-----\n...\n-----END CERTIFICATE-----\n".to_string(),
+/// )]))?;
+/// ```
+#[derive(Clone)]
+pub struct PublicKeySet {
+    /// The decoding keys by the key ID (`kid`).
+    pub(crate) keys: HashMap<String, jsonwebtoken::DecodingKey>,
+    /// How long the public keys are valid.
+    pub(crate) max_age: Option<Duration>,
+}
+
+impl std::fmt::Debug for PublicKeySet {
+    fn fmt(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        f.debug_struct("PublicKeySet")
+            .field(
+                "kids",
+                &self
+                    .keys
+                    .keys()
+                    .collect::<Vec<_>>(),
+            )
+            .field("max_age", &self.max_age)
+            .finish()
+    }
+}
+
+impl PublicKeySet {
+    /// Creates a new public key set from the X.509 certificates in PEM by the key ID (`kid`),
+    /// e.g. of the [public keys list](https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com).
+    ///
+    /// ## Arguments
+    /// - `certificates` - The X.509 certificates in PEM by the key ID.
+    ///
+    /// ## Errors
+    /// - `VerificationError::GetDecodingKeyFailed` - Failed to get a decoding key from a certificate.
+    pub fn from_x509_certificates(
+        certificates: HashMap<String, String>
+    ) -> Result<Self, VerificationError> {
+        let keys = certificates
+            .into_iter()
+            .map(|(kid, certificate)| {
+                jsonwebtoken::DecodingKey::from_rsa_pem(certificate.as_bytes())
+                    .map(|key| (kid, key))
+                    .map_err(VerificationError::GetDecodingKeyFailed)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            keys,
+            max_age: None,
+        })
+    }
+
+    /// Creates a new public key set from a JSON Web Key Set (JWKS) in JSON,
+    /// e.g. of the [JWKS](https://www.googleapis.com/service_accounts/v1/jwk/securetoken@system.gserviceaccount.com) or its local mirror.
+    ///
+    /// Keys without the key ID (`kid`) are ignored.
+    ///
+    /// ## Arguments
+    /// - `json` - The JSON Web Key Set in JSON.
+    ///
+    /// ## Errors
+    /// - `VerificationError::DeserializeJwkSetFailed` - Failed to deserialize the JSON Web Key Set.
+    /// - `VerificationError::GetDecodingKeyFailed` - Failed to get a decoding key from a JSON Web Key.
+    pub fn from_jwk_set_json(json: &str) -> Result<Self, VerificationError> {
+        let jwk_set = serde_json::from_str::<jsonwebtoken::jwk::JwkSet>(json)
+            .map_err(VerificationError::DeserializeJwkSetFailed)?;
+
+        let keys = jwk_set
+            .keys
+            .iter()
+            .filter_map(|jwk| {
+                jwk.common
+                    .key_id
+                    .clone()
+                    .map(|kid| (kid, jwk))
+            })
+            .map(|(kid, jwk)| {
+                jsonwebtoken::DecodingKey::from_jwk(jwk)
+                    .map(|key| (kid, key))
+                    .map_err(VerificationError::GetDecodingKeyFailed)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            keys,
+            max_age: None,
+        })
+    }
+
+    /// Sets how long the public keys are valid, e.g. by the `max-age` of the `Cache-Control` header.
+    ///
+    /// ## Arguments
+    /// - `max_age` - How long the public keys are valid.
+    pub fn with_max_age(
+        self,
+        max_age: Option<Duration>,
+    ) -> Self {
+        Self {
+            keys: self.keys,
+            max_age,
+        }
+    }
+
+    /// Returns how long the public keys are valid.
+    pub fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+}
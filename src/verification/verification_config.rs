@@ -1,7 +1,15 @@
-use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
 
+use crate::verification::AuthenticatedUser;
+use crate::verification::GoogleKeySource;
 use crate::verification::IdTokenPayloadClaims;
+use crate::verification::KeySource;
+use crate::verification::PublicKeySet;
 use crate::verification::VerificationError;
+use crate::verification::VerificationMetadata;
 use crate::verification::VerificationResult;
 use crate::Client;
 use crate::IdToken;
@@ -21,14 +29,120 @@ use crate::ProjectId;
 ///     ProjectId::new("firebase-project-id"),
 /// );
 /// ```
+///
+/// ## Sharing
+/// A configuration holds a HTTP client that pools connections to the Google API,
+/// so please create it once and share it by [`Clone`] instead of creating it for each verification.
+///
+/// Cloning is cheap because the internal states are shared by [`Arc`],
+/// and cloned configurations reuse the same HTTP client and the cache of the public keys.
+///
+/// ```
+/// use fars::verification::VerificationConfig;
+/// use fars::ProjectId;
+/// use fars::IdToken;
+///
+/// let config = VerificationConfig::new(
+///     ProjectId::new("firebase-project-id"),
+/// );
+///
+/// // Share the config between tasks.
+/// let shared = config.clone();
+/// tokio::spawn(async move {
+///     let claims = shared.verify_id_token(
+///         &IdToken::new("id-token"),
+///     ).await;
+/// });
+/// ```
+#[derive(Clone, Debug)]
 pub struct VerificationConfig {
+    /// Shared internal states.
+    inner: Arc<VerificationConfigInner>,
+}
+
+/// The cached public keys and the time when they have been fetched.
+type KeyCache = Mutex<Option<(Arc<PublicKeySet>, Instant)>>;
+
+/// Internal states of the [`VerificationConfig`].
+#[derive(Clone, Debug)]
+struct VerificationConfigInner {
     /// A HTTP client.
     client: Client,
     /// Your project ID of the Firebase project.
     project_id: ProjectId,
+    /// Timeout to fetch the public keys.
+    key_fetch_timeout: Duration,
+    /// A custom source of the public keys, or the [`GoogleKeySource`] if none.
+    key_source: Option<Arc<dyn KeySource>>,
+    /// The host of the Firebase Auth Emulator to accept the unsigned ID tokens issued by it.
+    unsigned_emulator_host: Option<String>,
+    /// The expected issuers of the ID token, or the one derived from the project ID if none.
+    issuers: Option<Vec<String>>,
+    /// The expected audiences of the ID token, or the project ID if none.
+    audiences: Option<Vec<String>>,
+    /// The cached public keys and the time when they have been fetched, shared by the cloned configurations.
+    key_cache: Arc<KeyCache>,
+}
+
+impl VerificationConfigInner {
+    /// Returns the expected issuers of the ID token.
+    fn expected_issuers(&self) -> Vec<String> {
+        match &self.issuers {
+            | Some(issuers) => issuers.clone(),
+            | None => vec![format!(
+                "https://securetoken.google.com/{}",
+                self.project_id.inner()
+            )],
+        }
+    }
+
+    /// Returns the cached public keys if they are within the max age.
+    fn cached_keys(&self) -> Option<Arc<PublicKeySet>> {
+        let cache = self
+            .key_cache
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        match &*cache {
+            | Some((keys, fetched_at)) => match keys.max_age {
+                | Some(max_age) if fetched_at.elapsed() < max_age => {
+                    Some(keys.clone())
+                },
+                | _ => None,
+            },
+            | None => None,
+        }
+    }
+
+    /// Caches all the public keys of the set until the max age.
+    fn cache_keys(
+        &self,
+        keys: Arc<PublicKeySet>,
+    ) {
+        let mut cache = self
+            .key_cache
+            .lock()
+            .unwrap_or_else(|error| error.into_inner());
+
+        *cache = Some((keys, Instant::now()));
+    }
+
+    /// Returns the expected audiences of the ID token.
+    fn expected_audiences(&self) -> Vec<String> {
+        match &self.audiences {
+            | Some(audiences) => audiences.clone(),
+            | None => vec![self
+                .project_id
+                .inner()
+                .to_string()],
+        }
+    }
 }
 
 impl VerificationConfig {
+    /// The default timeout to fetch the public keys: 10 seconds.
+    pub const DEFAULT_KEY_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
     /// Creates a new configuration for the ID token verification.
     ///
     /// ## NOTE
@@ -48,8 +162,16 @@ impl VerificationConfig {
     /// ```
     pub fn new(project_id: ProjectId) -> Self {
         Self {
-            client: Client::new(),
-            project_id,
+            inner: Arc::new(VerificationConfigInner {
+                client: Client::new(),
+                project_id,
+                key_fetch_timeout: Self::DEFAULT_KEY_FETCH_TIMEOUT,
+                key_source: None,
+                unsigned_emulator_host: None,
+                issuers: None,
+                audiences: None,
+                key_cache: Arc::new(Mutex::new(None)),
+            }),
         }
     }
 
@@ -84,11 +206,238 @@ impl VerificationConfig {
         project_id: ProjectId,
     ) -> Self {
         Self {
-            client,
-            project_id,
+            inner: Arc::new(VerificationConfigInner {
+                client,
+                project_id,
+                key_fetch_timeout: Self::DEFAULT_KEY_FETCH_TIMEOUT,
+                key_source: None,
+                unsigned_emulator_host: None,
+                issuers: None,
+                audiences: None,
+                key_cache: Arc::new(Mutex::new(None)),
+            }),
+        }
+    }
+
+    /// Sets the timeout to fetch the public keys from the Google API.
+    ///
+    /// The default is [`VerificationConfig::DEFAULT_KEY_FETCH_TIMEOUT`].
+    /// This prevents the verification from blocking under a network partition
+    /// until the timeout of the HTTP client, which is none by default.
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "verify" is enabled.
+    ///
+    /// This does not affect a custom key source set by [`VerificationConfig::with_key_source`].
+    ///
+    /// ## Arguments
+    /// - `timeout` - Timeout to fetch the public keys.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fars::verification::VerificationConfig;
+    /// use fars::ProjectId;
+    /// use std::time::Duration;
+    ///
+    /// let config = VerificationConfig::new(
+    ///     ProjectId::new("firebase-project-id"),
+    /// )
+    /// .with_key_fetch_timeout(Duration::from_secs(5));
+    /// ```
+    pub fn with_key_fetch_timeout(
+        self,
+        timeout: Duration,
+    ) -> Self {
+        Self {
+            inner: Arc::new(VerificationConfigInner {
+                key_fetch_timeout: timeout,
+                ..(*self.inner).clone()
+            }),
+        }
+    }
+
+    /// Replaces the source of the public keys from the default [`GoogleKeySource`],
+    /// e.g. with a local mirror of the JWKS or fixed keys for tests.
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "verify" is enabled.
+    ///
+    /// ## Arguments
+    /// - `key_source` - A source of the public keys.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fars::verification::KeySource;
+    /// use fars::verification::KeySourceFuture;
+    /// use fars::verification::PublicKeySet;
+    /// use fars::verification::VerificationConfig;
+    /// use fars::ProjectId;
+    ///
+    /// #[derive(Debug)]
+    /// struct FixedKeySource {
+    ///     keys: PublicKeySet,
+    /// }
+    ///
+    /// impl KeySource for FixedKeySource {
+    ///     fn keys(&self) -> KeySourceFuture<'_> {
+    ///         Box::pin(async move { Ok(self.keys.clone()) })
+    ///     }
+    /// }
+    ///
+    /// let config = VerificationConfig::new(
+    ///     ProjectId::new("firebase-project-id"),
+    /// )
+    /// .with_key_source(FixedKeySource {
+    ///     keys: PublicKeySet::from_jwk_set_json(r#"{"keys":[]}"#)?,
+    /// });
+    /// ```
+    pub fn with_key_source<K>(
+        self,
+        key_source: K,
+    ) -> Self
+    where
+        K: KeySource + 'static,
+    {
+        Self {
+            inner: Arc::new(VerificationConfigInner {
+                key_source: Some(Arc::new(key_source)),
+                key_cache: Arc::new(Mutex::new(None)),
+                ..(*self.inner).clone()
+            }),
         }
     }
 
+    /// Replaces the expected issuers (`iss`) of the ID token from the default `https://securetoken.google.com/{project_id}`,
+    /// e.g. for the tokens issued on a custom domain or an Identity Platform tenant.
+    ///
+    /// An ID token whose issuer is one of them is accepted.
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "verify" is enabled.
+    ///
+    /// No ID token is accepted if the issuers are empty.
+    ///
+    /// ## Arguments
+    /// - `issuers` - The expected issuers of the ID token.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fars::verification::VerificationConfig;
+    /// use fars::ProjectId;
+    ///
+    /// let config = VerificationConfig::new(
+    ///     ProjectId::new("firebase-project-id"),
+    /// )
+    /// .with_issuers([
+    ///     "https://securetoken.google.com/firebase-project-id",
+    ///     "https://auth.your-app.com/firebase-project-id",
+    /// ]);
+    /// ```
+    pub fn with_issuers<I, S>(
+        self,
+        issuers: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let issuers = issuers
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Self {
+            inner: Arc::new(VerificationConfigInner {
+                issuers: Some(issuers),
+                ..(*self.inner).clone()
+            }),
+        }
+    }
+
+    /// Replaces the expected audiences (`aud`) of the ID token from the default project ID,
+    /// e.g. for the tokens issued on a custom domain or an Identity Platform tenant.
+    ///
+    /// An ID token whose audience is one of them is accepted.
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "verify" is enabled.
+    ///
+    /// No ID token is accepted if the audiences are empty.
+    ///
+    /// ## Arguments
+    /// - `audiences` - The expected audiences of the ID token.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fars::verification::VerificationConfig;
+    /// use fars::ProjectId;
+    ///
+    /// let config = VerificationConfig::new(
+    ///     ProjectId::new("firebase-project-id"),
+    /// )
+    /// .with_audiences(["firebase-project-id", "other-audience"]);
+    /// ```
+    pub fn with_audiences<I, S>(
+        self,
+        audiences: I,
+    ) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let audiences = audiences
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Self {
+            inner: Arc::new(VerificationConfigInner {
+                audiences: Some(audiences),
+                ..(*self.inner).clone()
+            }),
+        }
+    }
+
+    /// (Insecure) Accepts the unsigned (`alg: none`) ID tokens issued by the Firebase Auth Emulator on the host.
+    ///
+    /// The claims, e.g. `aud`, `iss` and `exp`, are verified as the same as the production,
+    /// but the signature is not, and the signed ID tokens of the production are rejected.
+    ///
+    /// This is only reachable by [`crate::emulator::EmulatorConfig::verification_config`]
+    /// not to accept the forged unsigned ID tokens on the production by mistake.
+    ///
+    /// ## Arguments
+    /// - `emulator_host` - The host and port of the Firebase Auth Emulator that issues the ID tokens, e.g. `localhost:9099`.
+    #[cfg(feature = "emulator")]
+    pub(crate) fn insecure_allow_unsigned<S>(
+        self,
+        emulator_host: S,
+    ) -> Self
+    where
+        S: Into<String>,
+    {
+        let emulator_host = emulator_host.into();
+
+        Self {
+            inner: Arc::new(VerificationConfigInner {
+                unsigned_emulator_host: Some(emulator_host),
+                ..(*self.inner).clone()
+            }),
+        }
+    }
+
+    /// Returns the host of the Firebase Auth Emulator if the unsigned ID tokens are accepted
+    /// by the config of [`crate::emulator::EmulatorConfig::verification_config`].
+    ///
+    /// ## NOTE
+    /// This is only available when the features "verify" and "emulator" are enabled.
+    #[cfg(feature = "emulator")]
+    pub fn unsigned_emulator_host(&self) -> Option<&str> {
+        self.inner
+            .unsigned_emulator_host
+            .as_deref()
+    }
+
     /// Verifies an ID token of the Firebase Auth.
     ///
     /// See also [document](https://firebase.google.com/docs/auth/admin/verify-id-tokens#verify_id_tokens_using_a_third-party_jwt_library).
@@ -103,7 +452,8 @@ impl VerificationConfig {
     /// Decoded ID token payload claims if the ID token is valid.
     ///
     /// ## Errors
-    /// [`VerificationError`] if the ID token is invalid.
+    /// [`VerificationError`] if the ID token is invalid,
+    /// or [`VerificationError::KeyFetchTimeout`] if fetching the public keys timed out.
     ///
     /// ## Example
     /// ```
@@ -123,8 +473,242 @@ impl VerificationConfig {
         &self,
         id_token: &IdToken,
     ) -> VerificationResult {
-        verify_id_token(&self.client, id_token, &self.project_id).await
+        let (claims, _) = self
+            .verify_id_token_detailed(id_token)
+            .await?;
+
+        Ok(claims)
+    }
+
+    /// Verifies an ID token in an `Authorization` header value of the Bearer scheme, e.g. on a server middleware.
+    ///
+    /// The scheme `Bearer` is matched case-insensitively and the extra whitespaces around the scheme and the ID token are ignored.
+    ///
+    /// See also [`VerificationConfig::verify_id_token`].
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "verify" is enabled.
+    ///
+    /// ## Arguments
+    /// - `header_value` - A raw value of the `Authorization` header, e.g. `Bearer <ID token>`.
+    ///
+    /// ## Returns
+    /// Decoded ID token payload claims if the ID token is valid.
+    ///
+    /// ## Errors
+    /// [`VerificationError::MalformedAuthorizationHeader`] if the header value is not in the form of `Bearer <ID token>`,
+    /// or [`VerificationError`] if the ID token is invalid.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::verification::VerificationConfig;
+    /// use fars::ProjectId;
+    ///
+    /// let config = VerificationConfig::new(
+    ///     ProjectId::new("firebase-project-id"),
+    /// );
+    ///
+    /// let claims = config.verify_bearer(
+    ///     "Bearer id-token",
+    /// ).await?;
+    /// ```
+    pub async fn verify_bearer(
+        &self,
+        header_value: &str,
+    ) -> VerificationResult {
+        let id_token = parse_bearer(header_value)
+            .ok_or(VerificationError::MalformedAuthorizationHeader)?;
+
+        self.verify_id_token(&IdToken::new(id_token))
+            .await
+    }
+
+    /// Verifies an ID token of the Firebase Auth and returns the authenticated user,
+    /// e.g. for a web middleware that takes a bearer token.
+    ///
+    /// See also [`VerificationConfig::verify_id_token`].
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "verify" is enabled.
+    ///
+    /// ## Arguments
+    /// - `id_token` - An ID token of the Firebase Auth.
+    ///
+    /// ## Returns
+    /// The authenticated user of the ID token if the ID token is valid.
+    ///
+    /// ## Errors
+    /// [`VerificationError`] if the ID token is invalid.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::verification::VerificationConfig;
+    /// use fars::ProjectId;
+    /// use fars::IdToken;
+    ///
+    /// let config = VerificationConfig::new(
+    ///     ProjectId::new("firebase-project-id"),
+    /// );
+    ///
+    /// let user = config.authenticate(
+    ///     &IdToken::new("id-token"),
+    /// ).await?;
+    ///
+    /// if !user.email_verified {
+    ///     // Reject the request.
+    /// }
+    /// ```
+    pub async fn authenticate(
+        &self,
+        id_token: &IdToken,
+    ) -> Result<AuthenticatedUser, VerificationError> {
+        let claims = self
+            .verify_id_token(id_token)
+            .await?;
+
+        Ok(claims.into())
+    }
+
+    /// Verifies an ID token of the Firebase Auth and returns the metadata of the verification.
+    ///
+    /// The metadata contains the key ID (`kid`) of the public key used to verify the ID token,
+    /// which helps to diagnose verification failures across key rotations.
+    ///
+    /// ## NOTE
+    /// This is only available when the feature "verify" is enabled.
+    ///
+    /// ## Arguments
+    /// - `id_token` - An ID token of the Firebase Auth.
+    ///
+    /// ## Returns
+    /// Decoded ID token payload claims and the metadata of the verification if the ID token is valid.
+    ///
+    /// ## Errors
+    /// [`VerificationError`] if the ID token is invalid.
+    ///
+    /// ## Example
+    /// ```
+    /// use fars::verification::VerificationConfig;
+    /// use fars::ProjectId;
+    /// use fars::IdToken;
+    ///
+    /// let config = VerificationConfig::new(
+    ///     ProjectId::new("firebase-project-id"),
+    /// );
+    ///
+    /// let (claims, metadata) = config.verify_id_token_detailed(
+    ///     &IdToken::new("id-token"),
+    /// ).await?;
+    ///
+    /// println!("Verified by the key: {}", metadata.kid);
+    /// ```
+    pub async fn verify_id_token_detailed(
+        &self,
+        id_token: &IdToken,
+    ) -> Result<
+        (
+            IdTokenPayloadClaims,
+            VerificationMetadata,
+        ),
+        VerificationError,
+    > {
+        // NOTE: The Firebase Auth Emulator issues unsigned ID tokens,
+        // which are only accepted by the config of `EmulatorConfig::verification_config`.
+        if self
+            .inner
+            .unsigned_emulator_host
+            .is_some()
+        {
+            return verify_unsigned_id_token(
+                id_token,
+                &self.inner.expected_issuers(),
+                &self
+                    .inner
+                    .expected_audiences(),
+            );
+        }
+
+        // Get the public keys from the cache or the key source.
+        let (keys, from_cache) = self.public_keys().await?;
+
+        let (claims, metadata) = verify_id_token(
+            id_token,
+            &self.inner.expected_issuers(),
+            &self
+                .inner
+                .expected_audiences(),
+            &keys,
+        )?;
+
+        Ok((
+            claims,
+            VerificationMetadata {
+                from_cache,
+                ..metadata
+            },
+        ))
+    }
+
+    /// Gets the public keys from the cache within the max age, or fetches them from the key source.
+    ///
+    /// All the keys of the fetched set are cached, not only the one to verify the ID token,
+    /// so that any key ID (`kid`) in the set is served without refetching, e.g. while two keys coexist during a key rotation.
+    ///
+    /// ## Returns
+    /// The public keys and whether they came from the cache.
+    async fn public_keys(
+        &self
+    ) -> Result<(Arc<PublicKeySet>, bool), VerificationError> {
+        // Return the cached keys within the max age.
+        if let Some(keys) = self.inner.cached_keys() {
+            return Ok((keys, true));
+        }
+
+        // Fetch the public keys from the key source.
+        let keys = Arc::new(match &self.inner.key_source {
+            | Some(key_source) => key_source.keys().await?,
+            | None => {
+                GoogleKeySource::new(
+                    self.inner.client.clone(),
+                    self.inner.key_fetch_timeout,
+                )
+                .keys()
+                .await?
+            },
+        });
+
+        // Cache the keys only when they have the max age.
+        if keys.max_age.is_some() {
+            self.inner
+                .cache_keys(keys.clone());
+        }
+
+        Ok((keys, false))
+    }
+}
+
+/// Parses an `Authorization` header value of the Bearer scheme.
+///
+/// ## Arguments
+/// - `header_value` - A raw value of the `Authorization` header, e.g. `Bearer <ID token>`.
+///
+/// ## Returns
+/// The token if the header value is in the form of `Bearer <ID token>`, case-insensitively on the scheme.
+fn parse_bearer(header_value: &str) -> Option<&str> {
+    let (scheme, token) = header_value
+        .trim()
+        .split_once(char::is_whitespace)?;
+
+    if !scheme.eq_ignore_ascii_case("bearer") {
+        return None;
     }
+
+    let token = token.trim();
+    if token.is_empty() || token.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some(token)
 }
 
 /// Verify an ID token of the Firebase Auth.
@@ -135,22 +719,30 @@ impl VerificationConfig {
 /// This is only available when the feature "verify" is enabled.
 ///
 /// ## Arguments
-/// - `client` - A HTTP client.
 /// - `id_token` - An ID token of the Firebase Auth.
-/// - `project_id` - Your project ID of the Firebase project.
+/// - `issuers` - The expected issuers of the ID token.
+/// - `audiences` - The expected audiences of the ID token.
+/// - `keys` - The public keys to verify the ID token.
 ///
 /// ## Returns
-/// ID token payload claims if the ID token is valid.
+/// ID token payload claims and the metadata of the verification if the ID token is valid.
 ///
 /// ## Errors
 /// [`VerificationError`] if the ID token is invalid.
-async fn verify_id_token(
-    client: &Client,
+fn verify_id_token(
     id_token: &IdToken,
-    project_id: &ProjectId,
-) -> VerificationResult {
+    issuers: &[String],
+    audiences: &[String],
+    keys: &PublicKeySet,
+) -> Result<
+    (
+        IdTokenPayloadClaims,
+        VerificationMetadata,
+    ),
+    VerificationError,
+> {
     // Decode header of the ID token.
-    let header = jsonwebtoken::decode_header(id_token.inner())
+    let header = jsonwebtoken::decode_header(id_token.expose_secret())
         .map_err(VerificationError::DecodeTokenHeaderFailed)?;
 
     // Verify type of the token in the header.
@@ -172,48 +764,19 @@ async fn verify_id_token(
         .kid
         .ok_or(VerificationError::KidNotFound)?;
 
-    // Get public key list from the Google API.
-    let response = client
-        .inner()
-        .get("https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com")
-        .send()
-        .await
-        .map_err(VerificationError::HttpRequestError)?;
-
-    // Verify status code of the response.
-    if response.status() != reqwest::StatusCode::OK {
-        return Err(
-            VerificationError::InvalidResponseStatusCode(response.status()),
-        );
-    }
-
-    // Deserialize the response JSON.
-    let key_map = response
-        .json::<HashMap<String, String>>()
-        .await
-        .map_err(|error| {
-            VerificationError::DeserializeResponseJsonFailed(error)
-        })?;
-
-    // Find public key from the key map by kid.
-    let key = key_map
-        .get(&kid)
-        .ok_or(VerificationError::PublicKeyNotFound(
-            kid,
-        ))?;
-
-    // Get decoding key from the public key.
-    let decoding_key = jsonwebtoken::DecodingKey::from_rsa_pem(key.as_bytes())
-        .map_err(VerificationError::GetDecodingKeyFailed)?;
+    // Find decoding key from the public keys by kid.
+    let decoding_key =
+        keys.keys
+            .get(&kid)
+            .ok_or(VerificationError::PublicKeyNotFound(
+                kid.clone(),
+            ))?;
 
     // Create validation for the ID token.
     let mut validation =
         jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::RS256);
-    validation.set_audience(&[project_id.inner()]);
-    validation.set_issuer(&[format!(
-        "https://securetoken.google.com/{}",
-        project_id.inner()
-    )]);
+    validation.set_audience(audiences);
+    validation.set_issuer(issuers);
     validation.set_required_spec_claims(&[
         "exp",
         "iat",
@@ -225,8 +788,8 @@ async fn verify_id_token(
 
     // Decode and verify the ID token.
     let decoded = jsonwebtoken::decode::<IdTokenPayloadClaims>(
-        id_token.inner(),
-        &decoding_key,
+        id_token.expose_secret(),
+        decoding_key,
         &validation,
     )
     .map_err(VerificationError::DecodeTokenFailed)?;
@@ -247,5 +810,641 @@ async fn verify_id_token(
         );
     }
 
-    Ok(decoded.claims)
+    Ok((
+        decoded.claims,
+        VerificationMetadata {
+            kid,
+            from_cache: false,
+            max_age: keys.max_age,
+        },
+    ))
+}
+
+/// Verify an unsigned ID token issued by the Firebase Auth Emulator,
+/// which checks the claims of the ID token without any signature.
+///
+/// ## NOTE
+/// This is only available when the feature "verify" is enabled.
+///
+/// ## Arguments
+/// - `id_token` - An unsigned ID token of the Firebase Auth Emulator.
+/// - `issuers` - The expected issuers of the ID token.
+/// - `audiences` - The expected audiences of the ID token.
+///
+/// ## Returns
+/// ID token payload claims and the metadata of the verification, whose `kid` is empty, if the ID token is valid.
+///
+/// ## Errors
+/// [`VerificationError`] if the ID token is invalid.
+fn verify_unsigned_id_token(
+    id_token: &IdToken,
+    issuers: &[String],
+    audiences: &[String],
+) -> Result<
+    (
+        IdTokenPayloadClaims,
+        VerificationMetadata,
+    ),
+    VerificationError,
+> {
+    use base64::Engine;
+    use jsonwebtoken::errors::ErrorKind;
+
+    /// Decodes a Base64 URL encoded JSON part of the ID token.
+    fn decode_part<T>(part: &str) -> Result<T, jsonwebtoken::errors::Error>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let decoded = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(part)
+            .map_err(ErrorKind::Base64)?;
+
+        serde_json::from_slice(&decoded)
+            .map_err(|error| ErrorKind::Json(Arc::new(error)).into())
+    }
+
+    /// Header of an unsigned ID token.
+    #[derive(serde::Deserialize)]
+    struct UnsignedHeader {
+        alg: String,
+        typ: Option<String>,
+    }
+
+    // Split the ID token into the header, the payload and the empty signature.
+    let mut parts = id_token
+        .expose_secret()
+        .split('.');
+    let (Some(header), Some(payload), Some(_), None) = (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) else {
+        return Err(
+            VerificationError::DecodeTokenHeaderFailed(
+                ErrorKind::InvalidToken.into(),
+            ),
+        );
+    };
+
+    // Decode header of the ID token.
+    let header = decode_part::<UnsignedHeader>(header)
+        .map_err(VerificationError::DecodeTokenHeaderFailed)?;
+
+    // Verify type of the token in the header.
+    if header.typ != Some("JWT".to_string()) {
+        return Err(VerificationError::InvalidTokenType(
+            header.typ,
+        ));
+    }
+
+    // Verify that the token is unsigned.
+    if header.alg != "none" {
+        return Err(VerificationError::DecodeTokenFailed(
+            ErrorKind::InvalidAlgorithm.into(),
+        ));
+    }
+
+    // Decode payload of the ID token.
+    let claims = decode_part::<IdTokenPayloadClaims>(payload)
+        .map_err(VerificationError::DecodeTokenFailed)?;
+
+    // Verify audience and issuer.
+    if !audiences.contains(&claims.aud) {
+        return Err(VerificationError::DecodeTokenFailed(
+            ErrorKind::InvalidAudience.into(),
+        ));
+    }
+    if !issuers.contains(&claims.iss) {
+        return Err(VerificationError::DecodeTokenFailed(
+            ErrorKind::InvalidIssuer.into(),
+        ));
+    }
+
+    // Verify subject.
+    if claims.sub.as_str().is_empty() {
+        return Err(VerificationError::DecodeTokenFailed(
+            ErrorKind::InvalidSubject.into(),
+        ));
+    }
+
+    let time_stamp = jsonwebtoken::get_current_timestamp();
+
+    // Verify expiration time.
+    if claims.exp < time_stamp {
+        return Err(VerificationError::TokenExpired(
+            claims.exp,
+        ));
+    }
+
+    // Verify issued-at time.
+    if claims.iat > time_stamp {
+        return Err(VerificationError::TokenIssuedInTheFuture(claims.iat));
+    }
+
+    Ok((
+        claims,
+        VerificationMetadata {
+            kid: String::new(),
+            from_cache: false,
+            max_age: None,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use jsonwebtoken::errors::ErrorKind;
+
+    use super::*;
+    use crate::verification::KeySourceFuture;
+
+    /// A test key pair, whose certificate is self-signed.
+    struct TestKey {
+        kid: &'static str,
+        certificate: &'static str,
+        private_key: &'static [u8],
+    }
+
+    /// The first test key.
+    const KEY_1: TestKey = TestKey {
+        kid: "kid-1",
+        certificate: include_str!("testdata/certificate_1.pem"),
+        private_key: include_bytes!("testdata/private_key_1.pem"),
+    };
+
+    /// The second test key, e.g. coexisting during a key rotation.
+    const KEY_2: TestKey = TestKey {
+        kid: "kid-2",
+        certificate: include_str!("testdata/certificate_2.pem"),
+        private_key: include_bytes!("testdata/private_key_2.pem"),
+    };
+
+    /// A key source of the fixed test keys.
+    #[derive(Debug)]
+    struct FixedKeySource {
+        keys: PublicKeySet,
+    }
+
+    impl KeySource for FixedKeySource {
+        fn keys(&self) -> KeySourceFuture<'_> {
+            Box::pin(async move { Ok(self.keys.clone()) })
+        }
+    }
+
+    /// A key source of the fixed test keys that counts the fetches.
+    #[derive(Debug)]
+    struct CountingKeySource {
+        keys: PublicKeySet,
+        fetches: Arc<AtomicUsize>,
+    }
+
+    impl KeySource for CountingKeySource {
+        fn keys(&self) -> KeySourceFuture<'_> {
+            self.fetches
+                .fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move { Ok(self.keys.clone()) })
+        }
+    }
+
+    /// Creates the public key set of the test keys.
+    fn test_keys() -> PublicKeySet {
+        PublicKeySet::from_x509_certificates(HashMap::from([
+            (
+                KEY_1.kid.to_string(),
+                KEY_1.certificate.to_string(),
+            ),
+            (
+                KEY_2.kid.to_string(),
+                KEY_2.certificate.to_string(),
+            ),
+        ]))
+        .unwrap()
+    }
+
+    /// Signs an ID token with the first test key.
+    fn sign(
+        issuer: &str,
+        audience: &str,
+    ) -> IdToken {
+        sign_with(&KEY_1, issuer, audience)
+    }
+
+    /// Signs an ID token with the test key.
+    fn sign_with(
+        key: &TestKey,
+        issuer: &str,
+        audience: &str,
+    ) -> IdToken {
+        let now = jsonwebtoken::get_current_timestamp();
+        let claims = serde_json::json!({
+            "exp": now + 3600,
+            "iat": now - 10,
+            "auth_time": now - 10,
+            "aud": audience,
+            "iss": issuer,
+            "sub": "uid",
+        });
+
+        let mut header =
+            jsonwebtoken::Header::new(jsonwebtoken::Algorithm::RS256);
+        header.kid = Some(key.kid.to_string());
+
+        let key =
+            jsonwebtoken::EncodingKey::from_rsa_pem(key.private_key).unwrap();
+
+        IdToken::new(jsonwebtoken::encode(&header, &claims, &key).unwrap())
+    }
+
+    /// Creates a config of the test project with the keys counting the fetches.
+    fn counting_config(
+        max_age: Duration
+    ) -> (VerificationConfig, Arc<AtomicUsize>) {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let config = VerificationConfig::new(ProjectId::new("project-id"))
+            .with_key_source(CountingKeySource {
+                keys: test_keys().with_max_age(Some(max_age)),
+                fetches: fetches.clone(),
+            });
+
+        (config, fetches)
+    }
+
+    /// Creates a config of the test project with the test key.
+    fn config() -> VerificationConfig {
+        VerificationConfig::new(ProjectId::new("project-id")).with_key_source(
+            FixedKeySource {
+                keys: test_keys(),
+            },
+        )
+    }
+
+    /// Checks whether the verification failed with the kind of the error.
+    fn is_decode_error(
+        result: &VerificationResult,
+        kind: ErrorKind,
+    ) -> bool {
+        matches!(
+            result,
+            Err(VerificationError::DecodeTokenFailed(error)) if *error.kind() == kind
+        )
+    }
+
+    #[tokio::test]
+    async fn verify_default_issuer_and_audience() {
+        let config = config();
+
+        let claims = config
+            .verify_id_token(&sign(
+                "https://securetoken.google.com/project-id",
+                "project-id",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(claims.sub.as_str(), "uid");
+
+        let result = config
+            .verify_id_token(&sign(
+                "https://issuer.example.com",
+                "project-id",
+            ))
+            .await;
+        assert!(is_decode_error(
+            &result,
+            ErrorKind::InvalidIssuer
+        ));
+    }
+
+    #[tokio::test]
+    async fn verify_custom_issuers() {
+        let config = config().with_issuers([
+            "https://issuer.example.com",
+            "https://another-issuer.example.com",
+        ]);
+
+        assert!(config
+            .verify_id_token(&sign(
+                "https://issuer.example.com",
+                "project-id",
+            ))
+            .await
+            .is_ok());
+
+        // The default issuer is replaced by the custom issuers.
+        let result = config
+            .verify_id_token(&sign(
+                "https://securetoken.google.com/project-id",
+                "project-id",
+            ))
+            .await;
+        assert!(is_decode_error(
+            &result,
+            ErrorKind::InvalidIssuer
+        ));
+    }
+
+    #[tokio::test]
+    async fn verify_custom_audiences() {
+        let config = config().with_audiences([
+            "audience-1",
+            "audience-2",
+        ]);
+
+        assert!(config
+            .verify_id_token(&sign(
+                "https://securetoken.google.com/project-id",
+                "audience-2",
+            ))
+            .await
+            .is_ok());
+
+        // The default audience is replaced by the custom audiences.
+        let result = config
+            .verify_id_token(&sign(
+                "https://securetoken.google.com/project-id",
+                "project-id",
+            ))
+            .await;
+        assert!(is_decode_error(
+            &result,
+            ErrorKind::InvalidAudience
+        ));
+    }
+
+    #[tokio::test]
+    async fn verify_empty_issuers_and_audiences_reject_any_token() {
+        let id_token = sign(
+            "https://securetoken.google.com/project-id",
+            "project-id",
+        );
+
+        let result = config()
+            .with_issuers(Vec::<String>::new())
+            .verify_id_token(&id_token)
+            .await;
+        assert!(is_decode_error(
+            &result,
+            ErrorKind::InvalidIssuer
+        ));
+
+        let result = config()
+            .with_audiences(Vec::<String>::new())
+            .verify_id_token(&id_token)
+            .await;
+        assert!(is_decode_error(
+            &result,
+            ErrorKind::InvalidAudience
+        ));
+    }
+
+    #[tokio::test]
+    async fn cache_all_keys_within_max_age() {
+        let (config, fetches) = counting_config(Duration::from_secs(3600));
+        let issuer = "https://securetoken.google.com/project-id";
+
+        let (_, metadata) = config
+            .verify_id_token_detailed(&sign_with(&KEY_1, issuer, "project-id"))
+            .await
+            .unwrap();
+        assert_eq!(metadata.kid, KEY_1.kid);
+        assert!(!metadata.from_cache);
+
+        // The other key is served from the cache without fetching again.
+        let (_, metadata) = config
+            .verify_id_token_detailed(&sign_with(&KEY_2, issuer, "project-id"))
+            .await
+            .unwrap();
+        assert_eq!(metadata.kid, KEY_2.kid);
+        assert!(metadata.from_cache);
+
+        // The cache is shared by the cloned config.
+        let (_, metadata) = config
+            .clone()
+            .verify_id_token_detailed(&sign_with(&KEY_1, issuer, "project-id"))
+            .await
+            .unwrap();
+        assert!(metadata.from_cache);
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn refetch_keys_after_max_age() {
+        let (config, fetches) = counting_config(Duration::ZERO);
+        let issuer = "https://securetoken.google.com/project-id";
+
+        for key in [&KEY_1, &KEY_2] {
+            let (_, metadata) = config
+                .verify_id_token_detailed(&sign_with(key, issuer, "project-id"))
+                .await
+                .unwrap();
+            assert!(!metadata.from_cache);
+        }
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn parse_bearer_token() {
+        assert_eq!(parse_bearer("Bearer x"), Some("x"));
+        assert_eq!(parse_bearer("bearer  x "), Some("x"));
+    }
+
+    #[test]
+    fn parse_invalid_bearer() {
+        assert_eq!(parse_bearer("x"), None);
+        assert_eq!(parse_bearer("Bearer"), None);
+        assert_eq!(parse_bearer("Bearer a b"), None);
+        assert_eq!(parse_bearer("Basic x"), None);
+    }
+
+    /// Creates the valid claims of an unsigned ID token of the test project.
+    fn unsigned_claims() -> serde_json::Value {
+        let now = jsonwebtoken::get_current_timestamp();
+
+        serde_json::json!({
+            "exp": now + 3600,
+            "iat": now - 10,
+            "auth_time": now - 10,
+            "aud": "project-id",
+            "iss": "https://securetoken.google.com/project-id",
+            "sub": "uid",
+        })
+    }
+
+    /// Creates an unsigned ID token of the header and the claims as the Firebase Auth Emulator.
+    fn unsigned(
+        header: serde_json::Value,
+        claims: serde_json::Value,
+    ) -> IdToken {
+        use base64::Engine;
+
+        let encode = |value: serde_json::Value| {
+            base64::engine::general_purpose::URL_SAFE_NO_PAD
+                .encode(value.to_string())
+        };
+
+        IdToken::new(format!(
+            "{}.{}.",
+            encode(header),
+            encode(claims)
+        ))
+    }
+
+    /// Creates an unsigned ID token of the claims with the header of the Firebase Auth Emulator.
+    fn unsigned_with_claims(claims: serde_json::Value) -> IdToken {
+        unsigned(
+            serde_json::json!({ "alg": "none", "typ": "JWT" }),
+            claims,
+        )
+    }
+
+    /// Verifies the unsigned ID token for the test project.
+    fn verify_unsigned(id_token: &IdToken) -> VerificationResult {
+        verify_unsigned_id_token(
+            id_token,
+            &["https://securetoken.google.com/project-id".to_string()],
+            &["project-id".to_string()],
+        )
+        .map(|(claims, _)| claims)
+    }
+
+    /// Overrides the claim of the valid unsigned claims.
+    fn unsigned_claims_with(
+        key: &str,
+        value: serde_json::Value,
+    ) -> serde_json::Value {
+        let mut claims = unsigned_claims();
+        claims[key] = value;
+        claims
+    }
+
+    #[test]
+    fn verify_unsigned_token() {
+        let claims =
+            verify_unsigned(&unsigned_with_claims(unsigned_claims())).unwrap();
+
+        assert_eq!(claims.sub.as_str(), "uid");
+        assert_eq!(claims.aud, "project-id");
+    }
+
+    #[test]
+    fn verify_unsigned_rejects_signed_algorithm() {
+        let id_token = unsigned(
+            serde_json::json!({ "alg": "RS256", "typ": "JWT" }),
+            unsigned_claims(),
+        );
+
+        assert!(is_decode_error(
+            &verify_unsigned(&id_token),
+            ErrorKind::InvalidAlgorithm
+        ));
+    }
+
+    #[test]
+    fn verify_unsigned_rejects_wrong_audience() {
+        let id_token = unsigned_with_claims(unsigned_claims_with(
+            "aud",
+            serde_json::json!("other-project-id"),
+        ));
+
+        assert!(is_decode_error(
+            &verify_unsigned(&id_token),
+            ErrorKind::InvalidAudience
+        ));
+    }
+
+    #[test]
+    fn verify_unsigned_rejects_wrong_issuer() {
+        let id_token = unsigned_with_claims(unsigned_claims_with(
+            "iss",
+            serde_json::json!(
+                "https://securetoken.google.com/other-project-id"
+            ),
+        ));
+
+        assert!(is_decode_error(
+            &verify_unsigned(&id_token),
+            ErrorKind::InvalidIssuer
+        ));
+    }
+
+    #[test]
+    fn verify_unsigned_rejects_empty_subject() {
+        let id_token = unsigned_with_claims(unsigned_claims_with(
+            "sub",
+            serde_json::json!(""),
+        ));
+
+        assert!(is_decode_error(
+            &verify_unsigned(&id_token),
+            ErrorKind::InvalidSubject
+        ));
+    }
+
+    #[test]
+    fn verify_unsigned_rejects_expired_token() {
+        let expired = jsonwebtoken::get_current_timestamp() - 60;
+        let id_token = unsigned_with_claims(unsigned_claims_with(
+            "exp",
+            serde_json::json!(expired),
+        ));
+
+        assert!(matches!(
+            verify_unsigned(&id_token),
+            Err(VerificationError::TokenExpired(exp)) if exp == expired
+        ));
+    }
+
+    #[test]
+    fn verify_unsigned_rejects_token_issued_in_the_future() {
+        let future = jsonwebtoken::get_current_timestamp() + 3600;
+        let id_token = unsigned_with_claims(unsigned_claims_with(
+            "iat",
+            serde_json::json!(future),
+        ));
+
+        assert!(matches!(
+            verify_unsigned(&id_token),
+            Err(VerificationError::TokenIssuedInTheFuture(iat)) if iat == future
+        ));
+    }
+
+    #[test]
+    fn verify_unsigned_rejects_fourth_segment() {
+        let id_token = IdToken::new(format!(
+            "{}.signature",
+            unsigned_with_claims(unsigned_claims()).expose_secret()
+        ));
+
+        assert!(matches!(
+            verify_unsigned(&id_token),
+            Err(VerificationError::DecodeTokenHeaderFailed(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn verify_rejects_unsigned_token_without_emulator() {
+        let id_token = unsigned_with_claims(unsigned_claims());
+
+        assert!(config()
+            .verify_id_token(&id_token)
+            .await
+            .is_err());
+    }
+
+    #[cfg(feature = "emulator")]
+    #[tokio::test]
+    async fn verify_accepts_unsigned_token_with_emulator() {
+        let config = config().insecure_allow_unsigned("localhost:9099");
+        let id_token = unsigned_with_claims(unsigned_claims());
+
+        assert!(config
+            .verify_id_token(&id_token)
+            .await
+            .is_ok());
+    }
 }
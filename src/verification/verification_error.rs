@@ -21,12 +21,27 @@ pub enum VerificationError {
     /// HTTP request error to get public key from [public keys list](https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com).
     #[error("HTTP request error to get public key from https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com: {0:?}")]
     HttpRequestError(reqwest::Error),
+    /// Timed out to get public key from [public keys list](https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com).
+    ///
+    /// See also [`crate::verification::VerificationConfig::with_key_fetch_timeout`].
+    #[error("Timed out to get public key from https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com: {0:?}")]
+    KeyFetchTimeout(reqwest::Error),
     /// Invalid response status code to get public key from [public keys list](https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com).
     #[error("Invalid response status code to get public key from https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com: {0:?}")]
     InvalidResponseStatusCode(reqwest::StatusCode),
     /// Deserialize response JSON to hash map failed to get public key from [public keys list](https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com).
     #[error("Deserialize response JSON to hash map failed to get public key from https://www.googleapis.com/robot/v1/metadata/x509/securetoken@system.gserviceaccount.com")]
     DeserializeResponseJsonFailed(reqwest::Error),
+    /// Deserialize JSON Web Key Set failed.
+    ///
+    /// See also [`crate::verification::PublicKeySet::from_jwk_set_json`].
+    #[error("Deserialize JSON Web Key Set failed: {0:?}")]
+    DeserializeJwkSetFailed(serde_json::Error),
+    /// A custom key source failed to get the public keys.
+    ///
+    /// See also [`crate::verification::KeySource`].
+    #[error("Key source failed to get the public keys: {0:?}")]
+    KeySourceFailed(Box<dyn std::error::Error + Send + Sync>),
     /// Target public key specified by kid not found in key map.
     #[error("Target public key specified by kid not found in key map: {0:?}")]
     PublicKeyNotFound(String),
@@ -42,4 +57,98 @@ pub enum VerificationError {
     /// The ID token is issued in the future.
     #[error("The ID token is issued in the future at {0:?}")]
     TokenIssuedInTheFuture(u64),
+    /// The `Authorization` header value is not in the form of `Bearer <ID token>`.
+    ///
+    /// See also [`crate::verification::VerificationConfig::verify_bearer`].
+    #[error("Malformed authorization header, expected `Bearer <ID token>`")]
+    MalformedAuthorizationHeader,
+}
+
+// Feature "axum"
+#[cfg(feature = "axum")]
+impl VerificationError {
+    /// Returns the HTTP status code to respond to the client of your server, e.g. on an axum handler.
+    ///
+    /// - `401 Unauthorized` - The ID token is invalid or expired.
+    /// - `502 Bad Gateway` - Failed to get the public keys to verify the ID token.
+    ///
+    /// ## NOTE
+    /// This is only available when the features "verify" and "axum" are enabled.
+    pub fn status_code(&self) -> axum::http::StatusCode {
+        use axum::http::StatusCode;
+
+        match self {
+            | VerificationError::HttpRequestError(_)
+            | VerificationError::KeyFetchTimeout(_)
+            | VerificationError::InvalidResponseStatusCode(_)
+            | VerificationError::DeserializeResponseJsonFailed(_)
+            | VerificationError::DeserializeJwkSetFailed(_)
+            | VerificationError::KeySourceFailed(_)
+            | VerificationError::GetDecodingKeyFailed(_) => {
+                StatusCode::BAD_GATEWAY
+            },
+            | VerificationError::DecodeTokenHeaderFailed(_)
+            | VerificationError::InvalidTokenType(_)
+            | VerificationError::InvalidAlgorithm(_)
+            | VerificationError::KidNotFound
+            | VerificationError::PublicKeyNotFound(_)
+            | VerificationError::DecodeTokenFailed(_)
+            | VerificationError::TokenExpired(_)
+            | VerificationError::TokenIssuedInTheFuture(_)
+            | VerificationError::MalformedAuthorizationHeader => {
+                StatusCode::UNAUTHORIZED
+            },
+        }
+    }
+}
+
+/// Responds only the status code of [`VerificationError::status_code`] without any body
+/// not to leak the details of the error to the client.
+///
+/// ## NOTE
+/// This is only available when the features "verify" and "axum" are enabled.
+#[cfg(feature = "axum")]
+impl axum::response::IntoResponse for VerificationError {
+    fn into_response(self) -> axum::response::Response {
+        self.status_code()
+            .into_response()
+    }
+}
+
+#[cfg(all(test, feature = "axum"))]
+mod tests {
+    use axum::http::StatusCode;
+
+    use super::*;
+
+    /// Creates a `reqwest::Error` without sending any request.
+    fn reqwest_error() -> reqwest::Error {
+        reqwest::Client::new()
+            .get("invalid url")
+            .build()
+            .unwrap_err()
+    }
+
+    #[test]
+    fn status_code_of_errors() {
+        let cases = [
+            (
+                VerificationError::TokenExpired(0),
+                StatusCode::UNAUTHORIZED,
+            ),
+            (
+                VerificationError::KeyFetchTimeout(reqwest_error()),
+                StatusCode::BAD_GATEWAY,
+            ),
+        ];
+
+        for (error, status_code) in cases {
+            assert_eq!(
+                error.status_code(),
+                status_code,
+                "{:?}",
+                error
+            );
+        }
+    }
 }
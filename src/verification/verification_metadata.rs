@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+/// Metadata of a successful ID token verification, e.g. for diagnosing key rotation issues.
+///
+/// ## NOTE
+/// This is only available when the feature "verify" is enabled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationMetadata {
+    /// The key ID (`kid`) of the public key used to verify the ID token.
+    pub kid: String,
+    /// Whether the public key came from a cache instead of the network.
+    ///
+    /// The public keys are cached until their max age, see also [`crate::verification::PublicKeySet::max_age`].
+    pub from_cache: bool,
+    /// The `max-age` of the `Cache-Control` header of the public keys response,
+    /// i.e. how long the public keys are valid.
+    pub max_age: Option<Duration>,
+}